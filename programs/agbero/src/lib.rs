@@ -1,5 +1,16 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::keccak;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::incinerator;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::slot_hashes::MAX_ENTRIES as MAX_SLOT_HASHES;
+use anchor_lang::solana_program::sysvar::{
+    self,
+    instructions::{load_current_index_checked, load_instruction_at_checked},
+};
 use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
 // Agbero: The Enforcer
 // Performance bonds for AI agents
@@ -19,13 +30,48 @@ pub mod agbero {
         task_description: String,
         collateral_amount: u64,
         deadline: i64,
+        options: BondOptions,
     ) -> Result<()> {
+        // Emergency stop halts new bonds; existing ones can still wind down
+        require!(!ctx.accounts.config.paused, AgberoError::ProgramPaused);
+        // An id over the 32-byte seed limit has no bond PDA and already fails
+        // account validation; checked again so MAX_SIZE stays honest
+        require!(!bond_id.is_empty(), AgberoError::BondIdEmpty);
+        require!(bond_id.len() <= Bond::MAX_ID_LEN, AgberoError::BondIdTooLong);
         require!(
-            task_description.len() <= 500,
-            AgberoError::DescriptionTooLong
+            ctx.accounts.agent.key() != ctx.accounts.principal.key(),
+            AgberoError::PrincipalCannotBeAgent
         );
-        require!(collateral_amount >= 1_000_000, AgberoError::CollateralTooLow); // 0.001 SOL min
-        require!(deadline > Clock::get()?.unix_timestamp, AgberoError::InvalidDeadline);
+        check_bond_params(
+            &ctx.accounts.config,
+            &task_description,
+            collateral_amount,
+            deadline,
+            &options,
+        )?;
+        require!(
+            options.slash_destination != SlashDestination::Treasury
+                || ctx.accounts.config.treasury != Pubkey::default(),
+            AgberoError::InvalidSlashDestination
+        );
+        let now = Clock::get()?.unix_timestamp;
+        BondReservation::redeem(
+            &ctx.accounts.reservation,
+            &ctx.accounts.principal.to_account_info(),
+            now,
+        )?;
+
+        // Throttle repeat bonds between the same pair to deter reputation farming
+        let pair = &mut ctx.accounts.pair;
+        require!(
+            pair.last_bond_at == 0
+                || now >= pair.last_bond_at.saturating_add(ctx.accounts.config.bond_cooldown),
+            AgberoError::BondCooldownActive
+        );
+        pair.principal = ctx.accounts.principal.key();
+        pair.agent = ctx.accounts.agent.key();
+        pair.last_bond_at = now;
+        pair.bump = ctx.bumps.pair;
 
         let bond = &mut ctx.accounts.bond;
         bond.bond_id = bond_id;
@@ -35,12 +81,119 @@ pub mod agbero {
         bond.collateral_amount = collateral_amount;
         bond.deadline = deadline;
         bond.status = BondStatus::Pending;
-        bond.created_at = Clock::get()?.unix_timestamp;
+        bond.created_at = now;
+        bond.stake_deadline = match ctx.accounts.config.stake_window {
+            0 => 0,
+            window => bond.created_at.saturating_add(window),
+        };
         bond.completed_at = 0;
         bond.verification_votes = vec![];
         bond.slash_votes = vec![];
-        bond.proof_uri = String::new();
+        bond.proofs = vec![];
         bond.bump = ctx.bumps.bond;
+        bond.vault_bump = ctx.bumps.bond_vault;
+        bond.vote_mode = options.vote_mode;
+        bond.slash_reason = None;
+        bond.commit_window = options.commit_window;
+        bond.reveal_window = options.reveal_window;
+        bond.commit_deadline = 0;
+        bond.reveal_deadline = 0;
+        bond.vote_commits = vec![];
+        bond.subcommittee = vec![];
+        bond.assignment_deadlines = vec![];
+        bond.installments = options.installments;
+        bond.installment_interval = options.installment_interval;
+        bond.installments_paid = 0;
+        bond.funded_amount = 0;
+        bond.buffer_amount = 0;
+        bond.total_slashed = 0;
+        bond.total_released = 0;
+        bond.slash_justification = String::new();
+        bond.tip_pool = 0;
+        bond.on_hold = false;
+        bond.appeal_window = options.appeal_window;
+        bond.slash_requested_at = 0;
+        bond.category = options.category;
+        bond.deadline_warned = false;
+        bond.deadline_passed_noted = false;
+        bond.slash_appealed = false;
+        bond.escrow_authority = options.escrow_authority;
+        bond.extension_count = 0;
+        bond.agent_premium_paid = 0;
+        bond.agent_compensation_due = 0;
+        bond.milestones = options
+            .milestones
+            .iter()
+            .map(|m| Milestone {
+                description: m.description.clone(),
+                amount: m.amount,
+                released: false,
+                approvers: vec![],
+            })
+            .collect();
+        bond.milestones_released = 0;
+        bond.syndicate = options
+            .syndicate
+            .iter()
+            .map(|&member| SyndicateMember { member, contribution: 0 })
+            .collect();
+        bond.principal_contribution = 0;
+        bond.slash_destination = options.slash_destination;
+        bond.rubric = options.rubric.clone();
+        bond.vault_rent = Rent::get()?.minimum_balance(0);
+        bond.slash_acknowledged = false;
+        bond.required_votes = ctx.accounts.config.required_votes(collateral_amount);
+        bond.stake_forfeit_bps = 0;
+        bond.forfeits_collected = 0;
+        bond.proxies = vec![];
+        bond.keeper_bounty = options.keeper_bounty;
+        bond.vote_weighting = options.vote_weighting;
+        bond.callback = options.callback;
+        bond.callback_approved = false;
+        bond.reward_pool = 0;
+        bond.rewards_claimed = 0;
+        bond.team = vec![];
+        bond.team_lead = Pubkey::default();
+        bond.verification_deadline = 0;
+        bond.score = 0;
+        bond.insured_amount = options.insured_amount;
+        bond.insurance_due = 0;
+        bond.principal_premium_paid = 0;
+        bond.open_stakes = 0;
+        bond.subcommittee_draw_slot = 0;
+        bond.subcommittee_seed = [0; 32];
+        bond.tally_root = [0; 32];
+        bond.tally_approve = 0;
+        bond.tally_total = 0;
+        bond.tally_slot = 0;
+        bond.rewards_paid = 0;
+        bond.deferred_rewards = 0;
+        bond.reward_share = 0;
+        bond.deferred_tips = 0;
+        bond.tip_share = 0;
+        bond.staked_at = 0;
+        bond.started_at = 0;
+        bond.require_begin_work = options.require_begin_work;
+        bond.arbiter_ruling = None;
+        bond.reputation_snapshot = ReputationSnapshot::capture(
+            &ctx.accounts.reputation,
+            bond.created_at,
+            ctx.accounts.config.reputation_half_life,
+        )?;
+
+        // Principal pre-funds the vault's rent reserve, so settlements never
+        // drain it below rent exemption and the agent's collateral comes back
+        // whole, plus the bounty for whoever finalizes the bond
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.principal.to_account_info(),
+                to: ctx.accounts.bond_vault.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_context, bond.vault_rent + options.keeper_bounty)?;
+
+        ctx.accounts.stats.total_bonds += 1;
 
         emit!(BondCreated {
             bond_id: bond.bond_id.clone(),
@@ -48,14 +201,69 @@ pub mod agbero {
             agent: bond.agent,
             collateral_amount,
             deadline,
+            keeper_bounty: bond.keeper_bounty,
         });
+        if !bond.rubric.is_empty() {
+            emit!(RubricPublished {
+                bond_id: bond.bond_id.clone(),
+                rubric: bond.rubric.clone(),
+            });
+        }
 
         msg!("Bond created: {}", bond.bond_id);
         Ok(())
     }
 
+    /// Claim a bond_id ahead of create_bond so a watcher of the mempool
+    /// cannot take it first; only the reserver may use it for a while.
+    /// Only `commitment` = keccak(bond_id) is posted, so the id stays hidden
+    /// until create_bond reveals it. A lapsed claim may be taken over
+    pub fn reserve_bond_id(ctx: Context<ReserveBondId>, commitment: [u8; 32]) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let reservation = &mut ctx.accounts.reservation;
+        require!(
+            reservation.reserver == Pubkey::default() || reservation.is_lapsed(now),
+            AgberoError::BondIdReserved
+        );
+        reservation.reserver = ctx.accounts.reserver.key();
+        reservation.reserved_at = now;
+        reservation.bump = ctx.bumps.reservation;
+
+        emit!(BondIdReserved {
+            commitment,
+            reserver: reservation.reserver,
+            expires_at: reservation.reserved_at.saturating_add(BondReservation::WINDOW),
+        });
+        Ok(())
+    }
+
+    /// Dry-run create_bond's parameter checks without allocating anything
+    /// Read-only: returns 0 when valid, otherwise the AgberoError code
+    pub fn validate_bond_params(
+        ctx: Context<ValidateBondParams>,
+        task_description: String,
+        collateral_amount: u64,
+        deadline: i64,
+        options: BondOptions,
+    ) -> Result<u32> {
+        match check_bond_params(
+            &ctx.accounts.config,
+            &task_description,
+            collateral_amount,
+            deadline,
+            &options,
+        ) {
+            Ok(()) => Ok(0),
+            Err(err) => match ProgramError::from(err) {
+                ProgramError::Custom(code) => Ok(code),
+                other => Err(other.into()),
+            },
+        }
+    }
+
     /// Agent stakes collateral to activate bond
     pub fn stake_collateral(ctx: Context<StakeCollateral>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, AgberoError::ProgramPaused);
         let bond = &mut ctx.accounts.bond;
         
         require!(
@@ -66,18 +274,49 @@ pub mod agbero {
             ctx.accounts.agent.key() == bond.agent,
             AgberoError::UnauthorizedAgent
         );
-
-        // Transfer collateral from agent to bond vault
-        let cpi_context = CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.agent.to_account_info(),
-                to: ctx.accounts.bond_vault.to_account_info(),
-            },
+        require!(
+            bond.stake_deadline == 0 || Clock::get()?.unix_timestamp <= bond.stake_deadline,
+            AgberoError::StakeWindowExpired
+        );
+        // Staking past the deadline would only feed an immediate timeout slash
+        require!(
+            Clock::get()?.unix_timestamp < bond.deadline,
+            AgberoError::DeadlineExceeded
         );
-        system_program::transfer(cpi_context, bond.collateral_amount)?;
+        require!(!bond.uses_installments(), AgberoError::InstallmentScheduleActive);
+
+        // Team members have already paid in their contributions
+        let agent_share = bond.collateral_amount - bond.team_contributed();
+
+        // Draw from the agent's pooled staking vault when one is passed,
+        // otherwise transfer collateral directly from the agent
+        match &ctx.accounts.staking_vault {
+            Some(staking_vault) => {
+                require_vault_remainder(staking_vault.lamports(), agent_share)?;
+                let agent_key = ctx.accounts.agent.key();
+                transfer_signed(
+                    &ctx.accounts.system_program,
+                    staking_vault,
+                    &ctx.accounts.bond_vault.to_account_info(),
+                    &[b"staking_vault", agent_key.as_ref(), &[ctx.bumps.staking_vault]],
+                    agent_share,
+                )?;
+            }
+            None => {
+                let cpi_context = CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.agent.to_account_info(),
+                        to: ctx.accounts.bond_vault.to_account_info(),
+                    },
+                );
+                system_program::transfer(cpi_context, agent_share)?;
+            }
+        }
 
         bond.status = BondStatus::Active;
+        bond.staked_at = Clock::get()?.unix_timestamp;
+        ctx.accounts.stats.record_staked(bond.collateral_amount);
 
         emit!(CollateralStaked {
             bond_id: bond.bond_id.clone(),
@@ -89,198 +328,369 @@ pub mod agbero {
         Ok(())
     }
 
-    /// Agent submits proof of completion
-    pub fn submit_proof(ctx: Context<SubmitProof>, proof_uri: String) -> Result<()> {
-        let bond = &mut ctx.accounts.bond;
-        
+    /// Guarantor stakes several pending bonds in one transaction
+    /// remaining_accounts: (bond, bond_vault) pairs; each bond is activated
+    /// exactly as if its agent had staked, and settles the same way
+    pub fn batch_stake<'info>(ctx: Context<'_, '_, 'info, 'info, BatchStake<'info>>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, AgberoError::ProgramPaused);
+        let pairs = ctx.remaining_accounts.chunks_exact(2);
         require!(
-            bond.status == BondStatus::Active,
-            AgberoError::InvalidBondStatus
+            pairs.len() > 0 && pairs.remainder().is_empty(),
+            AgberoError::InvalidBatchAccounts
         );
+        let count = pairs.len();
+        let now = Clock::get()?.unix_timestamp;
+
+        for pair in pairs {
+            let (bond_info, vault_info) = (&pair[0], &pair[1]);
+            let mut bond: Account<'info, Bond> = Account::try_from(bond_info)?;
+
+            require!(bond.status == BondStatus::Pending, AgberoError::InvalidBondStatus);
+            require!(
+                bond.stake_deadline == 0 || now <= bond.stake_deadline,
+                AgberoError::StakeWindowExpired
+            );
+            require!(now < bond.deadline, AgberoError::DeadlineExceeded);
+            require!(!bond.uses_installments(), AgberoError::InstallmentScheduleActive);
+            let vault = Pubkey::create_program_address(
+                &[b"bond_vault", bond_info.key.as_ref(), &[bond.vault_bump]],
+                &crate::ID,
+            )
+            .map_err(|_| AgberoError::InvalidBatchAccounts)?;
+            require_keys_eq!(vault, vault_info.key(), AgberoError::InvalidBatchAccounts);
+
+            let share = bond.collateral_amount - bond.team_contributed();
+            let cpi_context = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.funder.to_account_info(),
+                    to: vault_info.clone(),
+                },
+            );
+            system_program::transfer(cpi_context, share)?;
+
+            bond.status = BondStatus::Active;
+            bond.staked_at = now;
+            ctx.accounts.stats.record_staked(bond.collateral_amount);
+            bond.exit(&crate::ID)?;
+
+            emit!(CollateralStaked {
+                bond_id: bond.bond_id.clone(),
+                agent: bond.agent,
+                amount: bond.collateral_amount,
+            });
+        }
+
+        msg!("Batch staked {} bonds", count);
+        Ok(())
+    }
+
+    /// Agent pays the next installment of a scheduled bond
+    /// The bond turns Active once the final installment lands
+    pub fn fund_installment(ctx: Context<FundInstallment>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(bond.status == BondStatus::Pending, AgberoError::InvalidBondStatus);
+        require!(bond.uses_installments(), AgberoError::InvalidInstallmentSchedule);
         require!(
-            ctx.accounts.agent.key() == bond.agent,
-            AgberoError::UnauthorizedAgent
+            bond.stake_deadline == 0 || now <= bond.stake_deadline,
+            AgberoError::StakeWindowExpired
         );
         require!(
-            Clock::get()?.unix_timestamp <= bond.deadline,
-            AgberoError::DeadlineExceeded
+            now <= bond.next_installment_due(),
+            AgberoError::InstallmentOverdue
         );
-        require!(proof_uri.len() <= 200, AgberoError::ProofUriTooLong);
 
-        bond.proof_uri = proof_uri;
-        bond.status = BondStatus::PendingVerification;
+        let amount = bond.installment_amount(bond.installments_paid);
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.agent.to_account_info(),
+                to: ctx.accounts.bond_vault.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_context, amount)?;
 
-        emit!(ProofSubmitted {
+        bond.installments_paid += 1;
+        bond.funded_amount += amount;
+
+        emit!(InstallmentFunded {
             bond_id: bond.bond_id.clone(),
-            agent: bond.agent,
-            proof_uri: bond.proof_uri.clone(),
+            index: bond.installments_paid - 1,
+            amount,
+            remaining: bond.collateral_amount - bond.funded_amount,
         });
 
-        msg!("Proof submitted for bond: {}", bond.bond_id);
+        if bond.installments_paid == bond.installments {
+            bond.status = BondStatus::Active;
+            bond.staked_at = now;
+            ctx.accounts.stats.record_staked(bond.collateral_amount);
+
+            emit!(CollateralStaked {
+                bond_id: bond.bond_id.clone(),
+                agent: bond.agent,
+                amount: bond.collateral_amount,
+            });
+        }
         Ok(())
     }
 
-    /// Verifier votes on bond completion (yes/no)
-    /// For MVP: anyone can verify (decentralized oracle network)
-    pub fn verify_work(ctx: Context<VerifyWork>, approve: bool) -> Result<()> {
+    /// Principal takes the installments paid so far once the agent misses
+    /// the next due date; the bond ends Slashed without ever going Active
+    pub fn slash_missed_installment<'info>(
+        ctx: Context<'_, '_, '_, 'info, SlashMissedInstallment<'info>>,
+    ) -> Result<()> {
         let bond = &mut ctx.accounts.bond;
-        
+        require!(!bond.on_hold, AgberoError::BondOnHold);
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(bond.status == BondStatus::Pending, AgberoError::InvalidBondStatus);
         require!(
-            bond.status == BondStatus::PendingVerification,
-            AgberoError::InvalidBondStatus
+            bond.uses_installments() && bond.installments_paid > 0,
+            AgberoError::InvalidInstallmentSchedule
         );
         require!(
-            ctx.accounts.verifier.key() != bond.agent,
-            AgberoError::AgentCannotVerify
+            now > bond.next_installment_due(),
+            AgberoError::InstallmentNotOverdue
         );
 
-        let vote = VerificationVote {
-            verifier: ctx.accounts.verifier.key(),
-            approve,
-            timestamp: Clock::get()?.unix_timestamp,
-        };
-        
-        bond.verification_votes.push(vote);
+        bond.status = BondStatus::Slashed;
+        bond.completed_at = now;
+        let reason = SlashReason::MissedInstallment;
+        bond.slash_reason = Some(reason);
 
-        emit!(WorkVerified {
+        // Funded installments go to the slash destination, the principal's
+        // own keeper bounty straight back to it
+        let vault_balance = bond.vault_funds(ctx.accounts.bond_vault.lamports());
+        let keeper_bounty = bond.keeper_bounty.min(vault_balance);
+        pay_slash(
+            &ctx.accounts.system_program,
+            &ctx.accounts.bond_vault,
+            &ctx.accounts.principal.to_account_info(),
+            bond,
+            &ctx.accounts.config,
+            vault_balance - keeper_bounty,
+            ctx.remaining_accounts,
+        )?;
+        transfer_from_vault(
+            &ctx.accounts.system_program,
+            &ctx.accounts.bond_vault,
+            &ctx.accounts.principal.to_account_info(),
+            &bond.key(),
+            bond.vault_bump,
+            keeper_bounty,
+        )?;
+
+        let amount_slashed = bond.funded_amount;
+        ctx.accounts.stats.record_unstaked_slash(amount_slashed);
+        ctx.accounts
+            .reputation
+            .record_slashed(now, ctx.accounts.config.reputation_half_life);
+        track_slash_volume(&mut ctx.accounts.config, &mut ctx.accounts.stats)?;
+
+        bond.record_settlement(amount_slashed, 0);
+        emit!(BondSlashed {
             bond_id: bond.bond_id.clone(),
-            verifier: ctx.accounts.verifier.key(),
-            approve,
+            agent: bond.agent,
+            principal: bond.principal,
+            amount_slashed,
+            reason,
+            justification: String::new(),
         });
+        Ok(())
+    }
 
-        msg!("Verification vote recorded for bond: {}", bond.bond_id);
+    /// Team member pays part of the collateral before the agent activates the bond
+    /// Refunds are later split pro rata to these contributions
+    pub fn contribute_collateral(ctx: Context<ContributeCollateral>, amount: u64) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        let member = ctx.accounts.member.key();
+
+        require!(bond.status == BondStatus::Pending, AgberoError::InvalidBondStatus);
+        require!(amount > 0, AgberoError::InvalidStakeAmount);
+        require!(!bond.uses_installments(), AgberoError::InstallmentScheduleActive);
+        require!(
+            member != bond.agent && member != bond.principal,
+            AgberoError::InvalidTeamMember
+        );
+        require!(
+            bond.team_contributed() + amount <= bond.collateral_amount,
+            AgberoError::ContributionExceedsCollateral
+        );
+
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.member.to_account_info(),
+                to: ctx.accounts.bond_vault.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_context, amount)?;
+
+        match bond.team.iter_mut().find(|m| m.member == member) {
+            Some(existing) => existing.contribution += amount,
+            None => {
+                require!(bond.team.len() < Bond::MAX_TEAM, AgberoError::TeamFull);
+                bond.team.push(TeamMember {
+                    member,
+                    contribution: amount,
+                });
+            }
+        }
+
+        emit!(TeamContribution {
+            bond_id: bond.bond_id.clone(),
+            member,
+            amount,
+        });
         Ok(())
     }
 
-    /// Finalize bond based on verification votes
-    /// Autonomous execution: anyone can call this once quorum is reached
-    pub fn finalize_bond(ctx: Context<FinalizeBond>) -> Result<()> {
+    /// Agent names the team member (or themselves) who alone may submit proof
+    pub fn set_team_lead(ctx: Context<SetTeamLead>, lead: Pubkey) -> Result<()> {
         let bond = &mut ctx.accounts.bond;
-        let vault_balance = ctx.accounts.bond_vault.lamports();
-        
+
         require!(
-            bond.status == BondStatus::PendingVerification ||
-            bond.status == BondStatus::Active && Clock::get()?.unix_timestamp > bond.deadline,
+            bond.status == BondStatus::Pending || bond.status == BondStatus::Active,
             AgberoError::InvalidBondStatus
         );
+        require!(
+            lead == bond.agent || bond.team.iter().any(|m| m.member == lead),
+            AgberoError::InvalidTeamMember
+        );
+        bond.team_lead = lead;
 
-        let total_votes = bond.verification_votes.len() as u64;
-        let approve_votes = bond.verification_votes
-            .iter()
-            .filter(|v| v.approve)
-            .count() as u64;
-        let slash_votes = total_votes - approve_votes;
+        msg!("Team lead for bond {} set to {}", bond.bond_id, lead);
+        Ok(())
+    }
 
-        // Quorum: at least 3 votes, 2/3 majority required
-        let quorum_reached = total_votes >= 3;
-        let majority_approve = approve_votes * 3 >= total_votes * 2;
-        let majority_slash = slash_votes * 3 >= total_votes * 2;
+    /// Clean up a bond the agent never staked once its stake deadline passes
+    /// Callable by anyone; the keeper bounty and all rent go back to the principal
+    pub fn cancel_unstaked<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelUnstaked<'info>>,
+    ) -> Result<()> {
+        let bond = &ctx.accounts.bond;
+        require!(!bond.on_hold, AgberoError::BondOnHold);
 
-        if quorum_reached && majority_approve {
-            // SUCCESS: Release stake to agent
-            bond.status = BondStatus::Completed;
-            bond.completed_at = Clock::get()?.unix_timestamp;
-
-            // Transfer collateral back to agent
-            let bond_key = bond.key();
-            let seeds = &[
-                b"bond_vault",
-                bond_key.as_ref(),
-                &[bond.bump],
-            ];
-            let signer = &[&seeds[..]];
-
-            let cpi_context = CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.bond_vault.to_account_info(),
-                    to: ctx.accounts.agent.to_account_info(),
-                },
-                signer,
-            );
-            system_program::transfer(cpi_context, vault_balance)?;
+        require!(bond.status == BondStatus::Pending, AgberoError::InvalidBondStatus);
+        require!(
+            bond.stake_deadline > 0 && Clock::get()?.unix_timestamp > bond.stake_deadline,
+            AgberoError::StakeWindowOpen
+        );
+        // Part-funded schedules are settled by slash_missed_installment
+        require!(bond.installments_paid == 0, AgberoError::InstallmentScheduleActive);
 
-            emit!(BondCompleted {
-                bond_id: bond.bond_id.clone(),
-                agent: bond.agent,
-                stake_released: vault_balance,
-            });
+        let vault_balance = refund_pending_vault(
+            &ctx.accounts.system_program,
+            &ctx.accounts.bond_vault,
+            &ctx.accounts.principal.to_account_info(),
+            bond,
+            ctx.remaining_accounts,
+        )?;
 
-            msg!("Bond completed successfully. Stake released.");
+        emit!(BondCancelled {
+            bond_id: bond.bond_id.clone(),
+            principal: bond.principal,
+            refunded: vault_balance + bond.to_account_info().lamports(),
+        });
 
-        } else if quorum_reached && majority_slash {
-            // FAILURE: Slash stake to principal
-            bond.status = BondStatus::Slashed;
-            bond.completed_at = Clock::get()?.unix_timestamp;
+        msg!("Unstaked bond cancelled: {}", bond.bond_id);
+        Ok(())
+    }
 
-            let bond_key = bond.key();
-            let seeds = &[
-                b"bond_vault",
-                bond_key.as_ref(),
-                &[bond.bump],
-            ];
-            let signer = &[&seeds[..]];
+    /// Principal withdraws a bond the agent has not staked yet, closing the
+    /// bond and emptying its vault; all rent and the bounty come back
+    pub fn cancel_bond<'info>(ctx: Context<'_, '_, '_, 'info, CancelBond<'info>>) -> Result<()> {
+        let bond = &ctx.accounts.bond;
 
-            let cpi_context = CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.bond_vault.to_account_info(),
-                    to: ctx.accounts.principal.to_account_info(),
-                },
-                signer,
-            );
-            system_program::transfer(cpi_context, vault_balance)?;
+        require!(bond.status == BondStatus::Pending, AgberoError::CannotCancelActiveBond);
+        require!(!bond.on_hold, AgberoError::BondOnHold);
+        require!(bond.installments_paid == 0, AgberoError::InstallmentScheduleActive);
+        // Funded reward tokens come back through reclaim_verifier_rewards first
+        require!(bond.reward_pool == bond.rewards_paid, AgberoError::ClaimsOutstanding);
 
-            emit!(BondSlashed {
-                bond_id: bond.bond_id.clone(),
-                agent: bond.agent,
-                principal: bond.principal,
-                amount_slashed: vault_balance,
-            });
+        let vault_balance = refund_pending_vault(
+            &ctx.accounts.system_program,
+            &ctx.accounts.bond_vault,
+            &ctx.accounts.principal.to_account_info(),
+            bond,
+            ctx.remaining_accounts,
+        )?;
 
-            msg!("Bond slashed! Stake transferred to principal.");
+        emit!(BondCancelled {
+            bond_id: bond.bond_id.clone(),
+            principal: bond.principal,
+            refunded: vault_balance + bond.to_account_info().lamports(),
+        });
 
-        } else if Clock::get()?.unix_timestamp > bond.deadline + 86400 {
-            // Deadline passed + 24hr grace period: auto-slash if no quorum
-            bond.status = BondStatus::Slashed;
-            bond.completed_at = Clock::get()?.unix_timestamp;
+        msg!("Bond cancelled by principal: {}", bond.bond_id);
+        Ok(())
+    }
 
-            let bond_key = bond.key();
-            let seeds = &[
-                b"bond_vault",
-                bond_key.as_ref(),
-                &[bond.bump],
-            ];
-            let signer = &[&seeds[..]];
+    /// Agent pre-funds their personal staking vault for future bonds
+    pub fn deposit_stake(ctx: Context<ManageStakingVault>, amount: u64) -> Result<()> {
+        require!(amount > 0, AgberoError::InvalidStakeAmount);
 
-            let cpi_context = CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.bond_vault.to_account_info(),
-                    to: ctx.accounts.principal.to_account_info(),
-                },
-                signer,
-            );
-            system_program::transfer(cpi_context, vault_balance)?;
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.agent.to_account_info(),
+                to: ctx.accounts.staking_vault.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_context, amount)?;
 
-            emit!(BondSlashed {
-                bond_id: bond.bond_id.clone(),
-                agent: bond.agent,
-                principal: bond.principal,
-                amount_slashed: vault_balance,
-            });
+        emit!(StakingVaultDeposited {
+            agent: ctx.accounts.agent.key(),
+            amount,
+            balance: ctx.accounts.staking_vault.lamports(),
+        });
+        Ok(())
+    }
 
-            msg!("Bond auto-slashed due to deadline expiration.");
-        } else {
-            return Err(AgberoError::QuorumNotReached.into());
-        }
+    /// Agent withdraws unallocated lamports from their staking vault
+    pub fn withdraw_stake(ctx: Context<ManageStakingVault>, amount: u64) -> Result<()> {
+        require!(amount > 0, AgberoError::InvalidStakeAmount);
+        require_vault_remainder(ctx.accounts.staking_vault.lamports(), amount)?;
 
+        let agent_key = ctx.accounts.agent.key();
+        transfer_signed(
+            &ctx.accounts.system_program,
+            &ctx.accounts.staking_vault,
+            &ctx.accounts.agent.to_account_info(),
+            &[b"staking_vault", agent_key.as_ref(), &[ctx.bumps.staking_vault]],
+            amount,
+        )?;
+
+        emit!(StakingVaultWithdrawn {
+            agent: agent_key,
+            amount,
+            balance: ctx.accounts.staking_vault.lamports(),
+        });
+        Ok(())
+    }
+
+    /// Agent signals that work on a staked bond has begun (SLA tracking)
+    pub fn begin_work(ctx: Context<BeginWork>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+
+        require!(bond.status == BondStatus::Active, AgberoError::InvalidBondStatus);
+        require!(bond.started_at == 0, AgberoError::WorkAlreadyStarted);
+
+        bond.started_at = Clock::get()?.unix_timestamp;
+
+        emit!(WorkStarted {
+            bond_id: bond.bond_id.clone(),
+            agent: bond.agent,
+            started_at: bond.started_at,
+        });
         Ok(())
     }
 
-    /// Emergency slash by principal (with delay for agent appeal)
-    /// This is for clear-cut scam cases
-    pub fn emergency_slash(ctx: Context<EmergencySlash>) -> Result<()> {
+    /// Agent submits proof of completion
+    /// Resubmissions append to the evidence trail rather than overwrite it
+    pub fn submit_proof(ctx: Context<SubmitProof>, proof_uri: String) -> Result<()> {
         let bond = &mut ctx.accounts.bond;
         
         require!(
@@ -288,291 +698,7537 @@ pub mod agbero {
             AgberoError::InvalidBondStatus
         );
         require!(
-            ctx.accounts.principal.key() == bond.principal,
-            AgberoError::UnauthorizedPrincipal
+            ctx.accounts.agent.key() == bond.proof_submitter(),
+            AgberoError::NotTeamLead
+        );
+        require!(
+            !bond.require_begin_work || bond.started_at > 0,
+            AgberoError::WorkNotStarted
+        );
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now <= bond.deadline.saturating_add(ctx.accounts.config.proof_grace),
+            AgberoError::DeadlineExceeded
+        );
+        require!(proof_uri.len() <= 200, AgberoError::ProofUriTooLong);
+        require!(
+            bond.proofs.len() < Bond::MAX_PROOFS,
+            AgberoError::ProofLimitReached
         );
 
-        // In production: add 24hr appeal window
-        // For MVP: immediate slash with reputation penalty
+        let late = now > bond.deadline;
+        bond.proofs.push(ProofEntry {
+            uri: proof_uri.clone(),
+            submitted_at: now,
+            late,
+        });
 
-        bond.status = BondStatus::Slashed;
-        bond.completed_at = Clock::get()?.unix_timestamp;
+        if bond.status == BondStatus::Active {
+            bond.status = BondStatus::PendingVerification;
 
-        let vault_balance = ctx.accounts.bond_vault.lamports();
-        let bond_key = bond.key();
-        let seeds = &[
-            b"bond_vault",
-            bond_key.as_ref(),
-            &[bond.bump],
-        ];
-        let signer = &[&seeds[..]];
+            // Commit-reveal bonds open the commit phase as soon as proof lands
+            if bond.commit_reveal_enabled() {
+                bond.commit_deadline = now + bond.commit_window;
+                bond.reveal_deadline = bond.commit_deadline + bond.reveal_window;
+            }
 
-        let cpi_context = CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.bond_vault.to_account_info(),
-                to: ctx.accounts.principal.to_account_info(),
-            },
-            signer,
-        );
-        system_program::transfer(cpi_context, vault_balance)?;
+            // Verifiers get the same window however early or late proof arrived
+            let verification_duration = ctx.accounts.config.verification_duration;
+            if verification_duration > 0 {
+                bond.verification_deadline = now.saturating_add(verification_duration);
+            }
 
-        emit!(BondSlashed {
+            // Sub-committee members formed before proof start their clocks now
+            let assignment_window = ctx.accounts.config.assignment_window;
+            if assignment_window > 0 {
+                let deadline = now.saturating_add(assignment_window);
+                bond.assignment_deadlines.iter_mut().for_each(|d| *d = deadline);
+            }
+        }
+
+        emit!(ProofSubmitted {
             bond_id: bond.bond_id.clone(),
             agent: bond.agent,
-            principal: bond.principal,
-            amount_slashed: vault_balance,
+            proof_uri,
+            proof_index: (bond.proofs.len() - 1) as u8,
+            late,
         });
 
-        msg!("Emergency slash executed for bond: {}", bond.bond_id);
+        msg!("Proof submitted for bond: {}", bond.bond_id);
         Ok(())
     }
-}
 
-#[derive(Accounts)]
-#[instruction(bond_id: String)]
-pub struct CreateBond<'info> {
-    #[account(mut)]
-    pub principal: Signer<'info>,
-    /// CHECK: Agent pubkey, verified in logic
-    pub agent: AccountInfo<'info>,
-    
-    #[account(
+    /// Verifier votes on bond completion (yes/no)
+    /// For MVP: anyone can verify (decentralized oracle network)
+    pub fn verify_work(ctx: Context<VerifyWork>, approve: bool) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        let signer = ctx.accounts.verifier.key();
+        let voter = cast_public_vote(bond, signer, approve, ctx.remaining_accounts)?;
+
+        emit!(WorkVerified {
+            bond_id: bond.bond_id.clone(),
+            verifier: voter,
+            approve,
+            cast_by: signer,
+        });
+
+        msg!("Verification vote recorded for bond: {}", bond.bond_id);
+        Ok(())
+    }
+
+    /// A DAO's governance authority records the outcome of its own on-chain
+    /// vote on a bond. Typically signed by the governance PDA when the
+    /// passed proposal executes; dao_verify turns it into a verifier vote.
+    /// Once per version of the task: an amend_task lets it decide again
+    pub fn resolve_dao_decision(ctx: Context<ResolveDaoDecision>, approve: bool) -> Result<()> {
+        // Only an account of the configured governance program can sign
+        // here, i.e. a governance PDA executing a passed proposal
+        let governance_program = ctx.accounts.config.governance_program;
+        require!(
+            governance_program != Pubkey::default()
+                && *ctx.accounts.governance.owner == governance_program,
+            AgberoError::UnauthorizedGovernance
+        );
+
+        let task_hash = keccak::hash(ctx.accounts.bond.task_description.as_bytes()).0;
+        let decision = &mut ctx.accounts.decision;
+        require!(
+            decision.governance == Pubkey::default() || decision.task_hash != task_hash,
+            AgberoError::DaoDecisionAlreadyResolved
+        );
+        decision.governance = ctx.accounts.governance.key();
+        decision.bond = ctx.accounts.bond.key();
+        decision.task_hash = task_hash;
+        decision.approve = approve;
+        decision.resolved_at = Clock::get()?.unix_timestamp;
+        decision.recorded = false;
+        decision.bump = ctx.bumps.decision;
+
+        emit!(DaoDecisionResolved {
+            bond_id: ctx.accounts.bond.bond_id.clone(),
+            governance: decision.governance,
+            approve,
+        });
+        Ok(())
+    }
+
+    /// Cast a resolved DAO decision as the governance account's single
+    /// verifier vote (permissionless, so any member can crank it)
+    pub fn dao_verify(ctx: Context<DaoVerify>) -> Result<()> {
+        let decision = &mut ctx.accounts.decision;
+        // A decision on the task as it stood before an amend_task is void
+        require!(
+            decision.task_hash == keccak::hash(ctx.accounts.bond.task_description.as_bytes()).0,
+            AgberoError::StaleDaoDecision
+        );
+        require!(!decision.recorded, AgberoError::AlreadyVoted);
+        let governance = decision.governance;
+        let approve = decision.approve;
+        decision.recorded = true;
+
+        let bond = &mut ctx.accounts.bond;
+        let voter = cast_public_vote(bond, governance, approve, ctx.remaining_accounts)?;
+
+        emit!(WorkVerified {
+            bond_id: bond.bond_id.clone(),
+            verifier: voter,
+            approve,
+            cast_by: governance,
+        });
+
+        msg!("DAO vote recorded for bond: {}", bond.bond_id);
+        Ok(())
+    }
+
+    /// Slash vote with a written justification kept in slash_votes. It is
+    /// also cast as an ordinary slash vote, so finalize_bond counts it
+    /// toward the slash quorum like any other
+    pub fn vote_slash(ctx: Context<VerifyWork>, reason: String) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        let signer = ctx.accounts.verifier.key();
+        require!(reason.len() <= SlashVote::MAX_REASON, AgberoError::SlashReasonTooLong);
+
+        // One reasoned vote per member, whether cast directly or by proxy
+        let member = bond.represented_member(&signer).unwrap_or(signer);
+        require!(!bond.has_voted(&member), AgberoError::AlreadyVoted);
+
+        let voter = cast_public_vote(bond, signer, false, ctx.remaining_accounts)?;
+        bond.slash_votes.push(SlashVote {
+            voter,
+            reason: reason.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        emit!(WorkVerified {
+            bond_id: bond.bond_id.clone(),
+            verifier: voter,
+            approve: false,
+            cast_by: signer,
+        });
+        emit!(SlashVoteCast {
+            bond_id: bond.bond_id.clone(),
+            voter,
+            reason,
+        });
+
+        msg!("Reasoned slash vote recorded for bond: {}", bond.bond_id);
+        Ok(())
+    }
+
+    /// Relayer posts a bundle of votes the verifiers signed off-chain
+    /// Each vote needs a matching Ed25519 program instruction earlier in the
+    /// same transaction over vote_bundle_message(bond, task hash, approve)
+    pub fn submit_vote_bundle(
+        ctx: Context<SubmitVoteBundle>,
+        votes: Vec<BundledVote>,
+    ) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        let submitter = ctx.accounts.submitter.key();
+
+        require!(
+            bond.status == BondStatus::PendingVerification,
+            AgberoError::InvalidBondStatus
+        );
+        require!(!bond.commit_reveal_enabled(), AgberoError::PublicVotingDisabled);
+        // A bundle must carry a quorum on its own
+        require!(
+            votes.len() as u64 >= ctx.accounts.config.min_votes
+                && bond.verification_votes.len() + votes.len() <= Bond::MAX_VOTES,
+            AgberoError::InvalidVoteBundle
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            bond.verification_deadline == 0 || now <= bond.verification_deadline,
+            AgberoError::VerificationWindowClosed
+        );
+
+        let signed = ed25519_signed_messages(&ctx.accounts.instructions)?;
+        let bond_key = bond.key();
+        // Binding the task text keeps signatures from before an amend_task from replaying
+        let task_hash = keccak::hash(bond.task_description.as_bytes()).0;
+        for (i, vote) in votes.iter().enumerate() {
+            require!(vote.verifier != bond.agent, AgberoError::AgentCannotVerify);
+            require!(
+                bond.is_eligible_verifier(&vote.verifier),
+                AgberoError::NotInSubcommittee
+            );
+            require!(
+                !bond.has_voted(&vote.verifier)
+                    && !votes[..i].iter().any(|v| v.verifier == vote.verifier),
+                AgberoError::AlreadyVoted
+            );
+            VerifierProfile::record_for(bond, &vote.verifier, vote.approve, ctx.remaining_accounts)?;
+            let message = vote_bundle_message(&bond_key, &task_hash, vote.approve);
+            require!(
+                signed.contains(&(vote.verifier, message)),
+                AgberoError::MissingVoteSignature
+            );
+
+            bond.verification_votes.push(VerificationVote {
+                verifier: vote.verifier,
+                approve: vote.approve,
+                timestamp: now,
+                cast_by: submitter,
+                excluded: false,
+                evidence: false,
+                stake: VerifierStake::amount_for(&bond_key, &vote.verifier, ctx.remaining_accounts)?,
+            });
+            emit!(WorkVerified {
+                bond_id: bond.bond_id.clone(),
+                verifier: vote.verifier,
+                approve: vote.approve,
+                cast_by: submitter,
+            });
+        }
+
+        msg!("Recorded {} bundled votes for bond: {}", votes.len(), bond.bond_id);
+        Ok(())
+    }
+
+    /// Verifier commits to a hidden vote during the commit phase
+    /// hash = keccak(approve || salt || verifier)
+    pub fn commit_vote(ctx: Context<CommitVote>, vote_hash: [u8; 32]) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        let verifier = ctx.accounts.verifier.key();
+
+        require!(
+            bond.status == BondStatus::PendingVerification,
+            AgberoError::InvalidBondStatus
+        );
+        require!(bond.commit_reveal_enabled(), AgberoError::CommitRevealDisabled);
+        require!(verifier != bond.agent, AgberoError::AgentCannotVerify);
+        require!(
+            bond.is_eligible_verifier(&verifier),
+            AgberoError::NotInSubcommittee
+        );
+        VerifierProfile::require_category(bond, &verifier, ctx.remaining_accounts)?;
+        require!(
+            Clock::get()?.unix_timestamp <= bond.commit_deadline,
+            AgberoError::CommitPhaseClosed
+        );
+        require!(
+            !bond.vote_commits.iter().any(|c| c.verifier == verifier),
+            AgberoError::AlreadyCommitted
+        );
+        require!(
+            bond.vote_commits.len() < Bond::MAX_COMMITS,
+            AgberoError::CommitLimitReached
+        );
+
+        bond.vote_commits.push(VoteCommit {
+            verifier,
+            vote_hash,
+            revealed: false,
+        });
+
+        emit!(VoteCommitted {
+            bond_id: bond.bond_id.clone(),
+            verifier,
+        });
+
+        msg!("Vote commitment recorded for bond: {}", bond.bond_id);
+        Ok(())
+    }
+
+    /// Verifier reveals a committed vote during the reveal phase
+    /// Only reveals matching their commitment are counted
+    pub fn reveal_vote(ctx: Context<RevealVote>, approve: bool, salt: [u8; 32]) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        let verifier = ctx.accounts.verifier.key();
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            bond.status == BondStatus::PendingVerification,
+            AgberoError::InvalidBondStatus
+        );
+        require!(bond.commit_reveal_enabled(), AgberoError::CommitRevealDisabled);
+        require!(
+            now > bond.commit_deadline && now <= bond.reveal_deadline,
+            AgberoError::RevealPhaseNotActive
+        );
+
+        let commit = bond
+            .vote_commits
+            .iter_mut()
+            .find(|c| c.verifier == verifier)
+            .ok_or(AgberoError::CommitNotFound)?;
+        require!(!commit.revealed, AgberoError::AlreadyRevealed);
+        require!(
+            commit.vote_hash == vote_commitment(approve, &salt, &verifier),
+            AgberoError::CommitMismatch
+        );
+        commit.revealed = true;
+        VerifierProfile::record_for(bond, &verifier, approve, ctx.remaining_accounts)?;
+
+        let stake = VerifierStake::amount_for(&bond.key(), &verifier, ctx.remaining_accounts)?;
+        bond.verification_votes.push(VerificationVote {
+            verifier,
+            approve,
+            timestamp: now,
+            cast_by: verifier,
+            excluded: false,
+            evidence: false,
+            stake,
+        });
+
+        emit!(WorkVerified {
+            bond_id: bond.bond_id.clone(),
+            verifier,
+            approve,
+            cast_by: verifier,
+        });
+
+        msg!("Vote revealed for bond: {}", bond.bond_id);
+        Ok(())
+    }
+
+    /// Either party fixes a slot a few slots ahead whose hash will seed the
+    /// bond's sub-committee draw; nobody knows that hash yet, so nobody can
+    /// pick it. Repeatable only if the slot aged out before anyone drew
+    pub fn request_subcommittee(ctx: Context<RequestSubcommittee>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        let party = ctx.accounts.party.key();
+
+        require!(
+            party == bond.principal || party == bond.agent,
+            AgberoError::NotBondParty
+        );
+        require!(
+            matches!(
+                bond.status,
+                BondStatus::Pending | BondStatus::Active | BondStatus::PendingVerification
+            ),
+            AgberoError::InvalidBondStatus
+        );
+        require!(
+            bond.verification_votes.is_empty() && bond.vote_commits.is_empty(),
+            AgberoError::VotingAlreadyStarted
+        );
+        require!(bond.subcommittee.is_empty(), AgberoError::SubcommitteeAlreadyFormed);
+        require!(ctx.accounts.config.subcommittee_size > 0, AgberoError::InvalidSubcommittee);
+
+        let slot = Clock::get()?.slot;
+        require!(
+            bond.subcommittee_draw_slot == 0
+                || slot > bond.subcommittee_draw_slot.saturating_add(MAX_SLOT_HASHES as u64),
+            AgberoError::SubcommitteeAlreadyRequested
+        );
+        bond.subcommittee_draw_slot = slot + Bond::SUBCOMMITTEE_DRAW_DELAY;
+
+        emit!(SubcommitteeRequested {
+            bond_id: bond.bond_id.clone(),
+            draw_slot: bond.subcommittee_draw_slot,
+        });
+        Ok(())
+    }
+
+    /// Anyone draws the requested sub-committee of Config::subcommittee_size
+    /// from all registered verifiers, seeded by the requested slot's hash.
+    /// Drawn verifiers' profiles are passed in remaining_accounts; only
+    /// members may vote, and the committee is formed once
+    pub fn form_subcommittee(ctx: Context<FormSubcommittee>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+
+        require!(
+            matches!(
+                bond.status,
+                BondStatus::Pending | BondStatus::Active | BondStatus::PendingVerification
+            ),
+            AgberoError::InvalidBondStatus
+        );
+        require!(
+            bond.verification_votes.is_empty() && bond.vote_commits.is_empty(),
+            AgberoError::VotingAlreadyStarted
+        );
+        require!(bond.subcommittee.is_empty(), AgberoError::SubcommitteeAlreadyFormed);
+        require!(bond.subcommittee_draw_slot > 0, AgberoError::SubcommitteeNotRequested);
+
+        // Rejection sampling over profile indices: the agent, the principal
+        // and repeats are redrawn. Not VRF-grade: the leader of the draw slot
+        // could still bias it, which is acceptable for spreading load
+        let clock = Clock::get()?;
+        let slot_hash = slot_hash_from(&ctx.accounts.slot_hashes, bond.subcommittee_draw_slot)?;
+        let mut seed = keccak::hashv(&[bond.key().as_ref(), &slot_hash]).0;
+        let profiles = VerifierProfile::from_accounts(ctx.remaining_accounts);
+        let registered = ctx.accounts.stats.registered_verifiers;
+        let size = ctx.accounts.config.subcommittee_size as usize;
+        require!(size > 0, AgberoError::InvalidSubcommittee);
+
+        let mut members = Vec::with_capacity(size);
+        let mut attempts = 0;
+        while members.len() < size {
+            require!(attempts < Bond::MAX_DRAW_ATTEMPTS, AgberoError::InvalidSubcommittee);
+            attempts += 1;
+            let drawn = draw_verifier(&mut seed, registered, &profiles)?;
+            if drawn != bond.agent && drawn != bond.principal && !members.contains(&drawn) {
+                members.push(drawn);
+            }
+        }
+        bond.subcommittee = members;
+        bond.subcommittee_seed = seed;
+
+        // Members' clocks start once there is proof to verify
+        let window = ctx.accounts.config.assignment_window;
+        let deadline = if window > 0 && bond.status == BondStatus::PendingVerification {
+            clock.unix_timestamp.saturating_add(window)
+        } else {
+            0
+        };
+        bond.assignment_deadlines = vec![deadline; bond.subcommittee.len()];
+
+        emit!(SubcommitteeFormed {
+            bond_id: bond.bond_id.clone(),
+            members: bond.subcommittee.clone(),
+        });
+
+        msg!("Sub-committee of {} formed for bond: {}", size, bond.bond_id);
+        Ok(())
+    }
+
+    /// Hand an idle sub-committee member's slot to the next registered
+    /// verifier the bond's draw yields, whose profile is passed in
+    /// remaining_accounts. Permissionless once the member's assignment
+    /// deadline passes unvoted; the caller has no say in who takes over
+    pub fn reassign_inactive(ctx: Context<ReassignInactive>, member: Pubkey) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            bond.status == BondStatus::PendingVerification,
+            AgberoError::InvalidBondStatus
+        );
+        let slot = bond
+            .subcommittee
+            .iter()
+            .position(|m| *m == member)
+            .ok_or(AgberoError::NotInSubcommittee)?;
+        let deadline = bond.assignment_deadlines.get(slot).copied().unwrap_or(0);
+        require!(
+            deadline > 0 && now > deadline,
+            AgberoError::AssignmentNotExpired
+        );
+        require!(
+            !bond.has_voted(&member) && !bond.vote_commits.iter().any(|c| c.verifier == member),
+            AgberoError::AlreadyVoted
+        );
+
+        // The draw carries on from where form_subcommittee (or the last
+        // reassignment) left it
+        let profiles = VerifierProfile::from_accounts(ctx.remaining_accounts);
+        let registered = ctx.accounts.stats.registered_verifiers;
+        let mut seed = bond.subcommittee_seed;
+        let mut replacement = None;
+        for _ in 0..Bond::MAX_DRAW_ATTEMPTS {
+            let drawn = draw_verifier(&mut seed, registered, &profiles)?;
+            if drawn != bond.agent && drawn != bond.principal && !bond.subcommittee.contains(&drawn) {
+                replacement = Some(drawn);
+                break;
+            }
+        }
+        let replacement = replacement.ok_or(AgberoError::InvalidSubcommittee)?;
+
+        bond.subcommittee_seed = seed;
+        bond.subcommittee[slot] = replacement;
+        bond.assignment_deadlines[slot] = now.saturating_add(ctx.accounts.config.assignment_window);
+
+        emit!(VerifierReassigned {
+            bond_id: bond.bond_id.clone(),
+            previous: member,
+            replacement,
+        });
+        Ok(())
+    }
+
+    /// Verifier designates a proxy to vote on their behalf while unavailable.
+    /// The proxy's vote is recorded under the member; only one of them counts.
+    pub fn set_proxy(ctx: Context<SetProxy>, proxy: Pubkey) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        let member = ctx.accounts.member.key();
+
+        require!(
+            bond.status == BondStatus::Active || bond.status == BondStatus::PendingVerification,
+            AgberoError::InvalidBondStatus
+        );
+        require!(
+            bond.vote_mode == VoteMode::Public,
+            AgberoError::PublicVotingDisabled
+        );
+        require!(member != bond.agent, AgberoError::AgentCannotVerify);
+        require!(
+            bond.is_eligible_verifier(&member),
+            AgberoError::NotInSubcommittee
+        );
+        require!(!bond.has_voted(&member), AgberoError::AlreadyVoted);
+        require!(
+            proxy != member && proxy != bond.agent && proxy != bond.principal,
+            AgberoError::InvalidProxy
+        );
+        // A proxy stands in for at most one member, otherwise its vote is ambiguous
+        require!(
+            !matches!(bond.represented_member(&proxy), Some(m) if m != member),
+            AgberoError::InvalidProxy
+        );
+
+        match bond.proxies.iter_mut().find(|p| p.member == member) {
+            Some(existing) => existing.proxy = proxy,
+            None => {
+                require!(
+                    bond.proxies.len() < Bond::MAX_PROXIES,
+                    AgberoError::ProxyLimitReached
+                );
+                bond.proxies.push(ProxyDesignation { member, proxy });
+            }
+        }
+
+        emit!(ProxySet {
+            bond_id: bond.bond_id.clone(),
+            member,
+            proxy,
+        });
+
+        msg!("Proxy set for bond: {}", bond.bond_id);
+        Ok(())
+    }
+
+    /// Verifier locks lamports behind their vote on a stake-weighted bond
+    /// Top-ups accumulate; the stake is locked until the bond is finalized
+    pub fn stake_verifier(ctx: Context<StakeVerifier>, amount: u64) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+
+        require!(amount > 0, AgberoError::InvalidStakeAmount);
+        require!(!bond.is_terminal(), AgberoError::InvalidBondStatus);
+        require!(
+            ctx.accounts.verifier.key() != bond.agent,
+            AgberoError::AgentCannotVerify
+        );
+        // A vote snapshots the stake behind it, so stake goes in first
+        require!(
+            !bond.has_voted(&ctx.accounts.verifier.key()),
+            AgberoError::StakeAfterVote
+        );
+
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.verifier.to_account_info(),
+                to: ctx.accounts.verifier_stake.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_context, amount)?;
+
+        let stake = &mut ctx.accounts.verifier_stake;
+        if stake.amount == 0 {
+            bond.open_stakes += 1;
+        }
+        stake.bond = bond.key();
+        stake.verifier = ctx.accounts.verifier.key();
+        stake.amount += amount;
+        stake.bump = ctx.bumps.verifier_stake;
+
+        emit!(VerifierStaked {
+            bond_id: bond.bond_id.clone(),
+            verifier: stake.verifier,
+            amount: stake.amount,
+        });
+        Ok(())
+    }
+
+    /// Return an evidence record's rent to its verifier once the bond is
+    /// final or gone (permissionless)
+    pub fn close_evidence(ctx: Context<CloseEvidence>) -> Result<()> {
+        let bond = &ctx.accounts.bond;
+        if bond.owner == &crate::ID && !bond.data_is_empty() {
+            let bond = Bond::try_deserialize(&mut &bond.data.borrow()[..])?;
+            require!(bond.is_terminal(), AgberoError::InvalidBondStatus);
+        }
+        Ok(())
+    }
+
+    /// Return a verifier's stake and the account rent once the bond is
+    /// final. Anyone may call it; the lamports only go to the verifier.
+    /// If the verifiers' vote decided the bond, a losing verifier forfeits
+    /// stake_forfeit_bps of their stake into the bond account, and a winner
+    /// also takes a stake-weighted share of those forfeits once all of
+    /// them are in.
+    pub fn reclaim_verifier_stake(ctx: Context<ReclaimVerifierStake>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        require!(bond.is_terminal(), AgberoError::InvalidBondStatus);
+
+        let verifier = ctx.accounts.verifier.key();
+        bond.open_stakes -= 1;
+        let vote = bond
+            .counted_votes()
+            .find(|v| v.verifier == verifier)
+            .map(|v| (v.approve, v.stake));
+        let (mut forfeited, mut share) = (0, 0);
+        if let (Some((approve, staked)), Some(side)) = (vote, bond.vote_outcome()) {
+            let (pool, winning_stake) = bond.forfeit_split();
+            if approve != side {
+                forfeited = bond.stake_forfeit(staked);
+                **ctx.accounts.verifier_stake.to_account_info().try_borrow_mut_lamports()? -= forfeited;
+                **bond.to_account_info().try_borrow_mut_lamports()? += forfeited;
+                bond.forfeits_collected += forfeited;
+            } else if pool > 0 {
+                require!(bond.forfeits_collected >= pool, AgberoError::ForfeitsOutstanding);
+                share = (pool as u128 * staked as u128)
+                    .checked_div(winning_stake as u128)
+                    .unwrap_or(0) as u64;
+                **bond.to_account_info().try_borrow_mut_lamports()? -= share;
+                **ctx.accounts.verifier.try_borrow_mut_lamports()? += share;
+            }
+        }
+
+        emit!(VerifierStakeReclaimed {
+            bond_id: bond.bond_id.clone(),
+            verifier,
+            returned: ctx.accounts.verifier_stake.amount - forfeited + share,
+            forfeited,
+        });
+        Ok(())
+    }
+
+    /// Pay a verifier the SOL reward and tip finalize parked in the bond
+    /// account because their wallet was not passed. Anyone may call it; the
+    /// lamports only go to the verifier
+    pub fn claim_deferred_reward(ctx: Context<ClaimDeferredReward>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        let verifier = ctx.accounts.verifier.key();
+
+        let bit = bond
+            .verification_votes
+            .iter()
+            .position(|v| v.verifier == verifier)
+            .map(|i| 1u16 << i)
+            .filter(|&bit| (bond.deferred_rewards | bond.deferred_tips) & bit != 0)
+            .ok_or(AgberoError::NoRewardDue)?;
+        let mut amount = 0;
+        if bond.deferred_rewards & bit != 0 {
+            bond.deferred_rewards &= !bit;
+            amount += bond.reward_share;
+        }
+        if bond.deferred_tips & bit != 0 {
+            bond.deferred_tips &= !bit;
+            amount += bond.tip_share;
+        }
+        **bond.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.verifier.try_borrow_mut_lamports()? += amount;
+
+        emit!(DeferredRewardClaimed {
+            bond_id: bond.bond_id.clone(),
+            verifier,
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Finalize bond based on verification votes
+    /// Autonomous execution: anyone can call this once quorum is reached
+    pub fn finalize_bond<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeBond<'info>>,
+    ) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        require!(!bond.on_hold, AgberoError::BondOnHold);
+        let vault_balance = bond.vault_funds(ctx.accounts.bond_vault.lamports());
+        let now = Clock::get()?.unix_timestamp;
+        let half_life = ctx.accounts.config.reputation_half_life;
+        ctx.accounts.reputation.init_if_new(bond.agent, ctx.bumps.reputation);
+
+        // A designated escrow co-signs every release, passed in remaining accounts
+        require!(
+            bond.escrow_cosigned(&ctx.accounts.executor.key(), ctx.remaining_accounts),
+            AgberoError::EscrowSignatureMissing
+        );
+        
+        require!(
+            bond.status == BondStatus::PendingVerification ||
+            bond.status == BondStatus::Active && now > bond.deadline,
+            AgberoError::InvalidBondStatus
+        );
+
+        // The keeper bounty and tip pool sit in the vault next to the collateral
+        let keeper_bounty = bond.keeper_bounty.min(vault_balance);
+        let tips = bond.tip_pool.min(vault_balance - keeper_bounty);
+        let payout = vault_balance - keeper_bounty - tips;
+
+        let snapshot =
+            bond.quorum_snapshot(&ctx.accounts.config, now)?;
+
+        if snapshot.winning_outcome == Some(true) {
+            // SUCCESS: Release stake to agent
+            bond.status = BondStatus::Completed;
+            bond.completed_at = now;
+            bond.score = bond.compute_score();
+
+            // Verifiers who approved take their cut before the agent is paid
+            let reward = pay_verifier_rewards(
+                &ctx.accounts.system_program,
+                &ctx.accounts.bond_vault,
+                bond,
+                &ctx.accounts.config,
+                payout,
+                ctx.remaining_accounts,
+            )?;
+            let released = payout - reward;
+
+            // Transfer collateral back to agent and any team members
+            release_to_team(
+                &ctx.accounts.system_program,
+                &ctx.accounts.bond_vault,
+                &ctx.accounts.agent.to_account_info(),
+                bond,
+                released,
+                ctx.remaining_accounts,
+            )?;
+            ctx.accounts.stats.record_released(bond.collateral_amount);
+            ctx.accounts.reputation.record_completed(now, half_life, bond.collateral_amount);
+
+            bond.record_settlement(0, released);
+            emit!(BondCompleted {
+                bond_id: bond.bond_id.clone(),
+                agent: bond.agent,
+                stake_released: released,
+                final_proof_uri: bond
+                    .latest_proof()
+                    .map(|p| p.uri.clone())
+                    .unwrap_or_default(),
+            });
+
+            msg!("Bond completed successfully. Stake released.");
+
+        } else if snapshot.winning_outcome == Some(false) {
+            // FAILURE: Slash stake to principal
+            require!(!ctx.accounts.config.paused, AgberoError::ProgramPaused);
+            bond.status = BondStatus::Slashed;
+            bond.completed_at = now;
+            let reason = SlashReason::MajorityVote;
+            bond.slash_reason = Some(reason);
+
+            // Partial slashing takes its cut from the buffer first and
+            // returns whatever is left to the agent's side
+            let (from_buffer, from_base) =
+                bond.partial_slash_split(payout, ctx.accounts.config.partial_slash_bps);
+            let slashed = from_buffer + from_base;
+            bond.buffer_amount -= from_buffer;
+
+            let premium = insurance_share(
+                slashed,
+                ctx.accounts.config.insurance_bps,
+                ctx.accounts.insurance_pool.lamports(),
+            )?;
+            if premium > 0 {
+                transfer_from_vault(
+                    &ctx.accounts.system_program,
+                    &ctx.accounts.bond_vault,
+                    &ctx.accounts.insurance_pool.to_account_info(),
+                    &bond.key(),
+                    bond.vault_bump,
+                    premium,
+                )?;
+                emit!(InsuranceFunded {
+                    bond_id: bond.bond_id.clone(),
+                    amount: premium,
+                });
+            }
+            let fee = pay_protocol_fee(
+                &ctx.accounts.system_program,
+                &ctx.accounts.bond_vault,
+                bond,
+                &ctx.accounts.config,
+                slashed,
+                ctx.remaining_accounts,
+            )?;
+            pay_slash(
+                &ctx.accounts.system_program,
+                &ctx.accounts.bond_vault,
+                &ctx.accounts.principal.to_account_info(),
+                bond,
+                &ctx.accounts.config,
+                slashed - premium - fee,
+                ctx.remaining_accounts,
+            )?;
+            if payout > slashed {
+                release_to_team(
+                    &ctx.accounts.system_program,
+                    &ctx.accounts.bond_vault,
+                    &ctx.accounts.agent.to_account_info(),
+                    bond,
+                    payout - slashed,
+                    ctx.remaining_accounts,
+                )?;
+            }
+            ctx.accounts.stats.record_slashed(bond.collateral_amount, slashed);
+            ctx.accounts.reputation.record_slashed(now, half_life);
+            track_slash_volume(&mut ctx.accounts.config, &mut ctx.accounts.stats)?;
+
+            msg!("Slashed {} from buffer and {} from base", from_buffer, from_base);
+            bond.record_settlement(slashed, payout - slashed);
+            emit!(BondSlashed {
+                bond_id: bond.bond_id.clone(),
+                agent: bond.agent,
+                principal: bond.principal,
+                amount_slashed: slashed,
+                reason,
+                justification: String::new(),
+            });
+
+            msg!("Bond slashed! Stake transferred to principal.");
+
+        } else if snapshot.tied {
+            // Tie rule: innocent until proven guilty. A quorum split evenly
+            // has not shown the work failed, so the collateral goes back to
+            // the agent with no reputation credit either way, instead of
+            // waiting out the window into a no-quorum auto-slash
+            bond.status = BondStatus::Expired;
+            bond.completed_at = now;
+
+            release_to_team(
+                &ctx.accounts.system_program,
+                &ctx.accounts.bond_vault,
+                &ctx.accounts.agent.to_account_info(),
+                bond,
+                payout,
+                ctx.remaining_accounts,
+            )?;
+            ctx.accounts.stats.record_expired(bond.collateral_amount);
+
+            bond.record_settlement(0, payout);
+            emit!(VoteTied {
+                bond_id: bond.bond_id.clone(),
+                agent: bond.agent,
+                approve_votes: snapshot.approve_votes,
+                slash_votes: snapshot.slash_votes,
+                amount_refunded: payout,
+            });
+
+            msg!("Verification tied. Stake refunded to agent.");
+
+        } else if now > bond.timeout_at(ctx.accounts.config.timeout_grace)?
+            && bond.proofs.is_empty()
+            && ctx.accounts.config.no_proof_outcome == NoProofOutcome::Refund
+        {
+            // No proof and therefore no votes: benefit-of-the-doubt deployments
+            // return the collateral instead of slashing
+            bond.status = BondStatus::Expired;
+            bond.completed_at = now;
+
+            release_to_team(
+                &ctx.accounts.system_program,
+                &ctx.accounts.bond_vault,
+                &ctx.accounts.agent.to_account_info(),
+                bond,
+                payout,
+                ctx.remaining_accounts,
+            )?;
+            ctx.accounts.stats.record_expired(bond.collateral_amount);
+
+            bond.record_settlement(0, payout);
+            emit!(BondExpired {
+                bond_id: bond.bond_id.clone(),
+                agent: bond.agent,
+                amount_refunded: payout,
+            });
+
+            msg!("Bond expired without proof. Stake refunded to agent.");
+
+        } else if now > bond.timeout_at(ctx.accounts.config.timeout_grace)? {
+            // Verification window (or deadline + grace) passed: auto-slash if no quorum
+            require!(!ctx.accounts.config.paused, AgberoError::ProgramPaused);
+            bond.status = BondStatus::Slashed;
+            bond.completed_at = now;
+            let reason = if bond.proofs.is_empty() {
+                SlashReason::TimeoutNoProof
+            } else {
+                SlashReason::TimeoutNoQuorum
+            };
+            bond.slash_reason = Some(reason);
+
+            let premium = insurance_share(
+                payout,
+                ctx.accounts.config.insurance_bps,
+                ctx.accounts.insurance_pool.lamports(),
+            )?;
+            if premium > 0 {
+                transfer_from_vault(
+                    &ctx.accounts.system_program,
+                    &ctx.accounts.bond_vault,
+                    &ctx.accounts.insurance_pool.to_account_info(),
+                    &bond.key(),
+                    bond.vault_bump,
+                    premium,
+                )?;
+                emit!(InsuranceFunded {
+                    bond_id: bond.bond_id.clone(),
+                    amount: premium,
+                });
+            }
+            let fee = pay_protocol_fee(
+                &ctx.accounts.system_program,
+                &ctx.accounts.bond_vault,
+                bond,
+                &ctx.accounts.config,
+                payout,
+                ctx.remaining_accounts,
+            )?;
+            pay_slash(
+                &ctx.accounts.system_program,
+                &ctx.accounts.bond_vault,
+                &ctx.accounts.principal.to_account_info(),
+                bond,
+                &ctx.accounts.config,
+                payout - premium - fee,
+                ctx.remaining_accounts,
+            )?;
+            if reason == SlashReason::TimeoutNoProof && bond.principal_premium_paid > 0 {
+                // Agent absconded: paid-for cover tops up what the slash
+                // did not recover, as far as the pool can currently back it
+                let recovered = match bond.slash_destination {
+                    SlashDestination::Principal => payout - premium - fee,
+                    _ => 0,
+                };
+                let available = ctx
+                    .accounts
+                    .insurance_pool
+                    .lamports()
+                    .saturating_sub(Rent::get()?.minimum_balance(0));
+                bond.insurance_due = bond.insured_amount.saturating_sub(recovered).min(available);
+            }
+            ctx.accounts.stats.record_slashed(bond.collateral_amount, payout);
+            ctx.accounts.reputation.record_slashed(now, half_life);
+            track_slash_volume(&mut ctx.accounts.config, &mut ctx.accounts.stats)?;
+
+            bond.record_settlement(payout, 0);
+            emit!(BondSlashed {
+                bond_id: bond.bond_id.clone(),
+                agent: bond.agent,
+                principal: bond.principal,
+                amount_slashed: payout,
+                reason,
+                justification: String::new(),
+            });
+
+            msg!("Bond auto-slashed due to deadline expiration.");
+        } else {
+            return Err(AgberoError::QuorumNotReached.into());
+        }
+
+        // One summary event per finalize, with the tally that decided it
+        let path = match (bond.status, bond.slash_reason) {
+            (BondStatus::Completed, _) => FinalizePath::QuorumApprove,
+            (_, Some(SlashReason::MajorityVote)) => FinalizePath::QuorumSlash,
+            (BondStatus::Slashed, _) => FinalizePath::AutoSlash,
+            _ if snapshot.tied => FinalizePath::Tied,
+            _ => FinalizePath::NoProofRefund,
+        };
+        emit!(BondFinalized {
+            bond_id: bond.bond_id.clone(),
+            status: bond.status,
+            total_votes: snapshot.total_votes,
+            approve_votes: snapshot.approve_votes,
+            slash_votes: snapshot.slash_votes,
+            path,
+        });
+
+        // Tips go to the side the vote settled on; timeouts refund them
+        let winning_side = bond.vote_outcome();
+        // Losing stakes are forfeited at the rate in force now; the
+        // lamports move as each stake is reclaimed
+        if winning_side.is_some() {
+            bond.stake_forfeit_bps = ctx.accounts.config.stake_forfeit_bps;
+        }
+        settle_tips(
+            &ctx.accounts.system_program,
+            &ctx.accounts.bond_vault,
+            &ctx.accounts.principal.to_account_info(),
+            bond,
+            tips,
+            winning_side,
+            ctx.remaining_accounts,
+        )?;
+
+        // Keeper is paid last: if any payout above fails, the whole
+        // instruction reverts before the bounty moves
+        if keeper_bounty > 0 {
+            transfer_from_vault(
+                &ctx.accounts.system_program,
+                &ctx.accounts.bond_vault,
+                &ctx.accounts.executor.to_account_info(),
+                &bond.key(),
+                bond.vault_bump,
+                keeper_bounty,
+            )?;
+
+            emit!(KeeperPaid {
+                bond_id: bond.bond_id.clone(),
+                keeper: ctx.accounts.executor.key(),
+                amount: keeper_bounty,
+            });
+        }
+
+        // Completion callback runs after every transfer, with the settled
+        // bond written back first so the callee sees its final state
+        if bond.status == BondStatus::Completed && bond.callback_approved {
+            if let Some(callback) = bond.callback.clone() {
+                ctx.accounts.bond.exit(&crate::ID)?;
+                invoke_callback(&callback, ctx.remaining_accounts)?;
+
+                emit!(CallbackInvoked {
+                    bond_id: ctx.accounts.bond.bond_id.clone(),
+                    program_id: callback.program_id,
+                });
+            }
+        }
+
+        // Deployments that don't archive bonds reclaim the rent right away;
+        // the vault was drained by the payouts above. A bond something is
+        // still owed through waits for close_bond instead
+        if ctx.accounts.config.auto_close_on_finalize
+            && !ctx.accounts.bond.has_outstanding_claims(now)
+        {
+            let vault_rent = release_vault_rent(
+                &ctx.accounts.system_program,
+                &ctx.accounts.bond_vault,
+                &ctx.accounts.principal.to_account_info(),
+                &ctx.accounts.bond,
+            )?;
+            emit_bond_closed(&ctx.accounts.bond, vault_rent);
+            ctx.accounts
+                .bond
+                .close(ctx.accounts.principal.to_account_info())?;
+        }
+
+        Ok(())
+    }
+
+    /// Crank entry point: finalizes like finalize_bond once the bond is
+    /// ready and otherwise succeeds without touching it, so a scheduler can
+    /// call it every slot. Returns whether the bond was finalized
+    pub fn try_finalize<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeBond<'info>>,
+    ) -> Result<bool> {
+        let now = Clock::get()?.unix_timestamp;
+        let bond = &ctx.accounts.bond;
+        let config = &ctx.accounts.config;
+        let snapshot =
+            bond.quorum_snapshot(config, now)?;
+        // Everything finalize_bond would refuse: not ready, a slash while
+        // paused, or a missing escrow co-signature
+        let ready = snapshot.can_finalize
+            && !(config.paused && bond.finalize_slashes(&snapshot, config, now)?)
+            && bond.escrow_cosigned(&ctx.accounts.executor.key(), ctx.remaining_accounts);
+        if !ready {
+            msg!("Bond {} not yet finalizable", ctx.accounts.bond.bond_id);
+            return Ok(false);
+        }
+        finalize_bond(ctx)?;
+        Ok(true)
+    }
+
+    /// Cast a public vote as the executor and, if it makes the bond
+    /// finalizable, finalize in the same instruction so the decisive
+    /// verifier collects the keeper bounty. Returns whether it finalized
+    pub fn verify_and_finalize<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeBond<'info>>,
+        approve: bool,
+    ) -> Result<bool> {
+        let signer = ctx.accounts.executor.key();
+        let bond = &mut ctx.accounts.bond;
+        let voter = cast_public_vote(bond, signer, approve, ctx.remaining_accounts)?;
+
+        emit!(WorkVerified {
+            bond_id: bond.bond_id.clone(),
+            verifier: voter,
+            approve,
+            cast_by: signer,
+        });
+
+        let now = Clock::get()?.unix_timestamp;
+        let snapshot =
+            bond.quorum_snapshot(&ctx.accounts.config, now)?;
+        if !snapshot.can_finalize {
+            msg!("Verification vote recorded for bond: {}", bond.bond_id);
+            return Ok(false);
+        }
+        finalize_bond(ctx)?;
+        Ok(true)
+    }
+
+    /// Arbiter strikes a fraudulent vote on a Disputed bond so its ruling
+    /// does not count it, optionally forfeiting that verifier's stake to the
+    /// principal
+    pub fn arbiter_invalidate_vote(
+        ctx: Context<ArbiterInvalidateVote>,
+        verifier: Pubkey,
+        slash_stake: bool,
+    ) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+
+        require!(bond.status == BondStatus::Disputed, AgberoError::InvalidBondStatus);
+        let vote = bond
+            .verification_votes
+            .iter_mut()
+            .find(|v| v.verifier == verifier)
+            .ok_or(AgberoError::VoteNotFound)?;
+        require!(!vote.excluded, AgberoError::VoteAlreadyExcluded);
+        vote.excluded = true;
+
+        let mut stake_slashed = 0;
+        if slash_stake {
+            let stake = ctx
+                .accounts
+                .verifier_stake
+                .as_mut()
+                .ok_or(AgberoError::VerifierStakeMissing)?;
+            stake_slashed = stake.amount;
+            stake.amount = 0;
+            **stake.to_account_info().try_borrow_mut_lamports()? -= stake_slashed;
+            **ctx.accounts.principal.try_borrow_mut_lamports()? += stake_slashed;
+        }
+
+        emit!(VoteInvalidated {
+            bond_id: bond.bond_id.clone(),
+            verifier,
+            stake_slashed,
+        });
+
+        msg!("Vote by {} invalidated on bond: {}", verifier, bond.bond_id);
+        Ok(())
+    }
+
+    /// Either party hands a contested bond to the configured arbiter once
+    /// verifiers disagree (a tie, or votes on both sides)
+    /// Voting stops; only resolve_dispute can settle a Disputed bond
+    pub fn escalate_to_arbiter(ctx: Context<EscalateToArbiter>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        let party = ctx.accounts.party.key();
+
+        require!(
+            party == bond.principal || party == bond.agent,
+            AgberoError::NotBondParty
+        );
+        require!(
+            bond.status == BondStatus::PendingVerification,
+            AgberoError::InvalidBondStatus
+        );
+        require!(
+            ctx.accounts.config.arbiter != Pubkey::default(),
+            AgberoError::UnauthorizedArbiter
+        );
+        let (approve_votes, slash_votes) = bond.tally_votes();
+        require!(approve_votes > 0 && slash_votes > 0, AgberoError::DisputeNotContested);
+        bond.status = BondStatus::Disputed;
+
+        emit!(DisputeEscalated {
+            bond_id: bond.bond_id.clone(),
+            raised_by: party,
+        });
+        Ok(())
+    }
+
+    /// Arbiter settles a Disputed bond, bypassing quorum
+    /// Verifiers who voted against the ruling forfeit any stake passed in
+    /// remaining_accounts to the principal and take a penalty on their profile
+    pub fn resolve_dispute<'info>(
+        ctx: Context<'_, '_, '_, 'info, ResolveDispute<'info>>,
+        approve: bool,
+    ) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        require!(!bond.on_hold, AgberoError::BondOnHold);
+        let vault_balance = bond.vault_funds(ctx.accounts.bond_vault.lamports());
+        let now = Clock::get()?.unix_timestamp;
+        let half_life = ctx.accounts.config.reputation_half_life;
+
+        require!(bond.status == BondStatus::Disputed, AgberoError::InvalidBondStatus);
+        ctx.accounts.reputation.init_if_new(bond.agent, ctx.bumps.reputation);
+
+        // No keeper finalized and no vote decided, so the bounty and any
+        // tips go back to the principal
+        let keeper_bounty = bond.keeper_bounty.min(vault_balance);
+        let tips = bond.tip_pool.min(vault_balance - keeper_bounty);
+        let payout = vault_balance - keeper_bounty - tips;
+        bond.completed_at = now;
+        bond.arbiter_ruling = Some(approve);
+
+        if approve {
+            bond.status = BondStatus::Completed;
+
+            release_to_team(
+                &ctx.accounts.system_program,
+                &ctx.accounts.bond_vault,
+                &ctx.accounts.agent.to_account_info(),
+                bond,
+                payout,
+                ctx.remaining_accounts,
+            )?;
+            ctx.accounts.stats.record_released(bond.collateral_amount);
+            ctx.accounts.reputation.record_completed(now, half_life, bond.collateral_amount);
+
+            bond.record_settlement(0, payout);
+            emit!(BondCompleted {
+                bond_id: bond.bond_id.clone(),
+                agent: bond.agent,
+                stake_released: payout,
+                final_proof_uri: bond
+                    .latest_proof()
+                    .map(|p| p.uri.clone())
+                    .unwrap_or_default(),
+            });
+        } else {
+            require!(!ctx.accounts.config.paused, AgberoError::ProgramPaused);
+            bond.status = BondStatus::Slashed;
+            let reason = SlashReason::Arbiter;
+            bond.slash_reason = Some(reason);
+
+            pay_slash(
+                &ctx.accounts.system_program,
+                &ctx.accounts.bond_vault,
+                &ctx.accounts.principal.to_account_info(),
+                bond,
+                &ctx.accounts.config,
+                payout,
+                ctx.remaining_accounts,
+            )?;
+            ctx.accounts.stats.record_slashed(bond.collateral_amount, payout);
+            ctx.accounts.reputation.record_slashed(now, half_life);
+            track_slash_volume(&mut ctx.accounts.config, &mut ctx.accounts.stats)?;
+
+            bond.record_settlement(payout, 0);
+            emit!(BondSlashed {
+                bond_id: bond.bond_id.clone(),
+                agent: bond.agent,
+                principal: bond.principal,
+                amount_slashed: payout,
+                reason,
+                justification: String::new(),
+            });
+        }
+
+        if keeper_bounty > 0 {
+            transfer_from_vault(
+                &ctx.accounts.system_program,
+                &ctx.accounts.bond_vault,
+                &ctx.accounts.principal.to_account_info(),
+                &bond.key(),
+                bond.vault_bump,
+                keeper_bounty,
+            )?;
+        }
+        pay_principals(
+            &ctx.accounts.system_program,
+            &ctx.accounts.bond_vault,
+            &ctx.accounts.principal.to_account_info(),
+            bond,
+            tips,
+            ctx.remaining_accounts,
+        )?;
+
+        // Direct lamport moves come after every CPI above
+        penalize_wrong_votes(
+            bond,
+            approve,
+            &ctx.accounts.principal.to_account_info(),
+            ctx.remaining_accounts,
+        )?;
+
+        msg!("Dispute on bond {} resolved by arbiter", bond.bond_id);
+        Ok(())
+    }
+
+    /// Legal authority freezes a bond's vault; finalize, slashes and every
+    /// other payout path refuse to move funds until the hold is lifted.
+    /// Independent of the protocol-wide pause.
+    pub fn place_hold(ctx: Context<SetHold>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        require!(!bond.is_terminal(), AgberoError::InvalidBondStatus);
+        bond.on_hold = true;
+
+        emit!(HoldPlaced {
+            bond_id: bond.bond_id.clone(),
+            authority: ctx.accounts.authority.key(),
+        });
+        Ok(())
+    }
+
+    /// Legal authority releases a hold placed with place_hold
+    pub fn lift_hold(ctx: Context<SetHold>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        require!(bond.on_hold, AgberoError::BondNotOnHold);
+        bond.on_hold = false;
+
+        emit!(HoldLifted {
+            bond_id: bond.bond_id.clone(),
+            authority: ctx.accounts.authority.key(),
+        });
+        Ok(())
+    }
+
+    /// Recovery path for a Disputed bond whose parties cannot be reached:
+    /// the arbiter routes `amount` of the collateral to an explicit
+    /// recipient and any remainder to the principal. The keeper bounty and
+    /// tips are refunded as on any other undecided bond
+    pub fn finalize_override<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeOverride<'info>>,
+        recipient: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        require!(!bond.on_hold, AgberoError::BondOnHold);
+        let vault_balance = bond.vault_funds(ctx.accounts.bond_vault.lamports());
+
+        require!(bond.status == BondStatus::Disputed, AgberoError::InvalidBondStatus);
+        let keeper_bounty = bond.keeper_bounty.min(vault_balance);
+        let tips = bond.tip_pool.min(vault_balance - keeper_bounty);
+        let collateral = vault_balance - keeper_bounty - tips;
+        require!(
+            amount > 0 && amount <= collateral,
+            AgberoError::InvalidRecoveryAmount
+        );
+
+        bond.status = BondStatus::Recovered;
+        bond.completed_at = Clock::get()?.unix_timestamp;
+
+        transfer_from_vault(
+            &ctx.accounts.system_program,
+            &ctx.accounts.bond_vault,
+            &ctx.accounts.recovery_wallet.to_account_info(),
+            &bond.key(),
+            bond.vault_bump,
+            amount,
+        )?;
+        let remainder = collateral - amount + keeper_bounty;
+        if remainder > 0 {
+            transfer_from_vault(
+                &ctx.accounts.system_program,
+                &ctx.accounts.bond_vault,
+                &ctx.accounts.principal.to_account_info(),
+                &bond.key(),
+                bond.vault_bump,
+                remainder,
+            )?;
+        }
+        pay_principals(
+            &ctx.accounts.system_program,
+            &ctx.accounts.bond_vault,
+            &ctx.accounts.principal.to_account_info(),
+            bond,
+            tips,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.stats.record_expired(bond.collateral_amount);
+
+        bond.record_settlement(collateral, 0);
+        emit!(FundsRecovered {
+            bond_id: bond.bond_id.clone(),
+            arbiter: ctx.accounts.arbiter.key(),
+            recipient,
+            amount,
+        });
+
+        msg!("Bond {} recovered by arbiter to {}", bond.bond_id, recipient);
+        Ok(())
+    }
+
+    /// Create the caller's verifier profile, up to Config::max_verifiers
+    pub fn register_verifier(ctx: Context<RegisterVerifier>) -> Result<()> {
+        let max_verifiers = ctx.accounts.config.max_verifiers;
+        let stats = &mut ctx.accounts.stats;
+        require!(
+            max_verifiers == 0 || stats.registered_verifiers < max_verifiers,
+            AgberoError::VerifierPoolFull
+        );
+        let profile = &mut ctx.accounts.profile;
+        profile.index = stats.registered_verifiers;
+        stats.registered_verifiers += 1;
+
+        profile.verifier = ctx.accounts.verifier.key();
+        profile.registered_at = Clock::get()?.unix_timestamp;
+        profile.penalties = 0;
+        profile.bump = ctx.bumps.profile;
+        profile.history_hash = [0; 32];
+        profile.votes_recorded = 0;
+        profile.categories = vec![];
+
+        msg!("Verifier registered: {}", profile.verifier);
+        Ok(())
+    }
+
+    /// Verifier lists the task categories they are competent to judge
+    /// Replaces the previous list; bonds in category 0 stay open to everyone
+    pub fn set_verifier_categories(
+        ctx: Context<SetVerifierCategories>,
+        categories: Vec<u8>,
+    ) -> Result<()> {
+        require!(
+            categories.len() <= VerifierProfile::MAX_CATEGORIES && !categories.contains(&0),
+            AgberoError::InvalidCategories
+        );
+        let profile = &mut ctx.accounts.profile;
+        profile.categories = categories;
+
+        msg!("Verifier {} categories: {:?}", profile.verifier, profile.categories);
+        Ok(())
+    }
+
+    /// Agent opts in to the principal's completion callback. Without this the
+    /// callback is skipped, so a hostile callback cannot block the agent's release.
+    pub fn approve_callback(ctx: Context<ApproveCallback>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+
+        require!(!bond.is_terminal(), AgberoError::InvalidBondStatus);
+        require!(bond.callback.is_some(), AgberoError::InvalidCallback);
+        bond.callback_approved = true;
+
+        msg!("Completion callback approved for bond: {}", bond.bond_id);
+        Ok(())
+    }
+
+    /// Principal starts the bond's appeal window ahead of an emergency slash,
+    /// the only way to begin one; the bond is frozen in SlashRequested until
+    /// executed or appealed
+    pub fn request_emergency_slash(
+        ctx: Context<RequestEmergencySlash>,
+        slash_reason: String,
+    ) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+
+        require!(
+            slash_reason.len() <= Bond::MAX_JUSTIFICATION,
+            AgberoError::SlashReasonTooLong
+        );
+        require!(
+            bond.status == BondStatus::Active || bond.status == BondStatus::PendingVerification,
+            AgberoError::InvalidBondStatus
+        );
+        require!(bond.appeal_window > 0, AgberoError::InvalidAppealWindow);
+        require!(bond.slash_requested_at == 0, AgberoError::SlashAlreadyRequested);
+        require!(
+            !bond.approve_quorum_reached(&ctx.accounts.config),
+            AgberoError::ApprovalQuorumReached
+        );
+        require!(!bond.slash_appealed, AgberoError::SlashAlreadyAppealed);
+
+        let now = Clock::get()?.unix_timestamp;
+        bond.status = BondStatus::SlashRequested;
+        bond.slash_requested_at = now;
+        bond.slash_justification = slash_reason;
+
+        emit!(EmergencySlashRequested {
+            bond_id: bond.bond_id.clone(),
+            principal: bond.principal,
+            executable_at: now.saturating_add(bond.appeal_window),
+        });
+        Ok(())
+    }
+
+    /// Principal carries out a requested emergency slash once the bond's
+    /// appeal window has run out
+    pub fn execute_emergency_slash<'info>(
+        ctx: Context<'_, '_, '_, 'info, EmergencySlash<'info>>,
+    ) -> Result<()> {
+        let bond = &ctx.accounts.bond;
+        require!(!bond.on_hold, AgberoError::BondOnHold);
+
+        require!(bond.status == BondStatus::SlashRequested, AgberoError::SlashNotRequested);
+        // An acknowledged slash is uncontested, so there is nothing to wait for
+        require!(
+            bond.slash_acknowledged
+                || Clock::get()?.unix_timestamp
+                    >= bond.slash_requested_at.saturating_add(bond.appeal_window),
+            AgberoError::AppealWindowOpen
+        );
+        require!(!ctx.accounts.config.paused, AgberoError::ProgramPaused);
+
+        settle_emergency_slash(ctx)
+    }
+
+    /// Agent contests a requested emergency slash inside the appeal window.
+    /// The bond returns to normal verification voting, or goes to the
+    /// arbiter as Disputed when one is configured, with the frozen time
+    /// added back to its deadlines; the principal cannot request again.
+    pub fn appeal_emergency_slash(ctx: Context<AppealEmergencySlash>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        require!(bond.status == BondStatus::SlashRequested, AgberoError::SlashNotRequested);
+        require!(!bond.slash_acknowledged, AgberoError::SlashAlreadyAcknowledged);
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now < bond.slash_requested_at.saturating_add(bond.appeal_window),
+            AgberoError::AppealWindowClosed
+        );
+
+        let frozen = now - bond.slash_requested_at;
+        bond.deadline = bond.deadline.saturating_add(frozen);
+        if bond.verification_deadline > 0 {
+            bond.verification_deadline = bond.verification_deadline.saturating_add(frozen);
+        }
+        bond.status = if ctx.accounts.config.arbiter != Pubkey::default() {
+            BondStatus::Disputed
+        } else if bond.proofs.is_empty() {
+            BondStatus::Active
+        } else {
+            BondStatus::PendingVerification
+        };
+        bond.slash_requested_at = 0;
+        bond.slash_justification.clear();
+        bond.slash_appealed = true;
+
+        emit!(EmergencySlashAppealed {
+            bond_id: bond.bond_id.clone(),
+            agent: bond.agent,
+            status: bond.status,
+        });
+
+        msg!("Emergency slash appealed for bond: {}", bond.bond_id);
+        Ok(())
+    }
+
+    /// Agent accepts a requested emergency slash as justified. This waives
+    /// the rest of the appeal window, so the principal can execute at once,
+    /// and gives up the right to appeal.
+    pub fn acknowledge_slash(ctx: Context<AcknowledgeSlash>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        require!(bond.status == BondStatus::SlashRequested, AgberoError::SlashNotRequested);
+        require!(!bond.slash_acknowledged, AgberoError::SlashAlreadyAcknowledged);
+
+        bond.slash_acknowledged = true;
+
+        emit!(SlashAcknowledged {
+            bond_id: bond.bond_id.clone(),
+            agent: bond.agent,
+        });
+
+        msg!("Emergency slash acknowledged for bond: {}", bond.bond_id);
+        Ok(())
+    }
+
+    /// Trusted oracle delivers the verdict and settles in one instruction,
+    /// bypassing vote accumulation. Only the Config-listed oracle may call.
+    pub fn oracle_settle<'info>(
+        ctx: Context<'_, '_, '_, 'info, OracleSettle<'info>>,
+        outcome: SettlementOutcome,
+    ) -> Result<()> {
+        let bond_id = ctx.accounts.bond.bond_id.clone();
+        let oracle = ctx.accounts.oracle.key();
+        settle_outcome(ctx, outcome)?;
+
+        emit!(OracleSettled {
+            bond_id: bond_id.clone(),
+            oracle,
+            outcome,
+        });
+
+        msg!("Bond settled by oracle: {}", bond_id);
+        Ok(())
+    }
+
+    /// Oracle commits to an off-chain vote tally ahead of settling on it:
+    /// the Merkle root over its votes, leaf = keccak(bond || index ||
+    /// verifier || approve), and the counts. Once per bond, unless the seed
+    /// slot has aged out of SlotHashes before the tally was settled
+    pub fn commit_tally(
+        ctx: Context<CommitTally>,
+        root: [u8; 32],
+        approve_count: u32,
+        total: u32,
+    ) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        require!(
+            bond.status == BondStatus::PendingVerification,
+            AgberoError::InvalidBondStatus
+        );
+        let slot = Clock::get()?.slot;
+        require!(
+            bond.tally_slot == 0
+                || slot > (bond.tally_slot + 1).saturating_add(MAX_SLOT_HASHES as u64),
+            AgberoError::TallyAlreadyCommitted
+        );
+        require!(total > 0 && approve_count <= total, AgberoError::TallyMismatch);
+
+        bond.tally_root = root;
+        bond.tally_approve = approve_count;
+        bond.tally_total = total;
+        bond.tally_slot = slot;
+
+        emit!(TallyCommitted {
+            bond_id: bond.bond_id.clone(),
+            root,
+            approve_count,
+            total,
+        });
+        Ok(())
+    }
+
+    /// Oracle settles a bond on its committed tally. The leaves to prove are
+    /// sampled from the hash of the slot after the commit, which the oracle
+    /// could not know when it built the tree; the SlotHashes sysvar is passed
+    /// in remaining_accounts. Proven votes must sit at distinct positions
+    /// within the committed total and agree with the committed counts; the
+    /// configured quorum and majority apply as usual. On a categorised bond
+    /// each proven voter's profile must be passed as well
+    pub fn finalize_from_tally<'info>(
+        ctx: Context<'_, '_, '_, 'info, OracleSettle<'info>>,
+        proofs: Vec<TallyProof>,
+    ) -> Result<()> {
+        let bond = &ctx.accounts.bond;
+        require!(
+            bond.status == BondStatus::PendingVerification,
+            AgberoError::InvalidBondStatus
+        );
+        require!(bond.tally_slot > 0, AgberoError::TallyNotCommitted);
+        let (root, approve_count, total) = (bond.tally_root, bond.tally_approve, bond.tally_total);
+        require!(
+            !proofs.is_empty() && proofs.len() <= total as usize,
+            AgberoError::TallyMismatch
+        );
+
+        let slot_hashes = ctx
+            .remaining_accounts
+            .iter()
+            .find(|a| a.key() == sysvar::slot_hashes::ID)
+            .ok_or(AgberoError::SlotHashesMissing)?;
+        let seed = slot_hash_from(slot_hashes, bond.tally_slot + 1)?;
+
+        let bond_key = bond.key();
+        let mut seen: Vec<(u32, Pubkey)> = Vec::with_capacity(proofs.len());
+        let (mut sampled_approve, mut sampled_slash) = (0u32, 0u32);
+        for proof in &proofs {
+            require!(proof.verifier != bond.agent, AgberoError::AgentCannotVerify);
+            require!(
+                bond.is_eligible_verifier(&proof.verifier),
+                AgberoError::NotInSubcommittee
+            );
+            VerifierProfile::require_category(bond, &proof.verifier, ctx.remaining_accounts)?;
+            require!(proof.index < total, AgberoError::TallyMismatch);
+            require!(
+                !seen.iter().any(|(i, v)| *i == proof.index || *v == proof.verifier),
+                AgberoError::TallyMismatch
+            );
+            require!(proof.verify(&bond_key, &root), AgberoError::InvalidTallyProof);
+            seen.push((proof.index, proof.verifier));
+            if proof.approve {
+                sampled_approve += 1;
+            } else {
+                sampled_slash += 1;
+            }
+        }
+        require!(
+            sampled_approve <= approve_count && sampled_slash <= total - approve_count,
+            AgberoError::TallyMismatch
+        );
+        require!(
+            TallyProof::sample(&seed, total)
+                .iter()
+                .all(|index| seen.iter().any(|(i, _)| i == index)),
+            AgberoError::TallySampleMissing
+        );
+
+        let config = &ctx.accounts.config;
+        let slash_count = total - approve_count;
+        let quorum_reached = bond.quorum_met(config, total as u64);
+        let outcome = if quorum_reached && config.is_majority(approve_count as u128, total as u128) {
+            SettlementOutcome::Complete
+        } else if quorum_reached && config.is_majority(slash_count as u128, total as u128) {
+            SettlementOutcome::Slash
+        } else {
+            return Err(AgberoError::QuorumNotReached.into());
+        };
+
+        let bond_id = bond.bond_id.clone();
+        settle_outcome(ctx, outcome)?;
+
+        emit!(TallySettled {
+            bond_id,
+            root,
+            approve_count,
+            total,
+            proofs_checked: proofs.len() as u32,
+            outcome,
+        });
+        Ok(())
+    }
+
+    /// Principal escrows SOL tips for verifiers who back a winning vote with
+    /// evidence; separate from token rewards and paid out by finalize_bond
+    pub fn fund_tips(ctx: Context<FundTips>, amount: u64) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+
+        require!(amount > 0, AgberoError::InvalidRewardAmount);
+        require!(
+            bond.status == BondStatus::Active || bond.status == BondStatus::PendingVerification,
+            AgberoError::InvalidBondStatus
+        );
+
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.principal.to_account_info(),
+                to: ctx.accounts.bond_vault.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_context, amount)?;
+        bond.tip_pool += amount;
+        bond.principal_contribution += amount;
+
+        emit!(TipsFunded {
+            bond_id: bond.bond_id.clone(),
+            amount,
+            tip_pool: bond.tip_pool,
+        });
+        Ok(())
+    }
+
+    /// Co-principal the principal invited at creation pays into the tip
+    /// pool, co-funding verification; its share of any slash, and of tips
+    /// no verifier earns, follows what it paid. Repeatable to add more
+    pub fn join_syndicate(ctx: Context<JoinSyndicate>, amount: u64) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        let member = ctx.accounts.member.key();
+
+        require!(amount > 0, AgberoError::InvalidRewardAmount);
+        require!(
+            bond.status == BondStatus::Active || bond.status == BondStatus::PendingVerification,
+            AgberoError::InvalidBondStatus
+        );
+        let index = bond
+            .syndicate
+            .iter()
+            .position(|m| m.member == member)
+            .ok_or(AgberoError::NotSyndicateMember)?;
+
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.member.to_account_info(),
+                to: ctx.accounts.bond_vault.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_context, amount)?;
+        bond.tip_pool += amount;
+        bond.syndicate[index].contribution += amount;
+
+        emit!(SyndicateJoined {
+            bond_id: bond.bond_id.clone(),
+            member,
+            contribution: bond.syndicate[index].contribution,
+            tip_pool: bond.tip_pool,
+        });
+        Ok(())
+    }
+
+    /// Verifier attaches an evidence URI to the vote they already cast
+    pub fn attach_evidence(ctx: Context<AttachEvidence>, evidence_uri: String) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        let verifier = ctx.accounts.verifier.key();
+
+        require!(
+            bond.status == BondStatus::PendingVerification,
+            AgberoError::InvalidBondStatus
+        );
+        require!(evidence_uri.len() <= Evidence::MAX_URI, AgberoError::ProofUriTooLong);
+
+        let vote = bond
+            .verification_votes
+            .iter_mut()
+            .find(|v| v.verifier == verifier)
+            .ok_or(AgberoError::VoteNotFound)?;
+        vote.evidence = true;
+
+        // Attaching again replaces the URI
+        let evidence = &mut ctx.accounts.evidence;
+        evidence.bond = bond.key();
+        evidence.verifier = verifier;
+        evidence.uri = evidence_uri.clone();
+        evidence.attached_at = Clock::get()?.unix_timestamp;
+        evidence.bump = ctx.bumps.evidence;
+
+        emit!(EvidenceAttached {
+            bond_id: bond.bond_id.clone(),
+            verifier,
+            evidence_uri,
+        });
+        Ok(())
+    }
+
+    /// Principal escrows reward tokens for verifiers who vote with the outcome
+    /// Paid in Config::reward_mint, entirely separate from the SOL collateral
+    pub fn fund_verifier_rewards(ctx: Context<FundVerifierRewards>, amount: u64) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+
+        require!(amount > 0, AgberoError::InvalidRewardAmount);
+        require!(!bond.is_terminal(), AgberoError::InvalidBondStatus);
+
+        let cpi_context = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.principal_token.to_account_info(),
+                to: ctx.accounts.reward_escrow.to_account_info(),
+                authority: ctx.accounts.principal.to_account_info(),
+            },
+        );
+        token::transfer(cpi_context, amount)?;
+        bond.reward_pool += amount;
+
+        emit!(VerifierRewardsFunded {
+            bond_id: bond.bond_id.clone(),
+            mint: ctx.accounts.reward_mint.key(),
+            amount,
+            reward_pool: bond.reward_pool,
+        });
+        Ok(())
+    }
+
+    /// Verifier on the winning side claims an equal share of the reward pool
+    pub fn claim_verifier_reward(ctx: Context<ClaimVerifierReward>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        let verifier = ctx.accounts.verifier.key();
+
+        require!(bond.is_terminal(), AgberoError::InvalidBondStatus);
+        let side = bond.winning_side().ok_or(AgberoError::NoRewardDue)?;
+        let index = bond
+            .verification_votes
+            .iter()
+            .position(|v| v.verifier == verifier && !v.excluded && v.approve == side)
+            .ok_or(AgberoError::NoRewardDue)?;
+        require!(
+            bond.rewards_claimed & (1 << index) == 0,
+            AgberoError::RewardAlreadyClaimed
+        );
+
+        let winners = bond.counted_votes().filter(|v| v.approve == side).count() as u64;
+        let share = bond.reward_pool / winners;
+        bond.rewards_claimed |= 1 << index;
+        bond.rewards_paid += share;
+
+        let bond_key = bond.key();
+        let seeds: &[&[u8]] = &[b"reward_escrow", bond_key.as_ref(), &[ctx.bumps.reward_escrow]];
+        let signer = &[seeds];
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.reward_escrow.to_account_info(),
+                to: ctx.accounts.verifier_token.to_account_info(),
+                authority: ctx.accounts.reward_escrow.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(cpi_context, share)?;
+
+        emit!(VerifierRewardClaimed {
+            bond_id: bond.bond_id.clone(),
+            verifier,
+            amount: share,
+        });
+        Ok(())
+    }
+
+    /// Principal takes back the part of the reward pool no winning verifier
+    /// can claim: all of it when the bond is still unstaked or settled
+    /// without a winning side, and the rounding dust once every winner has
+    /// claimed their share
+    pub fn reclaim_verifier_rewards(ctx: Context<ReclaimVerifierRewards>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+
+        require!(
+            bond.is_terminal() || bond.status == BondStatus::Pending,
+            AgberoError::InvalidBondStatus
+        );
+        require!(!bond.rewards_claimable(), AgberoError::RewardsStillClaimable);
+        let amount = bond.reward_pool - bond.rewards_paid;
+        require!(amount > 0, AgberoError::NoRewardDue);
+        bond.rewards_paid = bond.reward_pool;
+
+        let bond_key = bond.key();
+        let seeds: &[&[u8]] = &[b"reward_escrow", bond_key.as_ref(), &[ctx.bumps.reward_escrow]];
+        let signer = &[seeds];
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.reward_escrow.to_account_info(),
+                to: ctx.accounts.principal_token.to_account_info(),
+                authority: ctx.accounts.reward_escrow.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(cpi_context, amount)?;
+
+        emit!(VerifierRewardsReclaimed {
+            bond_id: bond.bond_id.clone(),
+            principal: bond.principal,
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Principal of a bond whose agent vanished without proof draws the
+    /// shortfall its paid-for cover left due from the shared insurance pool
+    /// Paid as far as the pool allows; any remainder stays claimable
+    pub fn claim_insurance(ctx: Context<ClaimInsurance>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        require!(bond.insurance_due > 0, AgberoError::NoInsuranceDue);
+
+        // The pool itself stays rent-exempt
+        let available = ctx
+            .accounts
+            .insurance_pool
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(0));
+        let amount = bond.insurance_due.min(available);
+        require!(amount > 0, AgberoError::InsurancePoolEmpty);
+
+        transfer_signed(
+            &ctx.accounts.system_program,
+            &ctx.accounts.insurance_pool,
+            &ctx.accounts.principal.to_account_info(),
+            &[b"insurance_pool", &[ctx.bumps.insurance_pool]],
+            amount,
+        )?;
+        bond.insurance_due -= amount;
+
+        emit!(InsuranceClaimed {
+            bond_id: bond.bond_id.clone(),
+            principal: bond.principal,
+            amount,
+            remaining_due: bond.insurance_due,
+        });
+        Ok(())
+    }
+
+    /// Verifier signs off on one milestone; Config::min_votes approvals let
+    /// release_milestone pay it out
+    pub fn approve_milestone(ctx: Context<ApproveMilestone>, index: u8) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        let verifier = ctx.accounts.verifier.key();
+        require!(
+            bond.status == BondStatus::Active || bond.status == BondStatus::PendingVerification,
+            AgberoError::InvalidBondStatus
+        );
+        require!(verifier != bond.agent, AgberoError::AgentCannotVerify);
+        require!(bond.is_eligible_verifier(&verifier), AgberoError::NotInSubcommittee);
+        // Without a sub-committee anyone could sign; the accounts require a
+        // registered verifier with stake on this bond, judging submitted work
+        require!(!bond.proofs.is_empty(), AgberoError::ProofRequired);
+
+        let milestone = bond
+            .milestones
+            .get_mut(index as usize)
+            .ok_or(AgberoError::InvalidMilestones)?;
+        require!(!milestone.released, AgberoError::MilestoneAlreadyReleased);
+        require!(!milestone.approvers.contains(&verifier), AgberoError::AlreadyVoted);
+        require!(
+            (milestone.approvers.len() as u64) < ctx.accounts.config.min_votes,
+            AgberoError::MilestoneAlreadyApproved
+        );
+        milestone.approvers.push(verifier);
+
+        emit!(MilestoneApproved {
+            bond_id: bond.bond_id.clone(),
+            index,
+            verifier,
+        });
+        Ok(())
+    }
+
+    /// Pay an approved milestone from the vault to the agent (permissionless).
+    /// Released amounts are final; a later slash only takes what is left
+    pub fn release_milestone(ctx: Context<ReleaseMilestone>, index: u8) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        require!(!bond.on_hold, AgberoError::BondOnHold);
+        require!(
+            bond.escrow_cosigned(&ctx.accounts.executor.key(), ctx.remaining_accounts),
+            AgberoError::EscrowSignatureMissing
+        );
+        require!(
+            bond.status == BondStatus::Active || bond.status == BondStatus::PendingVerification,
+            AgberoError::InvalidBondStatus
+        );
+
+        let milestone = bond
+            .milestones
+            .get(index as usize)
+            .ok_or(AgberoError::InvalidMilestones)?;
+        require!(!milestone.released, AgberoError::MilestoneAlreadyReleased);
+        require!(
+            milestone.approvers.len() as u64 >= ctx.accounts.config.min_votes,
+            AgberoError::MilestoneNotApproved
+        );
+        let amount = milestone.amount;
+
+        transfer_from_vault(
+            &ctx.accounts.system_program,
+            &ctx.accounts.bond_vault,
+            &ctx.accounts.agent.to_account_info(),
+            &bond.key(),
+            bond.vault_bump,
+            amount,
+        )?;
+        bond.milestones[index as usize].released = true;
+        bond.milestones_released += amount;
+
+        emit!(MilestoneReleased {
+            bond_id: bond.bond_id.clone(),
+            index,
+            amount,
+            total_released: bond.milestones_released,
+        });
+        Ok(())
+    }
+
+    /// Agent buys cover against a wrongful slash by paying
+    /// Config::agent_premium_bps of the collateral into the insurance pool.
+    /// Only before any proof is judged, and once per bond
+    pub fn pay_agent_premium(ctx: Context<PayAgentPremium>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        require!(
+            (bond.status == BondStatus::Pending || bond.status == BondStatus::Active)
+                && bond.proofs.is_empty(),
+            AgberoError::InvalidBondStatus
+        );
+        require!(bond.agent_premium_paid == 0, AgberoError::AgentPremiumAlreadyPaid);
+
+        let premium = (bond.collateral_amount as u128
+            * ctx.accounts.config.agent_premium_bps as u128
+            / 10_000) as u64;
+        require!(premium > 0, AgberoError::AgentCoverUnavailable);
+
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.agent.to_account_info(),
+                to: ctx.accounts.insurance_pool.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_context, premium)?;
+        bond.agent_premium_paid = premium;
+
+        emit!(AgentPremiumPaid {
+            bond_id: bond.bond_id.clone(),
+            agent: bond.agent,
+            premium,
+        });
+        Ok(())
+    }
+
+    /// Principal buys the cover registered at creation by paying
+    /// Config::principal_premium_bps of insured_amount into the insurance
+    /// pool; uncovered bonds get nothing from the pool on a no-proof timeout
+    pub fn pay_principal_premium(ctx: Context<PayPrincipalPremium>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        require!(
+            (bond.status == BondStatus::Pending || bond.status == BondStatus::Active)
+                && bond.proofs.is_empty(),
+            AgberoError::InvalidBondStatus
+        );
+        require!(
+            bond.principal_premium_paid == 0,
+            AgberoError::PrincipalPremiumAlreadyPaid
+        );
+
+        let premium = (bond.insured_amount as u128
+            * ctx.accounts.config.principal_premium_bps as u128
+            / 10_000) as u64;
+        require!(premium > 0, AgberoError::PrincipalCoverUnavailable);
+
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.principal.to_account_info(),
+                to: ctx.accounts.insurance_pool.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_context, premium)?;
+        bond.principal_premium_paid = premium;
+
+        emit!(PrincipalPremiumPaid {
+            bond_id: bond.bond_id.clone(),
+            principal: bond.principal,
+            premium,
+        });
+        Ok(())
+    }
+
+    /// Arbiter finds a covered bond was slashed wrongfully after its funds
+    /// already left the vault; what the agent's side lost becomes claimable,
+    /// up to Config::agent_cover_multiple times the premium it paid
+    pub fn rule_wrongful_slash(ctx: Context<RuleWrongfulSlash>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        require!(bond.status == BondStatus::Slashed, AgberoError::InvalidBondStatus);
+        require!(bond.agent_premium_paid > 0, AgberoError::AgentCoverUnavailable);
+        require!(bond.arbiter_ruling.is_none(), AgberoError::AlreadyRuled);
+
+        let cover = bond
+            .agent_premium_paid
+            .saturating_mul(ctx.accounts.config.agent_cover_multiple as u64);
+        bond.arbiter_ruling = Some(true);
+        bond.agent_compensation_due = bond.total_slashed.min(cover);
+
+        emit!(WrongfulSlashRuled {
+            bond_id: bond.bond_id.clone(),
+            agent: bond.agent,
+            compensation_due: bond.agent_compensation_due,
+        });
+        Ok(())
+    }
+
+    /// Agent draws the compensation awarded by rule_wrongful_slash from the
+    /// insurance pool; partial payouts leave the rest claimable
+    pub fn claim_agent_insurance(ctx: Context<ClaimAgentInsurance>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        require!(bond.agent_compensation_due > 0, AgberoError::NoInsuranceDue);
+
+        let available = ctx
+            .accounts
+            .insurance_pool
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(0));
+        let amount = bond.agent_compensation_due.min(available);
+        require!(amount > 0, AgberoError::InsurancePoolEmpty);
+
+        transfer_signed(
+            &ctx.accounts.system_program,
+            &ctx.accounts.insurance_pool,
+            &ctx.accounts.agent.to_account_info(),
+            &[b"insurance_pool", &[ctx.bumps.insurance_pool]],
+            amount,
+        )?;
+        bond.agent_compensation_due -= amount;
+
+        emit!(AgentCompensated {
+            bond_id: bond.bond_id.clone(),
+            agent: bond.agent,
+            amount,
+            remaining_due: bond.agent_compensation_due,
+        });
+        Ok(())
+    }
+
+    /// Shrink a finalized bond to the bytes it actually uses, refunding the
+    /// rent held for unused vote, commit and proof slots to the principal
+    pub fn reclaim_vote_rent(ctx: Context<ReclaimVoteRent>) -> Result<()> {
+        let bond = &ctx.accounts.bond;
+        require!(bond.is_terminal(), AgberoError::InvalidBondStatus);
+        // Forfeits and parked rewards sit in the bond's lamports above rent
+        require!(
+            !bond.has_outstanding_claims(Clock::get()?.unix_timestamp),
+            AgberoError::ClaimsOutstanding
+        );
+
+        let bond_info = bond.to_account_info();
+        let allocated = bond_info.data_len();
+        let used = 8 + bond.try_to_vec()?.len();
+        require!(used < allocated, AgberoError::NoRentToReclaim);
+
+        let refund = bond_info
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(used));
+        bond_info.realloc(used, false)?;
+        **bond_info.try_borrow_mut_lamports()? -= refund;
+        **ctx.accounts.principal.try_borrow_mut_lamports()? += refund;
+
+        emit!(VoteRentReclaimed {
+            bond_id: bond.bond_id.clone(),
+            bytes_released: (allocated - used) as u64,
+            lamports_refunded: refund,
+        });
+
+        msg!("Reclaimed {} lamports of unused slot rent", refund);
+        Ok(())
+    }
+
+    /// Principal closes a finalized bond, reclaiming its rent
+    /// BondClosed carries the whole outcome so indexers can archive one record
+    pub fn close_bond(ctx: Context<CloseBond>) -> Result<()> {
+        let bond = &ctx.accounts.bond;
+        require!(bond.is_terminal(), AgberoError::InvalidBondStatus);
+        require!(
+            !bond.has_outstanding_claims(Clock::get()?.unix_timestamp),
+            AgberoError::ClaimsOutstanding
+        );
+
+        let vault_rent = release_vault_rent(
+            &ctx.accounts.system_program,
+            &ctx.accounts.bond_vault,
+            &ctx.accounts.principal.to_account_info(),
+            bond,
+        )?;
+        emit_bond_closed(bond, vault_rent);
+
+        msg!("Bond closed: {}", bond.bond_id);
+        Ok(())
+    }
+
+    /// Principal grants the agent more time on an active bond, at most
+    /// Bond::MAX_EXTENSIONS times. submit_proof and the timeout paths read
+    /// the updated deadline
+    pub fn extend_deadline(ctx: Context<ExtendDeadline>, new_deadline: i64) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+
+        require!(bond.status == BondStatus::Active, AgberoError::InvalidBondStatus);
+        require!(new_deadline > bond.deadline, AgberoError::InvalidDeadline);
+        require!(
+            bond.extension_count < Bond::MAX_EXTENSIONS,
+            AgberoError::ExtensionLimitReached
+        );
+
+        msg!(
+            "Deadline for bond {} extended from {} to {}",
+            bond.bond_id,
+            bond.deadline,
+            new_deadline
+        );
+        emit!(DeadlineExtended {
+            bond_id: bond.bond_id.clone(),
+            old_deadline: bond.deadline,
+            new_deadline,
+            extension_count: bond.extension_count + 1,
+        });
+        bond.extension_count += 1;
+        bond.deadline = new_deadline;
+        bond.deadline_warned = false;
+        bond.deadline_passed_noted = false;
+        Ok(())
+    }
+
+    /// Permissionless nudge for off-chain watchers: emits DeadlineApproaching
+    /// once inside Config::deadline_warning_window, DeadlinePassed once after
+    pub fn poke_bond(ctx: Context<PokeBond>) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        require!(bond.status == BondStatus::Active, AgberoError::InvalidBondStatus);
+
+        let now = Clock::get()?.unix_timestamp;
+        let window = ctx.accounts.config.deadline_warning_window;
+        if now > bond.deadline {
+            if !bond.deadline_passed_noted {
+                bond.deadline_passed_noted = true;
+                emit!(DeadlinePassed {
+                    bond_id: bond.bond_id.clone(),
+                    agent: bond.agent,
+                    deadline: bond.deadline,
+                    overdue_by: now - bond.deadline,
+                });
+            }
+        } else if window > 0 && now >= bond.deadline.saturating_sub(window) && !bond.deadline_warned {
+            bond.deadline_warned = true;
+            emit!(DeadlineApproaching {
+                bond_id: bond.bond_id.clone(),
+                agent: bond.agent,
+                deadline: bond.deadline,
+                remaining: bond.deadline - now,
+            });
+        }
+        Ok(())
+    }
+
+    /// Swap in a new task by mutual consent
+    /// Proof and votes on the old task are discarded and the bond returns to Active
+    pub fn amend_task(
+        ctx: Context<AmendTask>,
+        new_description: String,
+        new_deadline: i64,
+    ) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+
+        require!(
+            bond.status == BondStatus::Active || bond.status == BondStatus::PendingVerification,
+            AgberoError::InvalidBondStatus
+        );
+        require!(new_description.len() <= 500, AgberoError::DescriptionTooLong);
+        require!(
+            new_deadline > Clock::get()?.unix_timestamp,
+            AgberoError::InvalidDeadline
+        );
+
+        bond.task_description = new_description;
+        bond.deadline = new_deadline;
+        bond.deadline_warned = false;
+        bond.deadline_passed_noted = false;
+        bond.status = BondStatus::Active;
+        bond.proofs.clear();
+        bond.verification_votes.clear();
+        bond.slash_votes.clear();
+        bond.vote_commits.clear();
+        bond.commit_deadline = 0;
+        bond.reveal_deadline = 0;
+        bond.verification_deadline = 0;
+        bond.assignment_deadlines.iter_mut().for_each(|d| *d = 0);
+        bond.tally_root = [0; 32];
+        bond.tally_approve = 0;
+        bond.tally_total = 0;
+        bond.tally_slot = 0;
+        // Milestone approvals were given on the old scope
+        bond.milestones.iter_mut().for_each(|m| m.approvers.clear());
+
+        emit!(TaskAmended {
+            bond_id: bond.bond_id.clone(),
+            new_deadline,
+        });
+
+        msg!("Task amended for bond: {}", bond.bond_id);
+        Ok(())
+    }
+
+    /// Lower the collateral requirement by mutual consent
+    /// Excess collateral is refunded from the vault to the agent
+    pub fn reduce_collateral(ctx: Context<ReduceCollateral>, new_amount: u64) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+        require!(!bond.on_hold, AgberoError::BondOnHold);
+        require!(
+            bond.escrow_cosigned(&ctx.accounts.principal.key(), ctx.remaining_accounts),
+            AgberoError::EscrowSignatureMissing
+        );
+
+        require!(
+            bond.status == BondStatus::Active || bond.status == BondStatus::PendingVerification,
+            AgberoError::InvalidBondStatus
+        );
+        require!(new_amount >= 1_000_000, AgberoError::CollateralTooLow);
+        require!(
+            bond.team.is_empty() && bond.milestones.is_empty(),
+            AgberoError::InvalidCollateralReduction
+        );
+        require!(
+            new_amount < bond.collateral_amount,
+            AgberoError::InvalidCollateralReduction
+        );
+
+        let excess = bond.collateral_amount - new_amount;
+        transfer_from_vault(
+            &ctx.accounts.system_program,
+            &ctx.accounts.bond_vault,
+            &ctx.accounts.agent.to_account_info(),
+            &bond.key(),
+            bond.vault_bump,
+            excess,
+        )?;
+
+        let old_amount = bond.collateral_amount;
+        bond.collateral_amount = new_amount;
+        ctx.accounts.stats.total_value_locked =
+            ctx.accounts.stats.total_value_locked.saturating_sub(excess);
+
+        emit!(CollateralReduced {
+            bond_id: bond.bond_id.clone(),
+            old_amount,
+            new_amount,
+            refunded: excess,
+        });
+
+        msg!("Collateral reduced to {} lamports, {} refunded", new_amount, excess);
+        Ok(())
+    }
+
+    /// Agent adds buffer collateral on top of the base amount
+    /// Partial slashes consume the buffer before touching the base
+    pub fn top_up_buffer(ctx: Context<TopUpBuffer>, amount: u64) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+
+        require!(
+            bond.status == BondStatus::Active || bond.status == BondStatus::PendingVerification,
+            AgberoError::InvalidBondStatus
+        );
+        require!(amount > 0, AgberoError::InvalidStakeAmount);
+
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.agent.to_account_info(),
+                to: ctx.accounts.bond_vault.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_context, amount)?;
+
+        bond.buffer_amount = bond
+            .buffer_amount
+            .checked_add(amount)
+            .ok_or(AgberoError::InvalidStakeAmount)?;
+
+        // More collateral at risk tightens the quorum; it never loosens
+        let at_risk = bond.collateral_amount.saturating_add(bond.buffer_amount);
+        let required = ctx.accounts.config.required_votes(at_risk);
+        if required > bond.required_votes {
+            bond.required_votes = required;
+            emit!(QuorumRaised {
+                bond_id: bond.bond_id.clone(),
+                required_votes: required,
+            });
+        }
+
+        emit!(BufferToppedUp {
+            bond_id: bond.bond_id.clone(),
+            amount,
+            buffer_amount: bond.buffer_amount,
+        });
+        Ok(())
+    }
+
+    /// Hand the principal position to a new account (e.g. a DAO reorg)
+    /// The new principal receives any slash payout and holds principal-only rights
+    pub fn transfer_principal(ctx: Context<TransferPrincipal>, new_principal: Pubkey) -> Result<()> {
+        let bond = &mut ctx.accounts.bond;
+
+        require!(!bond.is_terminal(), AgberoError::InvalidBondStatus);
+        require!(
+            new_principal != Pubkey::default()
+                && new_principal != bond.principal
+                && new_principal != bond.agent
+                && !bond.subcommittee.contains(&new_principal),
+            AgberoError::InvalidPrincipalTransfer
+        );
+
+        let old_principal = bond.principal;
+        bond.principal = new_principal;
+
+        emit!(PrincipalTransferred {
+            bond_id: bond.bond_id.clone(),
+            old_principal,
+            new_principal,
+        });
+
+        msg!("Principal of bond {} transferred to {}", bond.bond_id, new_principal);
+        Ok(())
+    }
+
+    /// One-time setup of the global program config
+    /// The signer becomes the config admin
+    pub fn initialize_config(ctx: Context<InitializeConfig>, params: ConfigParams) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.admin = ctx.accounts.admin.key();
+        config.paused = false;
+        config.bump = ctx.bumps.config;
+        config.apply(params)?;
+
+        msg!("Config initialized. Admin: {}", config.admin);
+        Ok(())
+    }
+
+    /// Admin updates the tunable protocol parameters
+    pub fn update_config(ctx: Context<UpdateConfig>, params: ConfigParams) -> Result<()> {
+        ctx.accounts.config.apply(params)?;
+
+        msg!("Config updated");
+        Ok(())
+    }
+
+    /// Admin emergency stop: while paused no bonds are created or staked
+    /// and slashing is held, but completions and refunds still go through.
+    /// Shares the flag the circuit breaker trips
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        ctx.accounts.config.paused = paused;
+
+        emit!(ProgramPauseSet {
+            admin: ctx.accounts.admin.key(),
+            paused,
+        });
+
+        msg!("Program paused: {}", paused);
+        Ok(())
+    }
+
+    /// Admin clears a tripped circuit breaker and starts a fresh slash window
+    pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let stats = &mut ctx.accounts.stats;
+
+        config.paused = false;
+        stats.breaker_window_start = Clock::get()?.unix_timestamp;
+        stats.breaker_window_slashes = 0;
+
+        emit!(CircuitBreakerReset {
+            admin: ctx.accounts.admin.key(),
+        });
+
+        msg!("Circuit breaker reset");
+        Ok(())
+    }
+
+    /// One-time setup of the global protocol statistics account
+    pub fn initialize_stats(ctx: Context<InitializeStats>) -> Result<()> {
+        ctx.accounts.stats.bump = ctx.bumps.stats;
+        msg!("Protocol stats initialized");
+        Ok(())
+    }
+
+    /// Apply pending decay to an agent's reputation (permissionless)
+    pub fn refresh_reputation(ctx: Context<RefreshReputation>) -> Result<()> {
+        let reputation = &mut ctx.accounts.reputation;
+        reputation.decay(Clock::get()?.unix_timestamp, ctx.accounts.config.reputation_half_life);
+        Ok(())
+    }
+
+    /// Bring a reputation account written under an older layout up to date
+    /// Accumulated scores carry over untouched; new fields are backfilled
+    pub fn migrate_reputation(ctx: Context<MigrateReputation>) -> Result<()> {
+        let reputation = ctx.accounts.reputation.to_account_info();
+        let body_len = reputation
+            .data_len()
+            .checked_sub(8)
+            .ok_or(ErrorCode::AccountDiscriminatorNotFound)?;
+        let from_version = match body_len {
+            AgentReputation::V1_SIZE => 1,
+            AgentReputation::V2_SIZE => 2,
+            _ => return Err(AgberoError::ReputationUpToDate.into()),
+        };
+        let discriminator = <AgentReputation as anchor_lang::Discriminator>::DISCRIMINATOR;
+        require!(
+            reputation.try_borrow_data()?[..8] == discriminator,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        let new_len = 8 + AgentReputation::MAX_SIZE;
+        let rent_due = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(reputation.lamports());
+        if rent_due > 0 {
+            let cpi_context = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: reputation.clone(),
+                },
+            );
+            system_program::transfer(cpi_context, rent_due)?;
+        }
+        reputation.realloc(new_len, true)?;
+
+        // v1 added the version byte, v2 the raw counters; realloc zeroed
+        // both, which is the right starting point for the counters
+        reputation.try_borrow_mut_data()?[8 + AgentReputation::V1_SIZE] = AgentReputation::VERSION;
+
+        emit!(ReputationMigrated {
+            agent: ctx.accounts.agent.key(),
+            from_version,
+            to_version: AgentReputation::VERSION,
+        });
+        Ok(())
+    }
+
+    /// Canonical bond and vault addresses for a bond_id
+    /// Read-only: returned via return data
+    pub fn derive_pdas(_ctx: Context<DerivePdas>, bond_id: String) -> Result<BondPdas> {
+        require!(!bond_id.is_empty(), AgberoError::BondIdEmpty);
+        require!(bond_id.len() <= Bond::MAX_ID_LEN, AgberoError::BondIdTooLong);
+        let (bond, bond_bump) =
+            Pubkey::find_program_address(&[b"bond", bond_id.as_bytes()], &crate::ID);
+        let (bond_vault, vault_bump) =
+            Pubkey::find_program_address(&[b"bond_vault", bond.as_ref()], &crate::ID);
+        Ok(BondPdas {
+            bond,
+            bond_bump,
+            bond_vault,
+            vault_bump,
+        })
+    }
+
+    /// Running hash of a verifier's vote history, for audits against an
+    /// off-chain record of their votes
+    /// Read-only: returned via return data
+    pub fn get_verifier_history_hash(ctx: Context<GetVerifierHistoryHash>) -> Result<[u8; 32]> {
+        Ok(ctx.accounts.profile.history_hash)
+    }
+
+    /// Human-readable token amount, e.g. 1500000 at 6 decimals -> "1.500000"
+    /// Read-only: returned via return data
+    pub fn format_collateral(ctx: Context<FormatCollateral>, amount: u64) -> Result<String> {
+        Ok(format_token_amount(amount, ctx.accounts.mint.decimals))
+    }
+
+    /// Lifecycle timestamps for timeline rendering, 0 for steps not reached
+    /// Read-only: returned via return data
+    pub fn get_timeline(ctx: Context<GetTimeline>) -> Result<BondTimeline> {
+        let bond = &ctx.accounts.bond;
+        Ok(BondTimeline {
+            created_at: bond.created_at,
+            staked_at: bond.staked_at,
+            started_at: bond.started_at,
+            proof_submitted_at: bond.proofs.first().map_or(0, |p| p.submitted_at),
+            finalized_at: bond.completed_at,
+        })
+    }
+
+    /// Recompute and store a settled bond's ranking score
+    /// Permissionless and idempotent; covers bonds settled outside finalize
+    pub fn compute_bond_score(ctx: Context<ComputeBondScore>) -> Result<u64> {
+        let bond = &mut ctx.accounts.bond;
+        require!(bond.is_terminal(), AgberoError::InvalidBondStatus);
+
+        bond.score = bond.compute_score();
+        Ok(bond.score)
+    }
+
+    /// Aggregate protocol health metrics for monitoring dashboards
+    /// Read-only: returned via return data
+    pub fn protocol_health(ctx: Context<QueryProtocolHealth>) -> Result<HealthMetrics> {
+        let stats = &ctx.accounts.stats;
+        Ok(HealthMetrics {
+            slash_rate_bps: stats.slash_rate_bps(),
+            average_bond_size: stats.average_bond_size(),
+            total_value_locked: stats.total_value_locked,
+            active_bonds: stats.active_bonds,
+        })
+    }
+
+    /// Advisory sizing for verifier rewards: verifiers join while their
+    /// share covers PARTICIPATION_COST, up to the registered pool and the
+    /// per-bond vote cap. Read-only: returned via return data
+    pub fn estimate_participation(
+        ctx: Context<EstimateParticipation>,
+        reward: u64,
+    ) -> Result<ParticipationEstimate> {
+        let mut pool = ctx.accounts.stats.registered_verifiers;
+        if ctx.accounts.config.max_verifiers > 0 {
+            pool = pool.min(ctx.accounts.config.max_verifiers);
+        }
+        let expected_verifiers = (reward / ParticipationEstimate::PARTICIPATION_COST)
+            .min(pool)
+            .min(Bond::MAX_VOTES as u64);
+
+        Ok(ParticipationEstimate {
+            expected_verifiers,
+            reward_per_verifier: reward.checked_div(expected_verifiers).unwrap_or(0),
+            reaches_quorum: ctx.accounts.config.quorum_met(expected_verifiers),
+        })
+    }
+
+    /// Current vote tally and whether finalize_bond would settle the bond,
+    /// using the same quorum rules. Read-only: returned via return data
+    pub fn get_bond_status(ctx: Context<GetBondStatus>) -> Result<QuorumSnapshot> {
+        let bond = &ctx.accounts.bond;
+        bond.quorum_snapshot(&ctx.accounts.config, Clock::get()?.unix_timestamp)
+    }
+
+    /// Lifecycle history rebuilt from the bond's own fields, for indexers
+    /// backfilling events an RPC has pruned. Read-only: returned via
+    /// return data, so proof URIs are left out to stay under its size limit
+    pub fn replay_events(ctx: Context<ReplayEvents>) -> Result<BondReplay> {
+        let bond = &ctx.accounts.bond;
+        Ok(BondReplay {
+            bond_id: bond.bond_id.clone(),
+            principal: bond.principal,
+            agent: bond.agent,
+            status: bond.status,
+            slash_reason: bond.slash_reason,
+            collateral_amount: bond.collateral_amount,
+            buffer_amount: bond.buffer_amount,
+            keeper_bounty: bond.keeper_bounty,
+            tip_pool: bond.tip_pool,
+            created_at: bond.created_at,
+            staked_at: bond.staked_at,
+            started_at: bond.started_at,
+            deadline: bond.deadline,
+            slash_requested_at: bond.slash_requested_at,
+            slash_appealed: bond.slash_appealed,
+            slash_acknowledged: bond.slash_acknowledged,
+            completed_at: bond.completed_at,
+            total_slashed: bond.total_slashed,
+            total_released: bond.total_released,
+            proofs: bond
+                .proofs
+                .iter()
+                .map(|p| ReplayProof {
+                    submitted_at: p.submitted_at,
+                    late: p.late,
+                })
+                .collect(),
+            votes: bond
+                .verification_votes
+                .iter()
+                .map(|v| ReplayVote {
+                    verifier: v.verifier,
+                    approve: v.approve,
+                    timestamp: v.timestamp,
+                    excluded: v.excluded,
+                })
+                .collect(),
+        })
+    }
+
+    /// Whether `caller` could finalize the bond right now on its own:
+    /// the quorum allows it, a pause does not block the slash it would
+    /// make, and when an escrow authority is set the caller is that escrow
+    /// (anyone else needs it to co-sign). Read-only: returned via return data
+    pub fn can_finalize(ctx: Context<GetBondStatus>, caller: Pubkey) -> Result<bool> {
+        let bond = &ctx.accounts.bond;
+        let config = &ctx.accounts.config;
+        let now = Clock::get()?.unix_timestamp;
+        let snapshot = bond.quorum_snapshot(config, now)?;
+        let paused = config.paused && bond.finalize_slashes(&snapshot, config, now)?;
+        let authorized =
+            bond.escrow_authority == Pubkey::default() || bond.escrow_authority == caller;
+        Ok(snapshot.can_finalize && !paused && authorized)
+    }
+
+    /// Advisory deadline for a task: the configured base duration, scaled
+    /// by complexity tier and by description length in DEADLINE_LENGTH_UNIT
+    /// steps. Read-only: returns seconds from now via return data
+    pub fn suggest_deadline(
+        ctx: Context<SuggestDeadline>,
+        description_len: u32,
+        complexity_tier: u8,
+    ) -> Result<i64> {
+        require!(
+            complexity_tier <= Config::MAX_COMPLEXITY_TIER,
+            AgberoError::InvalidComplexityTier
+        );
+        let base = match ctx.accounts.config.deadline_base_duration {
+            0 => Config::DEFAULT_DEADLINE_BASE,
+            base => base,
+        };
+        let length_steps = 1 + (description_len / Config::DEADLINE_LENGTH_UNIT) as i64;
+        Ok(base
+            .saturating_mul(1 + complexity_tier as i64)
+            .saturating_mul(length_steps))
+    }
+
+    /// Check a bond's internal consistency for monitoring
+    /// Read-only: returns a bitmask of Bond::VIOLATION_* flags, 0 when healthy
+    pub fn check_invariants(ctx: Context<CheckInvariants>) -> Result<u32> {
+        Ok(ctx
+            .accounts
+            .bond
+            .invariant_violations(ctx.accounts.bond_vault.lamports()))
+    }
+
+    /// Reconstruct what the vault should hold from the bond's accounting and
+    /// compare it with the actual lamports, for spotting drift
+    /// Read-only: returned via return data
+    pub fn audit_vault(ctx: Context<CheckInvariants>) -> Result<VaultAudit> {
+        let bond = &ctx.accounts.bond;
+        let actual = ctx.accounts.bond_vault.lamports();
+        let expected = bond.expected_vault_balance().unwrap_or(u64::MAX);
+        Ok(VaultAudit {
+            expected,
+            actual,
+            discrepancy: actual as i128 - expected as i128,
+        })
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(bond_id: String)]
+pub struct CreateBond<'info> {
+    #[account(mut)]
+    pub principal: Signer<'info>,
+    /// CHECK: Agent pubkey, verified in logic
+    pub agent: AccountInfo<'info>,
+    
+    #[account(
+        init,
+        payer = principal,
+        space = 8 + Bond::MAX_SIZE,
+        seeds = [b"bond", bond_id.as_bytes()],
+        bump
+    )]
+    pub bond: Account<'info, Bond>,
+    
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    #[account(mut, seeds = [b"stats"], bump = stats.bump)]
+    pub stats: Account<'info, Stats>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Agent's reputation PDA, snapshotted if it exists (first bonds have none)
+    #[account(seeds = [b"reputation", agent.key().as_ref()], bump)]
+    pub reputation: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = principal,
+        space = 8 + BondPair::MAX_SIZE,
+        seeds = [b"pair", principal.key().as_ref(), agent.key().as_ref()],
+        bump
+    )]
+    pub pair: Account<'info, BondPair>,
+
+    /// CHECK: Reservation PDA for keccak(bond_id), enforced and closed only if it exists
+    #[account(
+        mut,
+        seeds = [b"reservation", keccak::hash(bond_id.as_bytes()).as_ref()],
+        bump
+    )]
+    pub reservation: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(commitment: [u8; 32])]
+pub struct ReserveBondId<'info> {
+    #[account(mut)]
+    pub reserver: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = reserver,
+        space = 8 + BondReservation::MAX_SIZE,
+        seeds = [b"reservation", commitment.as_ref()],
+        bump
+    )]
+    pub reservation: Account<'info, BondReservation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ValidateBondParams<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct StakeCollateral<'info> {
+    #[account(mut)]
+    pub agent: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = bond.agent == agent.key()
+    )]
+    pub bond: Account<'info, Bond>,
+    
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    #[account(mut, seeds = [b"stats"], bump = stats.bump)]
+    pub stats: Account<'info, Stats>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// Optional: agent's pooled staking vault to draw collateral from
+    #[account(
+        mut,
+        seeds = [b"staking_vault", agent.key().as_ref()],
+        bump
+    )]
+    pub staking_vault: Option<SystemAccount<'info>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BatchStake<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(mut, seeds = [b"stats"], bump = stats.bump)]
+    pub stats: Account<'info, Stats>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundInstallment<'info> {
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.agent == agent.key() @ AgberoError::UnauthorizedAgent
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    #[account(mut, seeds = [b"stats"], bump = stats.bump)]
+    pub stats: Account<'info, Stats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SlashMissedInstallment<'info> {
+    #[account(mut)]
+    pub principal: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.principal == principal.key() @ AgberoError::UnauthorizedPrincipal
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    #[account(mut, seeds = [b"stats"], bump = stats.bump)]
+    pub stats: Account<'info, Stats>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = principal,
+        space = 8 + AgentReputation::MAX_SIZE,
+        seeds = [b"reputation", bond.agent.as_ref()],
+        bump
+    )]
+    pub reputation: Account<'info, AgentReputation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ContributeCollateral<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetTeamLead<'info> {
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.agent == agent.key() @ AgberoError::UnauthorizedAgent
+    )]
+    pub bond: Account<'info, Bond>,
+}
+
+#[derive(Accounts)]
+pub struct CancelBond<'info> {
+    #[account(mut)]
+    pub principal: Signer<'info>,
+
+    #[account(
+        mut,
+        close = principal,
+        constraint = bond.principal == principal.key() @ AgberoError::UnauthorizedPrincipal
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelUnstaked<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(mut, close = principal)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    /// CHECK: Principal receives the bounty and rent refund
+    #[account(mut, address = bond.principal)]
+    pub principal: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageStakingVault<'info> {
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_vault", agent.key().as_ref()],
+        bump
+    )]
+    pub staking_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BeginWork<'info> {
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.agent == agent.key() @ AgberoError::UnauthorizedAgent
+    )]
+    pub bond: Account<'info, Bond>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitProof<'info> {
+    /// Agent, or the team lead when one is set
+    #[account(mut)]
+    pub agent: Signer<'info>,
+    
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyWork<'info> {
+    pub verifier: Signer<'info>,
+    
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveDaoDecision<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub governance: Signer<'info>,
+
+    pub bond: Account<'info, Bond>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + DaoDecision::MAX_SIZE,
+        seeds = [b"dao_decision", bond.key().as_ref(), governance.key().as_ref()],
+        bump
+    )]
+    pub decision: Account<'info, DaoDecision>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DaoVerify<'info> {
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        seeds = [b"dao_decision", bond.key().as_ref(), decision.governance.as_ref()],
+        bump = decision.bump,
+        has_one = bond
+    )]
+    pub decision: Account<'info, DaoDecision>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitVoteBundle<'info> {
+    pub submitter: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Instructions sysvar, read for the Ed25519 signature checks
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetProxy<'info> {
+    pub member: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+}
+
+#[derive(Accounts)]
+pub struct StakeVerifier<'info> {
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = 8 + VerifierStake::MAX_SIZE,
+        seeds = [b"verifier_stake", bond.key().as_ref(), verifier.key().as_ref()],
+        bump
+    )]
+    pub verifier_stake: Account<'info, VerifierStake>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseEvidence<'info> {
+    #[account(mut)]
+    pub verifier: SystemAccount<'info>,
+
+    /// CHECK: The evidence's bond, read only if it still exists
+    #[account(address = evidence.bond)]
+    pub bond: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"evidence", bond.key().as_ref(), verifier.key().as_ref()],
+        bump = evidence.bump,
+        has_one = verifier,
+        close = verifier
+    )]
+    pub evidence: Account<'info, Evidence>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimVerifierStake<'info> {
+    #[account(mut)]
+    pub verifier: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        seeds = [b"verifier_stake", bond.key().as_ref(), verifier.key().as_ref()],
+        bump = verifier_stake.bump,
+        has_one = verifier,
+        has_one = bond,
+        close = verifier
+    )]
+    pub verifier_stake: Account<'info, VerifierStake>,
+}
+
+#[derive(Accounts)]
+pub struct CommitVote<'info> {
+    pub verifier: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+}
+
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    pub verifier: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+}
+
+#[derive(Accounts)]
+pub struct RequestSubcommittee<'info> {
+    /// Principal or agent
+    pub party: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct FormSubcommittee<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(seeds = [b"stats"], bump = stats.bump)]
+    pub stats: Account<'info, Stats>,
+
+    /// CHECK: SlotHashes sysvar, read for the draw seed
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReassignInactive<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(seeds = [b"stats"], bump = stats.bump)]
+    pub stats: Account<'info, Stats>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeBond<'info> {
+    /// CHECK: Anyone can call to execute autonomously
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+    
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+    
+    /// CHECK: Agent account for refund
+    #[account(mut, address = bond.agent)]
+    pub agent: AccountInfo<'info>,
+    
+    /// CHECK: Principal account for slash payout
+    #[account(mut, address = bond.principal)]
+    pub principal: AccountInfo<'info>,
+
+    #[account(mut, seeds = [b"stats"], bump = stats.bump)]
+    pub stats: Account<'info, Stats>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + AgentReputation::MAX_SIZE,
+        seeds = [b"reputation", bond.agent.as_ref()],
+        bump
+    )]
+    pub reputation: Account<'info, AgentReputation>,
+
+    /// Receives Config::insurance_bps of every slash
+    #[account(mut, seeds = [b"insurance_pool"], bump)]
+    pub insurance_pool: SystemAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(verifier: Pubkey)]
+pub struct ArbiterInvalidateVote<'info> {
+    pub arbiter: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.arbiter != Pubkey::default()
+            && config.arbiter == arbiter.key() @ AgberoError::UnauthorizedArbiter
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    /// Required only when slashing the verifier's stake
+    #[account(
+        mut,
+        seeds = [b"verifier_stake", bond.key().as_ref(), verifier.as_ref()],
+        bump = verifier_stake.bump
+    )]
+    pub verifier_stake: Option<Account<'info, VerifierStake>>,
+
+    /// CHECK: Principal receives a forfeited verifier stake
+    #[account(mut, address = bond.principal)]
+    pub principal: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveCallback<'info> {
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.agent == agent.key() @ AgberoError::UnauthorizedAgent
+    )]
+    pub bond: Account<'info, Bond>,
+}
+
+#[derive(Accounts)]
+pub struct EmergencySlash<'info> {
+    #[account(mut)]
+    pub principal: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = bond.principal == principal.key()
+    )]
+    pub bond: Account<'info, Bond>,
+    
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+    
+    /// CHECK: Principal receives slash
+    #[account(mut, address = bond.principal)]
+    pub principal_vault: AccountInfo<'info>,
+
+    #[account(mut, seeds = [b"stats"], bump = stats.bump)]
+    pub stats: Account<'info, Stats>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = principal,
+        space = 8 + AgentReputation::MAX_SIZE,
+        seeds = [b"reputation", bond.agent.as_ref()],
+        bump
+    )]
+    pub reputation: Account<'info, AgentReputation>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AppealEmergencySlash<'info> {
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.agent == agent.key() @ AgberoError::UnauthorizedAgent
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct AcknowledgeSlash<'info> {
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.agent == agent.key() @ AgberoError::UnauthorizedAgent
+    )]
+    pub bond: Account<'info, Bond>,
+}
+
+#[derive(Accounts)]
+pub struct RequestEmergencySlash<'info> {
+    pub principal: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.principal == principal.key() @ AgberoError::UnauthorizedPrincipal
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct OracleSettle<'info> {
+    #[account(mut)]
+    pub oracle: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    /// CHECK: Agent account for release
+    #[account(mut, address = bond.agent)]
+    pub agent: AccountInfo<'info>,
+
+    /// CHECK: Principal account for slash payout and bounty refund
+    #[account(mut, address = bond.principal)]
+    pub principal: AccountInfo<'info>,
+
+    #[account(mut, seeds = [b"stats"], bump = stats.bump)]
+    pub stats: Account<'info, Stats>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.oracle != Pubkey::default()
+            && config.oracle == oracle.key() @ AgberoError::UnauthorizedOracle
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = oracle,
+        space = 8 + AgentReputation::MAX_SIZE,
+        seeds = [b"reputation", bond.agent.as_ref()],
+        bump
+    )]
+    pub reputation: Account<'info, AgentReputation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CommitTally<'info> {
+    pub oracle: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.oracle != Pubkey::default()
+            && config.oracle == oracle.key() @ AgberoError::UnauthorizedOracle
+    )]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct EscalateToArbiter<'info> {
+    /// Principal or agent
+    pub party: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    /// CHECK: Agent account for release
+    #[account(mut, address = bond.agent)]
+    pub agent: AccountInfo<'info>,
+
+    /// CHECK: Principal account for slash payout, bounty refund and forfeited stakes
+    #[account(mut, address = bond.principal)]
+    pub principal: AccountInfo<'info>,
+
+    #[account(mut, seeds = [b"stats"], bump = stats.bump)]
+    pub stats: Account<'info, Stats>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.arbiter != Pubkey::default()
+            && config.arbiter == arbiter.key() @ AgberoError::UnauthorizedArbiter
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = arbiter,
+        space = 8 + AgentReputation::MAX_SIZE,
+        seeds = [b"reputation", bond.agent.as_ref()],
+        bump
+    )]
+    pub reputation: Account<'info, AgentReputation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetHold<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.legal_authority != Pubkey::default()
+            && config.legal_authority == authority.key() @ AgberoError::UnauthorizedLegalAuthority
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+}
+
+#[derive(Accounts)]
+#[instruction(recipient: Pubkey)]
+pub struct FinalizeOverride<'info> {
+    pub arbiter: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.arbiter != Pubkey::default()
+            && config.arbiter == arbiter.key() @ AgberoError::UnauthorizedArbiter
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    /// CHECK: Any wallet the arbiter names; only receives lamports
+    #[account(mut, address = recipient)]
+    pub recovery_wallet: AccountInfo<'info>,
+
+    /// CHECK: Principal account for the remainder
+    #[account(mut, address = bond.principal)]
+    pub principal: AccountInfo<'info>,
+
+    #[account(mut, seeds = [b"stats"], bump = stats.bump)]
+    pub stats: Account<'info, Stats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterVerifier<'info> {
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+
+    #[account(
+        init,
+        payer = verifier,
+        space = 8 + VerifierProfile::MAX_SIZE,
+        seeds = [b"verifier", verifier.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, VerifierProfile>,
+
+    #[account(mut, seeds = [b"stats"], bump = stats.bump)]
+    pub stats: Account<'info, Stats>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetVerifierCategories<'info> {
+    pub verifier: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"verifier", verifier.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, VerifierProfile>,
+}
+
+#[derive(Accounts)]
+pub struct FundTips<'info> {
+    #[account(mut)]
+    pub principal: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.principal == principal.key() @ AgberoError::UnauthorizedPrincipal
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct JoinSyndicate<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AttachEvidence<'info> {
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = 8 + Evidence::MAX_SIZE,
+        seeds = [b"evidence", bond.key().as_ref(), verifier.key().as_ref()],
+        bump
+    )]
+    pub evidence: Account<'info, Evidence>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundVerifierRewards<'info> {
+    #[account(mut)]
+    pub principal: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.principal == principal.key() @ AgberoError::UnauthorizedPrincipal
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(address = config.reward_mint @ AgberoError::InvalidRewardMint)]
+    pub reward_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = principal
+    )]
+    pub principal_token: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = principal,
+        seeds = [b"reward_escrow", bond.key().as_ref()],
+        bump,
+        token::mint = reward_mint,
+        token::authority = reward_escrow
+    )]
+    pub reward_escrow: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimVerifierReward<'info> {
+    pub verifier: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_escrow", bond.key().as_ref()],
+        bump
+    )]
+    pub reward_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = reward_escrow.mint,
+        token::authority = verifier
+    )]
+    pub verifier_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimVerifierRewards<'info> {
+    pub principal: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.principal == principal.key() @ AgberoError::UnauthorizedPrincipal
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_escrow", bond.key().as_ref()],
+        bump
+    )]
+    pub reward_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = reward_escrow.mint,
+        token::authority = principal
+    )]
+    pub principal_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimInsurance<'info> {
+    #[account(mut)]
+    pub principal: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.principal == principal.key() @ AgberoError::UnauthorizedPrincipal
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(mut, seeds = [b"insurance_pool"], bump)]
+    pub insurance_pool: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveMilestone<'info> {
+    pub verifier: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(seeds = [b"verifier", verifier.key().as_ref()], bump = profile.bump)]
+    pub profile: Account<'info, VerifierProfile>,
+
+    #[account(
+        seeds = [b"verifier_stake", bond.key().as_ref(), verifier.key().as_ref()],
+        bump = verifier_stake.bump
+    )]
+    pub verifier_stake: Account<'info, VerifierStake>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseMilestone<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    /// CHECK: Agent receives the milestone
+    #[account(mut, address = bond.agent)]
+    pub agent: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PayAgentPremium<'info> {
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.agent == agent.key() @ AgberoError::UnauthorizedAgent
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut, seeds = [b"insurance_pool"], bump)]
+    pub insurance_pool: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PayPrincipalPremium<'info> {
+    #[account(mut)]
+    pub principal: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.principal == principal.key() @ AgberoError::UnauthorizedPrincipal
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut, seeds = [b"insurance_pool"], bump)]
+    pub insurance_pool: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RuleWrongfulSlash<'info> {
+    pub arbiter: Signer<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.arbiter != Pubkey::default()
+            && config.arbiter == arbiter.key() @ AgberoError::UnauthorizedArbiter
+    )]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimAgentInsurance<'info> {
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.agent == agent.key() @ AgberoError::UnauthorizedAgent
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(mut, seeds = [b"insurance_pool"], bump)]
+    pub insurance_pool: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimDeferredReward<'info> {
+    #[account(mut)]
+    pub verifier: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimVoteRent<'info> {
+    #[account(mut)]
+    pub principal: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.principal == principal.key() @ AgberoError::UnauthorizedPrincipal
+    )]
+    pub bond: Account<'info, Bond>,
+}
+
+#[derive(Accounts)]
+pub struct CloseBond<'info> {
+    #[account(mut)]
+    pub principal: Signer<'info>,
+
+    #[account(
+        mut,
+        close = principal,
+        constraint = bond.principal == principal.key() @ AgberoError::UnauthorizedPrincipal
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TransferPrincipal<'info> {
+    pub principal: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.principal == principal.key() @ AgberoError::UnauthorizedPrincipal
+    )]
+    pub bond: Account<'info, Bond>,
+}
+
+#[derive(Accounts)]
+pub struct ExtendDeadline<'info> {
+    pub principal: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.principal == principal.key() @ AgberoError::UnauthorizedPrincipal
+    )]
+    pub bond: Account<'info, Bond>,
+}
+
+#[derive(Accounts)]
+pub struct AmendTask<'info> {
+    pub principal: Signer<'info>,
+
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.principal == principal.key() @ AgberoError::UnauthorizedPrincipal,
+        constraint = bond.agent == agent.key() @ AgberoError::UnauthorizedAgent
+    )]
+    pub bond: Account<'info, Bond>,
+}
+
+#[derive(Accounts)]
+pub struct TopUpBuffer<'info> {
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.agent == agent.key() @ AgberoError::UnauthorizedAgent
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReduceCollateral<'info> {
+    pub principal: Signer<'info>,
+
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bond.principal == principal.key() @ AgberoError::UnauthorizedPrincipal,
+        constraint = bond.agent == agent.key() @ AgberoError::UnauthorizedAgent
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+
+    #[account(mut, seeds = [b"stats"], bump = stats.bump)]
+    pub stats: Account<'info, Stats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
         init,
-        payer = principal,
-        space = 8 + Bond::MAX_SIZE,
-        seeds = [b"bond", bond_id.as_bytes()],
+        payer = admin,
+        space = 8 + Config::MAX_SIZE,
+        seeds = [b"config"],
         bump
     )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ AgberoError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ AgberoError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct ResetCircuitBreaker<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ AgberoError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut, seeds = [b"stats"], bump = stats.bump)]
+    pub stats: Account<'info, Stats>,
+}
+
+#[derive(Accounts)]
+pub struct PokeBond<'info> {
+    #[account(mut)]
     pub bond: Account<'info, Bond>,
-    
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct RefreshReputation<'info> {
+    #[account(
+        mut,
+        seeds = [b"reputation", reputation.agent.as_ref()],
+        bump = reputation.bump
+    )]
+    pub reputation: Account<'info, AgentReputation>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateReputation<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: only used to derive the reputation PDA
+    pub agent: UncheckedAccount<'info>,
+
+    /// CHECK: old layouts do not deserialize; owner, seeds and
+    /// discriminator are checked instead
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [b"reputation", agent.key().as_ref()],
+        bump
+    )]
+    pub reputation: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     #[account(
         init,
-        payer = principal,
-        space = 8,
-        seeds = [b"bond_vault", bond.key().as_ref()],
+        payer = payer,
+        space = 8 + Stats::MAX_SIZE,
+        seeds = [b"stats"],
         bump
     )]
-    pub bond_vault: SystemAccount<'info>,
-    
+    pub stats: Account<'info, Stats>,
+
     pub system_program: Program<'info, System>,
 }
 
-#[derive(Accounts)]
-pub struct StakeCollateral<'info> {
-    #[account(mut)]
-    pub agent: Signer<'info>,
-    
-    #[account(
-        mut,
-        constraint = bond.agent == agent.key()
-    )]
-    pub bond: Account<'info, Bond>,
-    
-    #[account(
-        mut,
-        seeds = [b"bond_vault", bond.key().as_ref()],
-        bump = bond.bump
-    )]
-    pub bond_vault: SystemAccount<'info>,
-    
-    pub system_program: Program<'info, System>,
+#[derive(Accounts)]
+pub struct QueryProtocolHealth<'info> {
+    #[account(seeds = [b"stats"], bump = stats.bump)]
+    pub stats: Account<'info, Stats>,
+}
+
+#[derive(Accounts)]
+pub struct EstimateParticipation<'info> {
+    #[account(seeds = [b"stats"], bump = stats.bump)]
+    pub stats: Account<'info, Stats>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct GetBondStatus<'info> {
+    pub bond: Account<'info, Bond>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct ReplayEvents<'info> {
+    pub bond: Account<'info, Bond>,
+}
+
+#[derive(Accounts)]
+pub struct SuggestDeadline<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct DerivePdas {}
+
+#[derive(Accounts)]
+pub struct FormatCollateral<'info> {
+    pub mint: Account<'info, Mint>,
+}
+
+#[derive(Accounts)]
+pub struct GetTimeline<'info> {
+    pub bond: Account<'info, Bond>,
+}
+
+#[derive(Accounts)]
+pub struct GetVerifierHistoryHash<'info> {
+    pub profile: Account<'info, VerifierProfile>,
+}
+
+#[derive(Accounts)]
+pub struct ComputeBondScore<'info> {
+    #[account(mut)]
+    pub bond: Account<'info, Bond>,
+}
+
+#[derive(Accounts)]
+pub struct CheckInvariants<'info> {
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        seeds = [b"bond_vault", bond.key().as_ref()],
+        bump = bond.vault_bump
+    )]
+    pub bond_vault: SystemAccount<'info>,
+}
+
+#[account]
+pub struct Bond {
+    pub bond_id: String,              // 4 + 50
+    pub principal: Pubkey,            // 32
+    pub agent: Pubkey,                // 32
+    pub task_description: String,     // 4 + 500
+    pub collateral_amount: u64,       // 8
+    pub deadline: i64,                // 8
+    pub status: BondStatus,           // 1
+    pub created_at: i64,              // 8
+    pub completed_at: i64,            // 8
+    pub verification_votes: Vec<VerificationVote>, // 4 + (83 * 10)
+    pub slash_votes: Vec<SlashVote>,  // 4 + (SlashVote::MAX_SIZE * 10)
+    pub proofs: Vec<ProofEntry>,      // 4 + (213 * 3)
+    pub bump: u8,                     // 1
+    pub commit_window: i64,           // 8
+    pub reveal_window: i64,           // 8
+    pub commit_deadline: i64,         // 8
+    pub reveal_deadline: i64,         // 8
+    pub vote_commits: Vec<VoteCommit>, // 4 + (65 * 10)
+    pub subcommittee: Vec<Pubkey>,    // 4 + (32 * 10)
+    pub keeper_bounty: u64,           // 8
+    pub vote_mode: VoteMode,          // 1
+    pub slash_reason: Option<SlashReason>, // 1 + 1
+    pub proxies: Vec<ProxyDesignation>, // 4 + (64 * 10)
+    pub reputation_snapshot: ReputationSnapshot, // 24
+    pub vote_weighting: VoteWeighting, // 1
+    pub callback: Option<CompletionCallback>, // 1 + 32 + 4 + 64
+    pub callback_approved: bool,      // 1
+    pub stake_deadline: i64,          // 8
+    pub reward_pool: u64,             // 8
+    pub rewards_claimed: u16,         // 2, bit per verification_votes index
+    pub team: Vec<TeamMember>,        // 4 + (40 * 5), agent's share is the remainder
+    pub team_lead: Pubkey,            // 32, default = agent submits proof
+    pub verification_deadline: i64,   // 8, 0 = timed out from deadline + timeout_grace
+    pub score: u64,                   // 8, ranking score set on completion
+    pub insured_amount: u64,          // 8, cover claimable if the agent absconds
+    pub insurance_due: u64,           // 8, cover not yet paid out of the pool
+    pub staked_at: i64,               // 8
+    pub started_at: i64,              // 8, set by begin_work, 0 = not started
+    pub require_begin_work: bool,     // 1, submit_proof needs begin_work first
+    pub arbiter_ruling: Option<bool>, // 2, set by resolve_dispute (true = completed)
+    pub assignment_deadlines: Vec<i64>, // 4 + (8 * 10), per subcommittee slot, 0 = no limit
+    pub installments: u8,             // 1, 0 or 1 = single stake_collateral
+    pub installment_interval: i64,    // 8, seconds between installment due dates
+    pub installments_paid: u8,        // 1
+    pub funded_amount: u64,           // 8, collateral paid in via installments
+    pub buffer_amount: u64,           // 8, agent top-up consumed before the base by partial slashes
+    pub total_slashed: u64,           // 8, lamports paid away from the agent's side at settlement
+    pub total_released: u64,          // 8, lamports returned to the agent's side at settlement
+    pub slash_justification: String,  // 4 + 200, principal's reason for an emergency slash
+    pub tip_pool: u64,                // 8, lamports escrowed for evidence-backed winning votes
+    pub on_hold: bool,                // 1, legal hold: no funds leave the vault while set
+    pub vault_bump: u8,               // 1, bump of the bond_vault PDA (bump is the bond's own)
+    pub appeal_window: i64,           // 8, seconds between an emergency slash request and execution
+    pub slash_requested_at: i64,      // 8, 0 = no emergency slash requested
+    pub category: u8,                 // 1, task category, 0 = open to every verifier
+    pub deadline_warned: bool,        // 1, DeadlineApproaching emitted for the current deadline
+    pub deadline_passed_noted: bool,  // 1, DeadlinePassed emitted for the current deadline
+    pub slash_appealed: bool,         // 1, agent appealed an emergency slash request
+    pub escrow_authority: Pubkey,     // 32, must co-sign every vault release, default = none
+    pub extension_count: u8,          // 1, extend_deadline calls so far, at most MAX_EXTENSIONS
+    pub agent_premium_paid: u64,      // 8, agent's wrongful-slash cover premium, 0 = uncovered
+    pub agent_compensation_due: u64,  // 8, owed to the agent from the insurance pool
+    pub milestones: Vec<Milestone>,   // 4 + (Milestone::MAX_SIZE * MAX_MILESTONES)
+    pub milestones_released: u64,     // 8, collateral already paid out by release_milestone
+    pub syndicate: Vec<SyndicateMember>, // 4 + (40 * MAX_SYNDICATE), co-principals sharing slashes
+    pub slash_destination: SlashDestination, // 1, where slashed collateral goes
+    pub rubric: String,               // 4 + MAX_RUBRIC, verification criteria for verifiers
+    pub vault_rent: u64,              // 8, rent reserve the principal pre-funded into the vault
+    pub slash_acknowledged: bool,     // 1, agent conceded a requested emergency slash
+    pub required_votes: u64,          // 8, quorum for this bond's collateral, raised by top-ups
+    pub stake_forfeit_bps: u16,       // 2, fixed at finalize when the vote decided the bond, else 0
+    pub forfeits_collected: u64,      // 8, losing stakes moved into the bond account for winners
+    pub principal_premium_paid: u64,  // 8, principal's no-proof cover premium, 0 = uncovered
+    pub open_stakes: u32,             // 4, verifier stake accounts not yet reclaimed
+    pub subcommittee_draw_slot: u64,  // 8, slot whose hash seeds the sub-committee draw, 0 = not requested
+    pub subcommittee_seed: [u8; 32],  // 32, draw state reassign_inactive continues from
+    pub tally_root: [u8; 32],         // 32, Merkle root of the oracle's committed tally
+    pub tally_approve: u32,           // 4
+    pub tally_total: u32,             // 4
+    pub tally_slot: u64,              // 8, slot the tally was committed in, 0 = none
+    pub rewards_paid: u64,            // 8, reward tokens claimed by winners or reclaimed by the principal
+    pub principal_contribution: u64,  // 8, tips the principal funded, its weight against the syndicate
+    pub deferred_rewards: u16,        // 2, bit per verification_votes index whose SOL reward waits in the bond account
+    pub reward_share: u64,            // 8, SOL reward each approving verifier was owed at finalize
+    pub deferred_tips: u16,           // 2, bit per verification_votes index whose tip waits in the bond account
+    pub tip_share: u64,               // 8, tip each winner with evidence was owed at finalize
+}
+
+impl Bond {
+    pub const MAX_SIZE: usize = 
+        4 + 50 +    // bond_id
+        32 +        // principal
+        32 +        // agent
+        4 + 500 +   // task_description
+        8 +         // collateral_amount
+        8 +         // deadline
+        1 +         // status
+        8 +         // created_at
+        8 +         // completed_at
+        4 + (83 * Bond::MAX_VOTES) + // verification_votes
+        4 + (SlashVote::MAX_SIZE * Bond::MAX_VOTES) + // slash_votes
+        4 + (213 * Bond::MAX_PROOFS) + // proofs
+        1 +         // bump
+        8 +         // commit_window
+        8 +         // reveal_window
+        8 +         // commit_deadline
+        8 +         // reveal_deadline
+        4 + (65 * Bond::MAX_COMMITS) + // vote_commits
+        4 + (32 * Bond::MAX_SUBCOMMITTEE) + // subcommittee
+        8 +         // keeper_bounty
+        1 +         // vote_mode
+        1 + 1 +     // slash_reason
+        4 + (64 * Bond::MAX_PROXIES) + // proxies
+        8 + 8 + 8 + // reputation_snapshot
+        1 +         // vote_weighting
+        1 + 32 + 4 + CompletionCallback::MAX_DATA + // callback
+        1 +         // callback_approved
+        8 +         // stake_deadline
+        8 +         // reward_pool
+        2 +         // rewards_claimed
+        4 + (40 * Bond::MAX_TEAM) + // team
+        32 +        // team_lead
+        8 +         // verification_deadline
+        8 +         // score
+        8 +         // insured_amount
+        8 +         // insurance_due
+        8 +         // staked_at
+        8 +         // started_at
+        1 +         // require_begin_work
+        2 +         // arbiter_ruling
+        4 + (8 * Bond::MAX_SUBCOMMITTEE) + // assignment_deadlines
+        1 + 8 + 1 + 8 + // installments, interval, paid, funded_amount
+        8 +         // buffer_amount
+        8 + 8 +     // total_slashed, total_released
+        4 + Bond::MAX_JUSTIFICATION + // slash_justification
+        8 +         // tip_pool
+        1 +         // on_hold
+        1 +         // vault_bump
+        8 + 8 +     // appeal_window, slash_requested_at
+        1 +         // category
+        1 + 1 +     // deadline_warned, deadline_passed_noted
+        1 +         // slash_appealed
+        32 +        // escrow_authority
+        1 +         // extension_count
+        8 + 8 +     // agent_premium_paid, agent_compensation_due
+        4 + (Milestone::MAX_SIZE * Bond::MAX_MILESTONES) + // milestones
+        8 +         // milestones_released
+        4 + (40 * Bond::MAX_SYNDICATE) + // syndicate
+        1 +         // slash_destination
+        4 + Bond::MAX_RUBRIC + // rubric
+        8 +         // vault_rent
+        1 +         // slash_acknowledged
+        8 +         // required_votes
+        2 + 8 +     // stake_forfeit_bps, forfeits_collected
+        8 +         // principal_premium_paid
+        4 +         // open_stakes
+        8 + 32 +    // subcommittee_draw_slot, subcommittee_seed
+        32 + 4 + 4 + 8 + // tally_root, tally_approve, tally_total, tally_slot
+        8 +         // rewards_paid
+        8 +         // principal_contribution
+        2 + 8 +     // deferred_rewards, reward_share
+        2 + 8;      // deferred_tips, tip_share
+
+    /// bond_id is a PDA seed, and seeds are at most 32 bytes; this sits
+    /// inside the 50 bytes MAX_SIZE budgets for it
+    pub const MAX_ID_LEN: usize = 32;
+    pub const MAX_VOTES: usize = 10;
+    pub const MAX_COMMITS: usize = 10;
+    pub const MAX_PROOFS: usize = 3;
+    pub const MAX_SUBCOMMITTEE: usize = 10;
+    /// Slots between request_subcommittee and the slot seeding the draw
+    pub const SUBCOMMITTEE_DRAW_DELAY: u64 = 2;
+    /// Draws form_subcommittee tries before giving up on a thin pool
+    pub const MAX_DRAW_ATTEMPTS: usize = 4 * Bond::MAX_SUBCOMMITTEE;
+    pub const MAX_PROXIES: usize = 10;
+    pub const MAX_TEAM: usize = 5;
+    pub const MAX_JUSTIFICATION: usize = 200;
+    pub const MAX_EXTENSIONS: u8 = 3;
+    pub const MAX_MILESTONES: usize = 4;
+    pub const MAX_SYNDICATE: usize = 4;
+    /// Weight the creating principal counts for when it has funded less,
+    /// so a token co-principal cannot take the whole of a slash
+    pub const PRINCIPAL_BASE_WEIGHT: u64 = 1_000_000;
+    pub const MAX_RUBRIC: usize = 256;
+    /// Room for a milestone's approvals at the largest Config::min_votes
+    pub const MAX_MILESTONE_APPROVERS: usize = Bond::MAX_VOTES;
+    /// Seconds a covered bond stays open after a slash for the arbiter to
+    /// rule it wrongful
+    pub const WRONGFUL_SLASH_REVIEW: i64 = 7 * 86400;
+
+    /// Collateral is scored in steps of the 0.001 SOL minimum
+    pub const SCORE_COLLATERAL_UNIT: u64 = 1_000_000;
+
+    // check_invariants violation flags
+    pub const VIOLATION_VAULT_BALANCE: u32 = 1 << 0;   // vault doesn't hold what the status implies
+    pub const VIOLATION_VOTE_CAP: u32 = 1 << 1;        // votes, commits or proofs over their cap
+    pub const VIOLATION_DEADLINE_ORDER: u32 = 1 << 2;  // timestamps out of order
+    pub const VIOLATION_SLASH_REASON: u32 = 1 << 3;    // slash reason set iff slashed
+
+    /// Finalized one way or another; nothing left to move
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self.status,
+            BondStatus::Completed
+                | BondStatus::Slashed
+                | BondStatus::Expired
+                | BondStatus::Recovered
+        )
+    }
+
+    /// Side the bond was settled on when it was judged on the work:
+    /// approve for any completion, slash for a slash by majority vote,
+    /// oracle or arbiter. None when it timed out, tied, expired, was
+    /// recovered, or was slashed by the principal or a missed installment
+    pub fn vote_outcome(&self) -> Option<bool> {
+        match (self.status, self.slash_reason) {
+            (BondStatus::Completed, _) => Some(true),
+            (
+                BondStatus::Slashed,
+                Some(SlashReason::MajorityVote | SlashReason::Oracle | SlashReason::Arbiter),
+            ) => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Part of a losing vote's stake that goes to the winning side
+    pub fn stake_forfeit(&self, stake: u64) -> u64 {
+        (stake as u128 * self.stake_forfeit_bps as u128 / 10_000) as u64
+    }
+
+    /// A winning verifier has yet to claim their share of the reward pool
+    pub fn rewards_claimable(&self) -> bool {
+        self.reward_pool > self.rewards_paid
+            && self.winning_side().is_some_and(|side| {
+                self.verification_votes
+                    .iter()
+                    .enumerate()
+                    .any(|(i, v)| !v.excluded && v.approve == side && self.rewards_claimed & (1 << i) == 0)
+            })
+    }
+
+    /// Something is still owed out of or through this bond: verifier
+    /// stakes, verifier rewards or their refund, cover, or a covered slash the arbiter may
+    /// still rule on. Closing it would strand them
+    pub fn has_outstanding_claims(&self, now: i64) -> bool {
+        let review_open = self.status == BondStatus::Slashed
+            && self.agent_premium_paid > 0
+            && self.arbiter_ruling.is_none()
+            && now < self.completed_at.saturating_add(Self::WRONGFUL_SLASH_REVIEW);
+        self.open_stakes > 0
+            || self.reward_pool > self.rewards_paid
+            || self.insurance_due > 0
+            || self.agent_compensation_due > 0
+            || self.deferred_rewards != 0
+            || self.deferred_tips != 0
+            || review_open
+    }
+
+    /// (forfeits owed by the losing side, stake behind the winning side)
+    pub fn forfeit_split(&self) -> (u64, u64) {
+        let Some(side) = self.vote_outcome() else {
+            return (0, 0);
+        };
+        self.counted_votes().fold((0, 0), |(pool, winning), vote| {
+            if vote.approve == side {
+                (pool, winning + vote.stake)
+            } else {
+                (pool + self.stake_forfeit(vote.stake), winning)
+            }
+        })
+    }
+
+    /// Most recent proof; the one verifiers judge the final outcome on
+    pub fn latest_proof(&self) -> Option<&ProofEntry> {
+        self.proofs.last()
+    }
+
+    /// Blind bonds vote through commit-reveal only
+    pub fn commit_reveal_enabled(&self) -> bool {
+        self.vote_mode == VoteMode::Blind
+    }
+
+    /// Anyone may verify unless a sub-committee has been formed
+    pub fn is_eligible_verifier(&self, verifier: &Pubkey) -> bool {
+        self.subcommittee.is_empty() || self.subcommittee.contains(verifier)
+    }
+
+    /// Lamports the vault should hold in the current status: collateral,
+    /// buffer, keeper bounty and tips while live, nothing once settled.
+    /// None if the sum overflows.
+    pub fn expected_vault_balance(&self) -> Option<u64> {
+        match self.status {
+            BondStatus::Pending => self
+                .keeper_bounty
+                .checked_add(self.team_contributed())
+                .and_then(|v| v.checked_add(self.funded_amount))
+                .and_then(|v| v.checked_add(self.vault_rent)),
+            BondStatus::Active
+            | BondStatus::PendingVerification
+            | BondStatus::Disputed
+            | BondStatus::SlashRequested => self
+                .collateral_amount
+                .checked_sub(self.milestones_released)
+                .and_then(|v| v.checked_add(self.keeper_bounty))
+                .and_then(|v| v.checked_add(self.buffer_amount))
+                .and_then(|v| v.checked_add(self.tip_pool))
+                .and_then(|v| v.checked_add(self.vault_rent)),
+            BondStatus::Completed
+            | BondStatus::Slashed
+            | BondStatus::Expired
+            | BondStatus::Recovered => Some(self.vault_rent),
+        }
+    }
+
+    /// Vault lamports available to settle the bond, above the rent reserve
+    pub fn vault_funds(&self, vault_balance: u64) -> u64 {
+        vault_balance.saturating_sub(self.vault_rent)
+    }
+
+    /// Bitmask of violated invariants given the vault's current balance
+    pub fn invariant_violations(&self, vault_balance: u64) -> u32 {
+        let mut violations = 0;
+
+        if self.expected_vault_balance() != Some(vault_balance) {
+            violations |= Self::VIOLATION_VAULT_BALANCE;
+        }
+
+        if self.verification_votes.len() > Self::MAX_VOTES
+            || self.vote_commits.len() > Self::MAX_COMMITS
+            || self.proofs.len() > Self::MAX_PROOFS
+        {
+            violations |= Self::VIOLATION_VOTE_CAP;
+        }
+
+        if self.deadline <= self.created_at
+            || (self.is_terminal() && self.completed_at < self.created_at)
+            || self.commit_deadline > self.reveal_deadline
+        {
+            violations |= Self::VIOLATION_DEADLINE_ORDER;
+        }
+
+        if (self.status == BondStatus::Slashed) != self.slash_reason.is_some() {
+            violations |= Self::VIOLATION_SLASH_REASON;
+        }
+
+        violations
+    }
+
+    /// Collateral is posted over a schedule rather than in one stake
+    pub fn uses_installments(&self) -> bool {
+        self.installments > 1
+    }
+
+    /// Due date of the next unpaid installment; installment k is due
+    /// (k + 1) intervals after creation
+    pub fn next_installment_due(&self) -> i64 {
+        let periods = self.installments_paid as i64 + 1;
+        self.created_at
+            .saturating_add(self.installment_interval.saturating_mul(periods))
+    }
+
+    /// Equal installments with the rounding remainder on the last one
+    pub fn installment_amount(&self, index: u8) -> u64 {
+        let base = self.collateral_amount / self.installments as u64;
+        if index + 1 == self.installments {
+            self.collateral_amount - base * (self.installments as u64 - 1)
+        } else {
+            base
+        }
+    }
+
+    /// Weights the principals split slashes and refunded tips by: each
+    /// co-principal's contribution, then the creating principal's, floored
+    /// at PRINCIPAL_BASE_WEIGHT
+    pub fn principal_weights(&self) -> Vec<u64> {
+        let mut weights: Vec<u64> = self.syndicate.iter().map(|m| m.contribution).collect();
+        weights.push(self.principal_contribution.max(Self::PRINCIPAL_BASE_WEIGHT));
+        weights
+    }
+
+    /// Collateral paid in by team members other than the agent
+    pub fn team_contributed(&self) -> u64 {
+        self.team.iter().map(|m| m.contribution).sum()
+    }
+
+    /// Amounts a settlement moved, summarised by BondClosed
+    pub fn record_settlement(&mut self, slashed: u64, released: u64) {
+        self.total_slashed = slashed;
+        self.total_released = self.milestones_released + released;
+    }
+
+    /// (buffer, base) lamports a majority-vote slash takes out of `payout`
+    /// With partial slashing off the whole payout goes; otherwise a bps share
+    /// of the base collateral, drawn from the buffer before the base
+    pub fn partial_slash_split(&self, payout: u64, partial_slash_bps: u16) -> (u64, u64) {
+        let target = if partial_slash_bps == 0 {
+            payout
+        } else {
+            ((self.collateral_amount as u128 * partial_slash_bps as u128 / 10_000) as u64)
+                .min(payout)
+        };
+        let from_buffer = target.min(self.buffer_amount);
+        (from_buffer, target - from_buffer)
+    }
+
+    /// Deterministic ranking score: timeliness of the first proof and the
+    /// approval margin, each in bps, scaled by sqrt(collateral units)
+    /// Bonds that did not complete score 0
+    pub fn compute_score(&self) -> u64 {
+        if self.status != BondStatus::Completed {
+            return 0;
+        }
+
+        let delivered_at = self
+            .proofs
+            .first()
+            .map(|p| p.submitted_at)
+            .unwrap_or(self.completed_at);
+        let allotted = (self.deadline - self.created_at).max(1);
+        let spare = (self.deadline - delivered_at).clamp(0, allotted);
+        let timeliness_bps = (spare as u128 * 10_000 / allotted as u128) as u64;
+
+        let (approve, slash) = self.tally_votes();
+        let margin_bps = match approve + slash {
+            0 => 0,
+            total => approve.saturating_sub(slash) * 10_000 / total,
+        };
+
+        (timeliness_bps + margin_bps)
+            .saturating_mul(isqrt(self.collateral_amount / Self::SCORE_COLLATERAL_UNIT))
+    }
+
+    /// When finalize may time the bond out: the verification window opened by
+    /// proof if there is one, otherwise the task deadline plus the global grace
+    /// A deadline so close to i64::MAX that the grace overflows is an error
+    pub fn timeout_at(&self, timeout_grace: i64) -> Result<i64> {
+        if self.verification_deadline > 0 {
+            Ok(self.verification_deadline)
+        } else {
+            self.deadline
+                .checked_add(timeout_grace)
+                .ok_or_else(|| AgberoError::DeadlineOverflow.into())
+        }
+    }
+
+    /// Only the team lead submits proof for a team; otherwise the agent does
+    pub fn proof_submitter(&self) -> Pubkey {
+        if self.team_lead == Pubkey::default() {
+            self.agent
+        } else {
+            self.team_lead
+        }
+    }
+
+    /// Counted votes already meet finalize_bond's quorum with an approve
+    /// majority by head count, so the outcome is settled bar finalization
+    pub fn approve_quorum_reached(&self, config: &Config) -> bool {
+        let (approve, slash) = self.tally_votes();
+        let total = approve + slash;
+        self.quorum_met(config, total) && config.is_majority(approve as u128, total as u128)
+    }
+
+    /// Head count is at least the config minimum and this bond's own
+    /// collateral-scaled requirement, whichever is higher, but never more
+    /// than the sub-committee can cast
+    pub fn quorum_met(&self, config: &Config, total_votes: u64) -> bool {
+        let required = config.min_votes.max(self.required_votes);
+        total_votes >= required.min(self.eligible_verifier_count())
+    }
+
+    /// Verifiers who may vote: the sub-committee once formed, else as many
+    /// as the bond holds votes for
+    pub fn eligible_verifier_count(&self) -> u64 {
+        if self.subcommittee.is_empty() {
+            Bond::MAX_VOTES as u64
+        } else {
+            self.subcommittee.len() as u64
+        }
+    }
+
+    /// Where the vote stands for finalize_bond at `now`
+    /// Quorum counts heads; the configured majority is measured by vote weight
+    pub fn quorum_snapshot(&self, config: &Config, now: i64) -> Result<QuorumSnapshot> {
+        let (approve_votes, slash_votes) = self.tally_votes();
+        let total_votes = approve_votes + slash_votes;
+        let (approve_weight, slash_weight) = self.tally_weights();
+        let total_weight = approve_weight + slash_weight;
+
+        // Quorum: enough votes for the collateral, then the configured majority
+        let quorum_reached = self.quorum_met(config, total_votes) && total_weight > 0;
+        let majority_approve = config.is_majority(approve_weight, total_weight);
+        let majority_slash = config.is_majority(slash_weight, total_weight);
+
+        // The configured majority is strict, so at most one side holds it
+        let winning_outcome = match (quorum_reached, majority_approve, majority_slash) {
+            (true, true, _) => Some(true),
+            (true, _, true) => Some(false),
+            _ => None,
+        };
+        // An exact split at quorum can't be broken by the votes cast, so it
+        // settles at once (see finalize_bond); other no-majority tallies wait
+        let tied = quorum_reached && approve_weight == slash_weight;
+
+        let finalizable_status = self.status == BondStatus::PendingVerification
+            || self.status == BondStatus::Active && now > self.deadline;
+        let can_finalize = finalizable_status
+            && !self.on_hold
+            && (winning_outcome.is_some() || tied || now > self.timeout_at(config.timeout_grace)?);
+
+        Ok(QuorumSnapshot {
+            total_votes: total_votes as u32,
+            approve_votes: approve_votes as u32,
+            slash_votes: slash_votes as u32,
+            quorum_reached,
+            tied,
+            can_finalize,
+            winning_outcome,
+        })
+    }
+
+    /// Whether finalize_bond settling on `snapshot` at `now` would slash the
+    /// agent, which it refuses while the program is paused
+    pub fn finalize_slashes(&self, snapshot: &QuorumSnapshot, config: &Config, now: i64) -> Result<bool> {
+        Ok(match snapshot.winning_outcome {
+            Some(approve) => !approve,
+            None => {
+                !snapshot.tied
+                    && now > self.timeout_at(config.timeout_grace)?
+                    && !(self.proofs.is_empty() && config.no_proof_outcome == NoProofOutcome::Refund)
+            }
+        })
+    }
+
+    /// The escrow authority, if any, is the executor or signed among `accounts`
+    pub fn escrow_cosigned(&self, executor: &Pubkey, accounts: &[AccountInfo]) -> bool {
+        self.escrow_authority == Pubkey::default()
+            || self.escrow_authority == *executor
+            || accounts
+                .iter()
+                .any(|a| a.key() == self.escrow_authority && a.is_signer)
+    }
+
+    /// Vote that agreed with the final outcome, see vote_outcome
+    pub fn winning_side(&self) -> Option<bool> {
+        self.vote_outcome()
+    }
+
+    /// Votes that still count toward quorum (not invalidated by the arbiter)
+    pub fn counted_votes(&self) -> impl Iterator<Item = &VerificationVote> {
+        self.verification_votes.iter().filter(|v| !v.excluded)
+    }
+
+    /// (approve, slash) vote counts in a single pass
+    pub fn tally_votes(&self) -> (u64, u64) {
+        self.counted_votes()
+            .fold((0, 0), |(approve, slash), v| {
+                if v.approve {
+                    (approve + 1, slash)
+                } else {
+                    (approve, slash + 1)
+                }
+            })
+    }
+
+    /// (approve, slash) weight under the bond's weighting scheme, from the
+    /// stake each vote snapshotted when it was cast
+    pub fn tally_weights(&self) -> (u128, u128) {
+        self.counted_votes().fold((0, 0), |(approve, slash), vote| {
+            let weight = self.vote_weighting.weight(vote.stake);
+            if vote.approve {
+                (approve + weight, slash)
+            } else {
+                (approve, slash + weight)
+            }
+        })
+    }
+
+    pub fn has_voted(&self, verifier: &Pubkey) -> bool {
+        self.verification_votes.iter().any(|v| v.verifier == *verifier)
+    }
+
+    /// Proxy a member has designated, if any
+    pub fn proxy_of(&self, member: &Pubkey) -> Option<Pubkey> {
+        self.proxies.iter().find(|p| p.member == *member).map(|p| p.proxy)
+    }
+
+    /// Member a proxy is standing in for, if any
+    pub fn represented_member(&self, proxy: &Pubkey) -> Option<Pubkey> {
+        self.proxies.iter().find(|p| p.proxy == *proxy).map(|p| p.member)
+    }
+}
+
+/// Render base units as a decimal string with exactly `decimals` places
+pub fn format_token_amount(amount: u64, decimals: u8) -> String {
+    if decimals == 0 {
+        return amount.to_string();
+    }
+    let width = decimals as usize;
+    // Past 38 decimals no u64 amount reaches a whole unit
+    match 10u128.checked_pow(decimals as u32) {
+        Some(scale) => {
+            let amount = amount as u128;
+            format!("{}.{:0width$}", amount / scale, amount % scale)
+        }
+        None => format!("0.{:0width$}", amount),
+    }
+}
+
+/// Hash a verifier commits to: keccak(approve || salt || verifier)
+pub fn vote_commitment(approve: bool, salt: &[u8; 32], verifier: &Pubkey) -> [u8; 32] {
+    keccak::hashv(&[&[approve as u8], salt, verifier.as_ref()]).0
+}
+
+/// Global protocol counters, updated as bonds move through their lifecycle
+#[account]
+pub struct Stats {
+    pub total_bonds: u64,             // 8
+    pub active_bonds: u64,            // 8
+    pub total_value_locked: u64,      // 8
+    pub bonds_staked: u64,            // 8
+    pub total_collateral_staked: u64, // 8
+    pub bonds_completed: u64,         // 8
+    pub bonds_slashed: u64,           // 8
+    pub total_slashed: u64,           // 8
+    pub bump: u8,                     // 1
+    pub breaker_window_start: i64,    // 8
+    pub breaker_window_slashes: u64,  // 8
+    pub registered_verifiers: u64,    // 8
+}
+
+impl Stats {
+    pub const MAX_SIZE: usize = 8 * 8 + 1 + 8 + 8 + 8;
+
+    pub fn record_staked(&mut self, amount: u64) {
+        self.active_bonds += 1;
+        self.total_value_locked += amount;
+        self.bonds_staked += 1;
+        self.total_collateral_staked += amount;
+    }
+
+    pub fn record_released(&mut self, collateral: u64) {
+        self.active_bonds = self.active_bonds.saturating_sub(1);
+        self.total_value_locked = self.total_value_locked.saturating_sub(collateral);
+        self.bonds_completed += 1;
+    }
+
+    /// Bond closed with neither completion nor slash
+    pub fn record_expired(&mut self, collateral: u64) {
+        self.active_bonds = self.active_bonds.saturating_sub(1);
+        self.total_value_locked = self.total_value_locked.saturating_sub(collateral);
+    }
+
+    pub fn record_slashed(&mut self, collateral: u64, amount_slashed: u64) {
+        self.active_bonds = self.active_bonds.saturating_sub(1);
+        self.total_value_locked = self.total_value_locked.saturating_sub(collateral);
+        self.bonds_slashed += 1;
+        self.total_slashed += amount_slashed;
+    }
+
+    /// Slash of a bond that never went Active, so was never counted as locked
+    pub fn record_unstaked_slash(&mut self, amount_slashed: u64) {
+        self.bonds_slashed += 1;
+        self.total_slashed += amount_slashed;
+    }
+
+    /// Slashed share of finalized bonds, in basis points
+    pub fn slash_rate_bps(&self) -> u64 {
+        let finalized = self.bonds_completed + self.bonds_slashed;
+        if finalized == 0 {
+            return 0;
+        }
+        self.bonds_slashed * 10_000 / finalized
+    }
+
+    pub fn average_bond_size(&self) -> u64 {
+        if self.bonds_staked == 0 {
+            return 0;
+        }
+        self.total_collateral_staked / self.bonds_staked
+    }
+
+    /// Count a slash in the current breaker window
+    /// Returns true once the window's slash count reaches the threshold
+    pub fn record_breaker_slash(&mut self, now: i64, window: i64, max_slashes: u64) -> bool {
+        if now - self.breaker_window_start >= window {
+            self.breaker_window_start = now;
+            self.breaker_window_slashes = 0;
+        }
+        self.breaker_window_slashes += 1;
+        max_slashes > 0 && self.breaker_window_slashes >= max_slashes
+    }
+}
+
+/// Per-agent track record. Scores decay with age so recent outcomes
+/// dominate, and are capped so a long history cannot grow without bound.
+#[account]
+pub struct AgentReputation {
+    pub agent: Pubkey,                // 32
+    pub completed_score: u64,         // 8
+    pub slashed_score: u64,           // 8
+    pub last_updated: i64,            // 8
+    pub bump: u8,                     // 1
+    pub version: u8,                  // 1, layout version, see migrate_reputation
+    pub bonds_completed: u64,         // 8, raw count, never decays
+    pub bonds_slashed: u64,           // 8, raw count, never decays
+    pub total_collateral_cleared: u64, // 8, collateral of completed bonds
+}
+
+impl AgentReputation {
+    pub const MAX_SIZE: usize = 32 + 8 + 8 + 8 + 1 + 1 + 8 + 8 + 8;
+
+    /// Layout written by this program; v1 predates the version byte,
+    /// v2 predates the raw counters
+    pub const VERSION: u8 = 3;
+    pub const V1_SIZE: usize = 32 + 8 + 8 + 8 + 1;
+    pub const V2_SIZE: usize = Self::V1_SIZE + 1;
+
+    /// Score contributed by a single fresh outcome
+    pub const OUTCOME_WEIGHT: u64 = 1_000;
+    pub const MAX_SCORE: u64 = 100 * Self::OUTCOME_WEIGHT;
+
+    pub fn init_if_new(&mut self, agent: Pubkey, bump: u8) {
+        if self.agent == Pubkey::default() {
+            self.agent = agent;
+            self.bump = bump;
+            self.version = Self::VERSION;
+        }
+    }
+
+    /// Age both scores up to `now`; a zero half-life disables decay
+    pub fn decay(&mut self, now: i64, half_life: i64) {
+        let elapsed = now.saturating_sub(self.last_updated);
+        if half_life > 0 && elapsed > 0 {
+            self.completed_score = decayed(self.completed_score, elapsed, half_life);
+            self.slashed_score = decayed(self.slashed_score, elapsed, half_life);
+        }
+        self.last_updated = now;
+    }
+
+    pub fn record_completed(&mut self, now: i64, half_life: i64, collateral: u64) {
+        self.decay(now, half_life);
+        self.completed_score = (self.completed_score + Self::OUTCOME_WEIGHT).min(Self::MAX_SCORE);
+        self.bonds_completed += 1;
+        self.total_collateral_cleared = self.total_collateral_cleared.saturating_add(collateral);
+    }
+
+    pub fn record_slashed(&mut self, now: i64, half_life: i64) {
+        self.decay(now, half_life);
+        self.slashed_score = (self.slashed_score + Self::OUTCOME_WEIGHT).min(Self::MAX_SCORE);
+        self.bonds_slashed += 1;
+    }
+}
+
+/// Last bond between a principal and an agent, for Config::bond_cooldown
+#[account]
+pub struct BondPair {
+    pub principal: Pubkey,            // 32
+    pub agent: Pubkey,                // 32
+    pub last_bond_at: i64,            // 8
+    pub bump: u8,                     // 1
+}
+
+impl BondPair {
+    pub const MAX_SIZE: usize = 32 + 32 + 8 + 1;
+}
+
+/// A DAO governance outcome on a bond, cast as one vote by dao_verify
+#[account]
+pub struct DaoDecision {
+    pub governance: Pubkey,           // 32
+    pub bond: Pubkey,                 // 32
+    pub approve: bool,                // 1
+    pub resolved_at: i64,             // 8
+    pub recorded: bool,               // 1
+    pub bump: u8,                     // 1
+    pub task_hash: [u8; 32],          // 32, keccak(task_description) the decision was made on
+}
+
+impl DaoDecision {
+    pub const MAX_SIZE: usize = 32 + 32 + 1 + 8 + 1 + 1 + 32;
+}
+
+/// Evidence URI a verifier attached to their vote, see attach_evidence
+#[account]
+pub struct Evidence {
+    pub bond: Pubkey,                 // 32
+    pub verifier: Pubkey,             // 32
+    pub uri: String,                  // 4 + MAX_URI
+    pub attached_at: i64,             // 8
+    pub bump: u8,                     // 1
+}
+
+impl Evidence {
+    pub const MAX_URI: usize = 200;
+    pub const MAX_SIZE: usize = 32 + 32 + 4 + Self::MAX_URI + 8 + 1;
+}
+
+/// Short-lived claim on a bond_id, see reserve_bond_id
+#[account]
+pub struct BondReservation {
+    pub reserver: Pubkey,             // 32
+    pub reserved_at: i64,             // 8
+    pub bump: u8,                     // 1
+}
+
+impl BondReservation {
+    pub const MAX_SIZE: usize = 32 + 8 + 1;
+    /// How long only the reserver may create the bond
+    pub const WINDOW: i64 = 600;
+
+    pub fn is_lapsed(&self, now: i64) -> bool {
+        now >= self.reserved_at.saturating_add(Self::WINDOW)
+    }
+
+    /// Reject a create_bond by anyone but the reserver while the claim is
+    /// live, then close the reservation. Its rent goes to the bond's
+    /// principal: the reserver, or whoever outlasted a lapsed claim
+    pub fn redeem<'info>(
+        account: &AccountInfo<'info>,
+        principal: &AccountInfo<'info>,
+        now: i64,
+    ) -> Result<()> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(());
+        }
+        let reservation = BondReservation::try_deserialize(&mut &account.data.borrow()[..])?;
+        require!(
+            reservation.reserver == principal.key() || reservation.is_lapsed(now),
+            AgberoError::BondIdReserved
+        );
+
+        let rent = account.lamports();
+        **account.try_borrow_mut_lamports()? -= rent;
+        **principal.try_borrow_mut_lamports()? += rent;
+        account.realloc(0, false)?;
+        account.assign(&system_program::ID);
+        Ok(())
+    }
+}
+
+/// Co-principal on a syndicated bond and the tips it has funded
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SyndicateMember {
+    pub member: Pubkey,               // 32
+    pub contribution: u64,            // 8, lamports paid in through join_syndicate
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TeamMember {
+    pub member: Pubkey,               // 32
+    pub contribution: u64,            // 8
+}
+
+/// Team member's wallet from `remaining_accounts`, matched by key
+fn find_team_account<'a, 'info>(
+    member: &Pubkey,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<&'a AccountInfo<'info>> {
+    remaining_accounts
+        .iter()
+        .find(|a| a.key() == *member)
+        .ok_or_else(|| AgberoError::TeamMemberMissing.into())
+}
+
+/// Empty a Pending bond's vault: team contributions go back to their
+/// members and the rest to the principal, whose share is returned
+fn refund_pending_vault<'info>(
+    system_program: &Program<'info, System>,
+    bond_vault: &SystemAccount<'info>,
+    principal: &AccountInfo<'info>,
+    bond: &Account<'info, Bond>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<u64> {
+    for member in &bond.team {
+        let account = find_team_account(&member.member, remaining_accounts)?;
+        transfer_from_vault(
+            system_program,
+            bond_vault,
+            account,
+            &bond.key(),
+            bond.vault_bump,
+            member.contribution,
+        )?;
+    }
+
+    let vault_balance = bond_vault.lamports();
+    if vault_balance > 0 {
+        transfer_from_vault(
+            system_program,
+            bond_vault,
+            principal,
+            &bond.key(),
+            bond.vault_bump,
+            vault_balance,
+        )?;
+    }
+    Ok(vault_balance)
+}
+
+/// Release `payout` to the agent and team members pro rata to what each
+/// staked, rounding by largest remainder
+fn release_to_team<'info>(
+    system_program: &Program<'info, System>,
+    bond_vault: &SystemAccount<'info>,
+    agent: &AccountInfo<'info>,
+    bond: &Account<'info, Bond>,
+    payout: u64,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    // Buffer top-ups are the agent's alone; team shares come out of the base
+    let base_payout = payout.saturating_sub(bond.buffer_amount);
+    let mut weights: Vec<u64> = bond.team.iter().map(|m| m.contribution).collect();
+    weights.push(bond.collateral_amount - bond.team_contributed());
+    let shares = largest_remainder_split(base_payout, &weights);
+
+    let mut released = 0u64;
+    for (member, &share) in bond.team.iter().zip(&shares) {
+        if share == 0 {
+            continue;
+        }
+        let account = find_team_account(&member.member, remaining_accounts)?;
+        transfer_from_vault(system_program, bond_vault, account, &bond.key(), bond.vault_bump, share)?;
+        released += share;
+    }
+    transfer_from_vault(
+        system_program,
+        bond_vault,
+        agent,
+        &bond.key(),
+        bond.vault_bump,
+        payout - released,
+    )
+}
+
+/// Split `tips` evenly among verifiers who voted on `winning_side` and
+/// attached evidence; unclaimed lamports and division dust go back to the
+/// principal. Tipped verifiers are passed in remaining_accounts.
+fn settle_tips<'info>(
+    system_program: &Program<'info, System>,
+    bond_vault: &SystemAccount<'info>,
+    principal: &AccountInfo<'info>,
+    bond: &mut Account<'info, Bond>,
+    tips: u64,
+    winning_side: Option<bool>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    if tips == 0 {
+        return Ok(());
+    }
+    let winners: Vec<(usize, Pubkey)> = match winning_side {
+        Some(side) => bond
+            .verification_votes
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| !v.excluded && v.approve == side && v.evidence)
+            .map(|(i, v)| (i, v.verifier))
+            .collect(),
+        None => vec![],
+    };
+
+    let mut paid = 0u64;
+    if !winners.is_empty() {
+        let tip = tips / winners.len() as u64;
+        let bond_info = bond.to_account_info();
+        // A winner whose wallet was not passed has the tip parked in the
+        // bond account for claim_deferred_reward
+        for (index, verifier) in &winners {
+            let account = remaining_accounts.iter().find(|a| a.key() == *verifier);
+            let recipient = account.unwrap_or(&bond_info);
+            transfer_from_vault(system_program, bond_vault, recipient, &bond.key(), bond.vault_bump, tip)?;
+            if account.is_none() {
+                bond.deferred_tips |= 1 << index;
+            }
+            paid += tip;
+            emit!(VerifierTipped {
+                bond_id: bond.bond_id.clone(),
+                verifier: *verifier,
+                amount: tip,
+                deferred: account.is_none(),
+            });
+        }
+        bond.tip_share = tip;
+    }
+    // Unearned tips go back to whoever funded them
+    pay_principals(system_program, bond_vault, principal, bond, tips - paid, remaining_accounts)
+}
+
+/// Settle a bond to a verdict reached outside on-chain vote accumulation
+/// (oracle_settle, finalize_from_tally). No keeper finalized and no vote
+/// decided on-chain, so the bounty and any tips go back to the principal.
+fn settle_outcome<'info>(
+    ctx: Context<'_, '_, '_, 'info, OracleSettle<'info>>,
+    outcome: SettlementOutcome,
+) -> Result<()> {
+    let bond = &mut ctx.accounts.bond;
+    require!(!bond.on_hold, AgberoError::BondOnHold);
+    require!(
+        bond.escrow_cosigned(&ctx.accounts.oracle.key(), ctx.remaining_accounts),
+        AgberoError::EscrowSignatureMissing
+    );
+    let vault_balance = bond.vault_funds(ctx.accounts.bond_vault.lamports());
+    let now = Clock::get()?.unix_timestamp;
+    let half_life = ctx.accounts.config.reputation_half_life;
+
+    require!(
+        bond.status == BondStatus::Active || bond.status == BondStatus::PendingVerification,
+        AgberoError::InvalidBondStatus
+    );
+    ctx.accounts.reputation.init_if_new(bond.agent, ctx.bumps.reputation);
+
+    let keeper_bounty = bond.keeper_bounty.min(vault_balance);
+    let tips = bond.tip_pool.min(vault_balance - keeper_bounty);
+    let payout = vault_balance - keeper_bounty - tips;
+    bond.completed_at = now;
+
+    match outcome {
+        SettlementOutcome::Complete => {
+            bond.status = BondStatus::Completed;
+
+            release_to_team(
+                &ctx.accounts.system_program,
+                &ctx.accounts.bond_vault,
+                &ctx.accounts.agent.to_account_info(),
+                bond,
+                payout,
+                ctx.remaining_accounts,
+            )?;
+            ctx.accounts.stats.record_released(bond.collateral_amount);
+            ctx.accounts.reputation.record_completed(now, half_life, bond.collateral_amount);
+
+            bond.record_settlement(0, payout);
+            emit!(BondCompleted {
+                bond_id: bond.bond_id.clone(),
+                agent: bond.agent,
+                stake_released: payout,
+                final_proof_uri: bond
+                    .latest_proof()
+                    .map(|p| p.uri.clone())
+                    .unwrap_or_default(),
+            });
+        }
+        SettlementOutcome::Slash => {
+            require!(!ctx.accounts.config.paused, AgberoError::ProgramPaused);
+            bond.status = BondStatus::Slashed;
+            let reason = SlashReason::Oracle;
+            bond.slash_reason = Some(reason);
+
+            pay_slash(
+                &ctx.accounts.system_program,
+                &ctx.accounts.bond_vault,
+                &ctx.accounts.principal.to_account_info(),
+                bond,
+                &ctx.accounts.config,
+                payout,
+                ctx.remaining_accounts,
+            )?;
+            ctx.accounts.stats.record_slashed(bond.collateral_amount, payout);
+            ctx.accounts.reputation.record_slashed(now, half_life);
+            track_slash_volume(&mut ctx.accounts.config, &mut ctx.accounts.stats)?;
+
+            bond.record_settlement(payout, 0);
+            emit!(BondSlashed {
+                bond_id: bond.bond_id.clone(),
+                agent: bond.agent,
+                principal: bond.principal,
+                amount_slashed: payout,
+                reason,
+                justification: String::new(),
+            });
+        }
+    }
+
+    if keeper_bounty > 0 {
+        transfer_from_vault(
+            &ctx.accounts.system_program,
+            &ctx.accounts.bond_vault,
+            &ctx.accounts.principal.to_account_info(),
+            &bond.key(),
+            bond.vault_bump,
+            keeper_bounty,
+        )?;
+    }
+    pay_principals(
+        &ctx.accounts.system_program,
+        &ctx.accounts.bond_vault,
+        &ctx.accounts.principal.to_account_info(),
+        bond,
+        tips,
+        ctx.remaining_accounts,
+    )
+}
+
+/// One sampled leaf of an off-chain vote tally and its Merkle path
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TallyProof {
+    pub index: u32,
+    pub verifier: Pubkey,
+    pub approve: bool,
+    pub path: Vec<[u8; 32]>,
+}
+
+impl TallyProof {
+    /// Leaves finalize_from_tally must see proven
+    pub const SAMPLES: u32 = 3;
+
+    /// Leaf positions to prove, drawn from `seed` over `total` leaves
+    pub fn sample(seed: &[u8; 32], total: u32) -> Vec<u32> {
+        let mut seed = *seed;
+        (0..Self::SAMPLES.min(total))
+            .map(|_| {
+                let mut bytes = [0u8; 4];
+                bytes.copy_from_slice(&seed[..4]);
+                seed = keccak::hash(&seed).0;
+                u32::from_le_bytes(bytes) % total
+            })
+            .collect()
+    }
+
+    /// Fold the leaf up its path with sorted-pair keccak and compare to root
+    pub fn verify(&self, bond: &Pubkey, root: &[u8; 32]) -> bool {
+        let mut node = keccak::hashv(&[
+            bond.as_ref(),
+            &self.index.to_le_bytes(),
+            self.verifier.as_ref(),
+            &[self.approve as u8],
+        ])
+        .0;
+        for sibling in &self.path {
+            node = if node <= *sibling {
+                keccak::hashv(&[&node, sibling]).0
+            } else {
+                keccak::hashv(&[sibling, &node]).0
+            };
+        }
+        node == *root
+    }
+}
+
+/// A single verifier's vote inside submit_vote_bundle
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BundledVote {
+    pub verifier: Pubkey,
+    pub approve: bool,
+}
+
+/// Bytes a verifier signs to cast a bundled vote on a bond
+/// task_hash = keccak(task_description) at the time of signing
+pub fn vote_bundle_message(bond: &Pubkey, task_hash: &[u8; 32], approve: bool) -> Vec<u8> {
+    let mut message = b"agbero:vote".to_vec();
+    message.extend_from_slice(bond.as_ref());
+    message.extend_from_slice(task_hash);
+    message.push(approve as u8);
+    message
+}
+
+/// (signer, message) pairs proven by Ed25519 program instructions that
+/// precede the current one; only self-contained signatures (all offsets
+/// pointing into the same instruction) are accepted
+fn ed25519_signed_messages(instructions: &AccountInfo) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+    const OFFSETS_START: usize = 2;
+    const OFFSETS_SIZE: usize = 14;
+    let read_u16 = |data: &[u8], at: usize| -> Result<usize> {
+        data.get(at..at + 2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]) as usize)
+            .ok_or_else(|| AgberoError::InvalidVoteBundle.into())
+    };
+
+    let current = load_current_index_checked(instructions)? as usize;
+    let mut signed = vec![];
+    for index in 0..current {
+        let ix = load_instruction_at_checked(index, instructions)?;
+        if ix.program_id != ed25519_program::ID {
+            continue;
+        }
+        let data = &ix.data;
+        let count = *data.first().ok_or(AgberoError::InvalidVoteBundle)? as usize;
+        for n in 0..count {
+            let at = OFFSETS_START + n * OFFSETS_SIZE;
+            let pubkey_offset = read_u16(data, at + 4)?;
+            let message_offset = read_u16(data, at + 8)?;
+            let message_size = read_u16(data, at + 10)?;
+            require!(
+                read_u16(data, at + 2)? == u16::MAX as usize
+                    && read_u16(data, at + 6)? == u16::MAX as usize
+                    && read_u16(data, at + 12)? == u16::MAX as usize,
+                AgberoError::InvalidVoteBundle
+            );
+            let pubkey = data
+                .get(pubkey_offset..pubkey_offset + 32)
+                .ok_or(AgberoError::InvalidVoteBundle)?;
+            let message = data
+                .get(message_offset..message_offset + message_size)
+                .ok_or(AgberoError::InvalidVoteBundle)?;
+            let pubkey =
+                Pubkey::try_from(pubkey).map_err(|_| AgberoError::InvalidVoteBundle)?;
+            signed.push((pubkey, message.to_vec()));
+        }
+    }
+    Ok(signed)
+}
+
+/// Split `amount` in proportion to `weights` by the largest-remainder method:
+/// floor shares first, then one lamport each to the largest remainders
+/// (earlier entries win ties), so the shares always sum to `amount`
+fn largest_remainder_split(amount: u64, weights: &[u64]) -> Vec<u64> {
+    let total: u128 = weights.iter().map(|&w| w as u128).sum();
+    if total == 0 {
+        return vec![0; weights.len()];
+    }
+
+    let mut shares = Vec::with_capacity(weights.len());
+    let mut remainders = Vec::with_capacity(weights.len());
+    for (i, &weight) in weights.iter().enumerate() {
+        let scaled = amount as u128 * weight as u128;
+        shares.push((scaled / total) as u64);
+        remainders.push((scaled % total, i));
+    }
+
+    let leftover = amount - shares.iter().sum::<u64>();
+    remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    for &(_, i) in remainders.iter().take(leftover as usize) {
+        shares[i] += 1;
+    }
+    shares
+}
+
+/// Program the principal wants invoked when a bond completes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CompletionCallback {
+    pub program_id: Pubkey,
+    pub data: Vec<u8>,                // instruction data, max 64 bytes
+}
+
+impl CompletionCallback {
+    pub const MAX_DATA: usize = 64;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.data.len() <= Self::MAX_DATA
+                && self.program_id != crate::ID
+                && self.program_id != system_program::ID,
+            AgberoError::InvalidCallback
+        );
+        Ok(())
+    }
+}
+
+/// CPI into a completion callback. Accounts that follow the callback program in
+/// `remaining_accounts` are forwarded to it, never with signer privileges.
+fn invoke_callback<'info>(
+    callback: &CompletionCallback,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let position = remaining_accounts
+        .iter()
+        .position(|a| a.key() == callback.program_id)
+        .ok_or(AgberoError::CallbackProgramMissing)?;
+    let forwarded = &remaining_accounts[position + 1..];
+
+    let instruction = Instruction {
+        program_id: callback.program_id,
+        accounts: forwarded
+            .iter()
+            .map(|a| {
+                if a.is_writable {
+                    AccountMeta::new(a.key(), false)
+                } else {
+                    AccountMeta::new_readonly(a.key(), false)
+                }
+            })
+            .collect(),
+        data: callback.data.clone(),
+    };
+    let mut account_infos = forwarded.to_vec();
+    account_infos.push(remaining_accounts[position].clone());
+    invoke(&instruction, &account_infos)?;
+    Ok(())
+}
+
+/// Lamports a verifier has locked behind their vote on one bond
+#[account]
+pub struct VerifierStake {
+    pub bond: Pubkey,                 // 32
+    pub verifier: Pubkey,             // 32
+    pub amount: u64,                  // 8
+    pub bump: u8,                     // 1
+}
+
+impl VerifierStake {
+    pub const MAX_SIZE: usize = 32 + 32 + 8 + 1;
+
+    /// Staked amount for `verifier` on `bond_key`. The stake address must
+    /// be among `accounts` even when unused, so nobody can have a vote
+    /// counted as unstaked by leaving it out; never initialised = 0
+    pub fn amount_for(bond_key: &Pubkey, verifier: &Pubkey, accounts: &[AccountInfo]) -> Result<u64> {
+        let (address, _) = Pubkey::find_program_address(
+            &[b"verifier_stake", bond_key.as_ref(), verifier.as_ref()],
+            &crate::ID,
+        );
+        let account = accounts
+            .iter()
+            .find(|a| a.key() == address)
+            .ok_or(AgberoError::VerifierStakeMissing)?;
+        if account.owner != &crate::ID {
+            return Ok(0);
+        }
+        let stake = VerifierStake::try_deserialize(&mut &account.data.borrow()[..])?;
+        Ok(stake.amount)
+    }
+}
+
+/// Registered verifier's long-lived record across bonds
+#[account]
+pub struct VerifierProfile {
+    pub verifier: Pubkey,             // 32
+    pub registered_at: i64,           // 8
+    pub penalties: u32,               // 4, votes against an arbiter ruling
+    pub bump: u8,                     // 1
+    pub history_hash: [u8; 32],       // 32, running hash over every recorded vote
+    pub votes_recorded: u32,          // 4
+    pub categories: Vec<u8>,          // 4 + 8, task categories the verifier opted into
+    pub index: u64,                   // 8, registration order, what sub-committee draws pick by
+}
+
+impl VerifierProfile {
+    pub const MAX_SIZE: usize = 32 + 8 + 4 + 1 + 32 + 4 + 4 + Self::MAX_CATEGORIES + 8;
+
+    pub const MAX_CATEGORIES: usize = 8;
+
+    /// history_hash = keccak(history_hash || bond || approve)
+    pub fn record_vote(&mut self, bond: &Pubkey, approve: bool) {
+        self.history_hash =
+            keccak::hashv(&[&self.history_hash, bond.as_ref(), &[approve as u8]]).0;
+        self.votes_recorded = self.votes_recorded.saturating_add(1);
+    }
+
+    /// Every genuine profile among `accounts`; anything else is skipped
+    pub fn from_accounts(accounts: &[AccountInfo]) -> Vec<VerifierProfile> {
+        accounts
+            .iter()
+            .filter(|a| a.owner == &crate::ID)
+            .filter_map(|a| VerifierProfile::try_deserialize(&mut &a.data.borrow()[..]).ok())
+            .collect()
+    }
+
+    /// Requires `voter` to have opted into the bond's category, shown by
+    /// their profile among `accounts`; uncategorised bonds need no profile
+    pub fn require_category(bond: &Bond, voter: &Pubkey, accounts: &[AccountInfo]) -> Result<()> {
+        if bond.category == 0 {
+            return Ok(());
+        }
+        let (address, _) = Pubkey::find_program_address(&[b"verifier", voter.as_ref()], &crate::ID);
+        let opted_in = accounts
+            .iter()
+            .find(|a| a.key() == address && a.owner == &crate::ID)
+            .and_then(|a| VerifierProfile::try_deserialize(&mut &a.data.borrow()[..]).ok())
+            .is_some_and(|p| p.categories.contains(&bond.category));
+        require!(opted_in, AgberoError::CategoryNotOptedIn);
+        Ok(())
+    }
+
+    /// Folds a vote into `voter`'s profile, which must be among `accounts`
+    /// (writable) and opted into the bond's category
+    pub fn record_for(
+        bond: &Account<Bond>,
+        voter: &Pubkey,
+        approve: bool,
+        accounts: &[AccountInfo],
+    ) -> Result<()> {
+        let (address, _) = Pubkey::find_program_address(&[b"verifier", voter.as_ref()], &crate::ID);
+        let account = accounts
+            .iter()
+            .find(|a| a.key() == address && a.owner == &crate::ID)
+            .ok_or(AgberoError::VerifierProfileMissing)?;
+        let mut profile = VerifierProfile::try_deserialize(&mut &account.data.borrow()[..])?;
+        require!(
+            bond.category == 0 || profile.categories.contains(&bond.category),
+            AgberoError::CategoryNotOptedIn
+        );
+        profile.record_vote(&bond.key(), approve);
+        profile.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+        Ok(())
+    }
+}
+
+/// Next verifier in a draw: a uniform pick among all `registered` profile
+/// indices, advancing `seed`. The picked profile must be among `profiles`
+fn draw_verifier(seed: &mut [u8; 32], registered: u64, profiles: &[VerifierProfile]) -> Result<Pubkey> {
+    require!(registered > 0, AgberoError::InvalidSubcommittee);
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&seed[..8]);
+    let index = u64::from_le_bytes(bytes) % registered;
+    *seed = keccak::hash(seed.as_ref()).0;
+    profiles
+        .iter()
+        .find(|p| p.index == index)
+        .map(|p| p.verifier)
+        .ok_or(AgberoError::CandidateMissing.into())
+}
+
+/// Hash of the first slot at or after `slot` in the SlotHashes sysvar,
+/// whose entries run newest first
+fn slot_hash_from(slot_hashes: &AccountInfo, slot: u64) -> Result<[u8; 32]> {
+    let data = slot_hashes.try_borrow_data()?;
+    let entry = |i: usize| -> Option<(u64, [u8; 32])> {
+        let at = 8 + i * 40;
+        let slot = u64::from_le_bytes(data.get(at..at + 8)?.try_into().ok()?);
+        let hash = data.get(at + 8..at + 40)?.try_into().ok()?;
+        Some((slot, hash))
+    };
+    let len = u64::from_le_bytes(
+        data.get(..8)
+            .and_then(|b| b.try_into().ok())
+            .ok_or(AgberoError::DrawNotReady)?,
+    ) as usize;
+
+    let mut found = None;
+    for i in 0..len {
+        let (entry_slot, hash) = entry(i).ok_or(AgberoError::DrawNotReady)?;
+        if entry_slot < slot {
+            return found.ok_or(AgberoError::DrawNotReady.into());
+        }
+        found = Some(hash);
+    }
+    // Every entry is at or after `slot`: the one we want may have aged out
+    match found {
+        Some(hash) if len < MAX_SLOT_HASHES => Ok(hash),
+        Some(_) => Err(AgberoError::DrawExpired.into()),
+        None => Err(AgberoError::DrawNotReady.into()),
+    }
+}
+
+/// Forfeit the stake of every counted vote that disagreed with the
+/// arbiter's ruling and bump the voter's profile penalties
+/// Stake and profile accounts are optional, matched by address
+fn penalize_wrong_votes<'info>(
+    bond: &Account<'info, Bond>,
+    ruling: bool,
+    principal: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let bond_key = bond.key();
+    let find = |seeds: &[&[u8]]| {
+        let (address, _) = Pubkey::find_program_address(seeds, &crate::ID);
+        accounts
+            .iter()
+            .find(|a| a.key() == address && a.owner == &crate::ID)
+    };
+
+    for vote in bond.counted_votes().filter(|v| v.approve != ruling) {
+        let mut stake_slashed = 0;
+        let stake_seeds: &[&[u8]] = &[b"verifier_stake", bond_key.as_ref(), vote.verifier.as_ref()];
+        if let Some(account) = find(stake_seeds) {
+            let mut stake = VerifierStake::try_deserialize(&mut &account.data.borrow()[..])?;
+            stake_slashed = stake.amount;
+            stake.amount = 0;
+            stake.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+            **account.try_borrow_mut_lamports()? -= stake_slashed;
+            **principal.try_borrow_mut_lamports()? += stake_slashed;
+        }
+        if let Some(account) = find(&[b"verifier", vote.verifier.as_ref()]) {
+            let mut profile = VerifierProfile::try_deserialize(&mut &account.data.borrow()[..])?;
+            profile.penalties = profile.penalties.saturating_add(1);
+            profile.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+        }
+
+        emit!(VerifierPenalized {
+            bond_id: bond.bond_id.clone(),
+            verifier: vote.verifier,
+            stake_slashed,
+        });
+    }
+    Ok(())
+}
+
+/// Agent's standing when a bond was created, kept for later disputes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ReputationSnapshot {
+    pub completed_score: u64,
+    pub slashed_score: u64,
+    pub taken_at: i64,
+}
+
+impl ReputationSnapshot {
+    /// Decayed scores as of `now`; an agent without a reputation account snapshots as zero
+    pub fn capture(account: &AccountInfo, now: i64, half_life: i64) -> Result<Self> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(Self {
+                taken_at: now,
+                ..Self::default()
+            });
+        }
+        let mut reputation = AgentReputation::try_deserialize(&mut &account.data.borrow()[..])?;
+        reputation.decay(now, half_life);
+        Ok(Self {
+            completed_score: reputation.completed_score,
+            slashed_score: reputation.slashed_score,
+            taken_at: now,
+        })
+    }
+}
+
+/// score * 2^(-elapsed / half_life): exact per whole half-life,
+/// linear in between
+fn decayed(score: u64, elapsed: i64, half_life: i64) -> u64 {
+    let halvings = elapsed / half_life;
+    if halvings >= 64 {
+        return 0;
+    }
+    let score = (score >> halvings) as u128;
+    let rem = (elapsed % half_life) as u128;
+    let span = 2 * half_life as u128;
+    (score * (span - rem) / span) as u64
+}
+
+/// Global program settings, owned by the config admin
+#[account]
+pub struct Config {
+    pub admin: Pubkey,                // 32
+    pub paused: bool,                 // 1
+    pub breaker_window: i64,          // 8
+    pub breaker_max_slashes: u64,     // 8
+    pub bump: u8,                     // 1
+    pub proof_grace: i64,             // 8
+    pub reputation_half_life: i64,    // 8
+    pub timeout_grace: i64,           // 8
+    pub no_proof_outcome: NoProofOutcome, // 1
+    pub oracle: Pubkey,               // 32
+    pub stake_window: i64,            // 8
+    pub arbiter: Pubkey,              // 32
+    pub reward_mint: Pubkey,          // 32
+    pub verification_duration: i64,   // 8
+    pub insurance_bps: u16,           // 2
+    pub bond_cooldown: i64,           // 8
+    pub assignment_window: i64,       // 8
+    pub max_verifiers: u64,           // 8
+    pub partial_slash_bps: u16,       // 2
+    pub legal_authority: Pubkey,      // 32
+    pub max_appeal_window: i64,       // 8
+    pub fee_bps: u16,                 // 2
+    pub treasury: Pubkey,             // 32
+    pub deadline_warning_window: i64, // 8
+    pub auto_close_on_finalize: bool, // 1
+    pub agent_premium_bps: u16,       // 2
+    pub verifier_reward_bps: u16,     // 2
+    pub deadline_base_duration: i64,  // 8
+    pub min_votes: u64,               // 8
+    pub majority_num: u64,            // 8
+    pub majority_den: u64,            // 8
+    pub quorum_step: u64,             // 8
+    pub stake_forfeit_bps: u16,       // 2
+    pub principal_premium_bps: u16,   // 2
+    pub min_appeal_window: i64,       // 8
+    pub subcommittee_size: u64,       // 8
+    pub agent_cover_multiple: u16,    // 2
+    pub governance_program: Pubkey,   // 32
+}
+
+impl Config {
+    pub const MAX_SIZE: usize = 32 + 1 + 8 + 8 + 1 + 8 + 8 + 8 + 1 + 32 + 8 + 32 + 32 + 8 + 2 + 8 + 8 + 8 + 2 + 32 + 8 + 2 + 32 + 8 + 1 + 2 + 2 + 8 + 8 + 8 + 8 + 8 + 2 + 2 + 8 + 8 + 2 + 32;
+
+    pub const MAX_PROOF_GRACE: i64 = 7 * 86400;
+    /// suggest_deadline's base duration when deadline_base_duration is unset
+    pub const DEFAULT_DEADLINE_BASE: i64 = 86400;
+    pub const MAX_COMPLEXITY_TIER: u8 = 4;
+    /// Description bytes per extra base duration in suggest_deadline
+    pub const DEADLINE_LENGTH_UNIT: u32 = 100;
+    /// Keeps majority products within u128 for any vote weight
+    pub const MAX_MAJORITY_DEN: u64 = 10_000;
+
+    /// Head count finalize_bond needs before either majority counts
+    pub fn quorum_met(&self, total_votes: u64) -> bool {
+        total_votes >= self.min_votes
+    }
+
+    /// Votes a bond with `at_risk` lamports of collateral needs: min_votes
+    /// plus one per quorum_step, capped at what a bond can hold
+    pub fn required_votes(&self, at_risk: u64) -> u64 {
+        let extra = at_risk.checked_div(self.quorum_step).unwrap_or(0);
+        self.min_votes
+            .saturating_add(extra)
+            .min(Bond::MAX_VOTES as u64)
+    }
+
+    /// `part` is at least majority_num/majority_den of `total`
+    pub fn is_majority(&self, part: u128, total: u128) -> bool {
+        part * self.majority_den as u128 >= total * self.majority_num as u128
+    }
+
+    pub fn apply(&mut self, params: ConfigParams) -> Result<()> {
+        require!(params.breaker_window > 0, AgberoError::InvalidConfig);
+        require!(
+            (0..=Self::MAX_PROOF_GRACE).contains(&params.proof_grace),
+            AgberoError::InvalidConfig
+        );
+        require!(params.reputation_half_life >= 0, AgberoError::InvalidConfig);
+        // A proof accepted late must not already be past the timeout
+        require!(params.timeout_grace >= params.proof_grace, AgberoError::InvalidConfig);
+        require!(params.stake_window >= 0, AgberoError::InvalidConfig);
+        require!(params.verification_duration >= 0, AgberoError::InvalidConfig);
+        require!(params.insurance_bps <= 10_000, AgberoError::InvalidConfig);
+        require!(params.bond_cooldown >= 0, AgberoError::InvalidConfig);
+        require!(params.assignment_window >= 0, AgberoError::InvalidConfig);
+        require!(params.partial_slash_bps <= 10_000, AgberoError::InvalidConfig);
+        require!(params.max_appeal_window >= 0, AgberoError::InvalidConfig);
+        // Every emergency slash gets an appeal window; none are instant
+        require!(
+            params.min_appeal_window > 0 && params.min_appeal_window <= params.max_appeal_window,
+            AgberoError::InvalidConfig
+        );
+        require!(params.deadline_warning_window >= 0, AgberoError::InvalidConfig);
+        require!(params.agent_premium_bps <= 10_000, AgberoError::InvalidConfig);
+        // Cover on offer must pay something when a ruling goes the agent's way
+        require!(
+            params.agent_premium_bps == 0 || params.agent_cover_multiple > 0,
+            AgberoError::InvalidConfig
+        );
+        require!(params.verifier_reward_bps <= 10_000, AgberoError::InvalidConfig);
+        require!(params.stake_forfeit_bps <= 10_000, AgberoError::InvalidConfig);
+        require!(params.principal_premium_bps <= 10_000, AgberoError::InvalidConfig);
+        require!(
+            params.subcommittee_size <= Bond::MAX_SUBCOMMITTEE as u64,
+            AgberoError::InvalidConfig
+        );
+        require!(params.deadline_base_duration >= 0, AgberoError::InvalidConfig);
+        require!(
+            (1..=Bond::MAX_VOTES as u64).contains(&params.min_votes),
+            AgberoError::InvalidConfig
+        );
+        // A strict majority, or both sides could hold it at once
+        require!(
+            params.majority_den > 0
+                && params.majority_den <= Self::MAX_MAJORITY_DEN
+                && params.majority_num <= params.majority_den
+                && params.majority_num * 2 > params.majority_den,
+            AgberoError::InvalidConfig
+        );
+        // Insurance premium and protocol fee both come out of the same slash
+        require!(
+            params.insurance_bps as u32 + params.fee_bps as u32 <= 10_000,
+            AgberoError::InvalidConfig
+        );
+        require!(
+            params.fee_bps == 0 || params.treasury != Pubkey::default(),
+            AgberoError::InvalidConfig
+        );
+        // Reward split dust is swept to the treasury
+        require!(
+            params.verifier_reward_bps == 0 || params.treasury != Pubkey::default(),
+            AgberoError::InvalidConfig
+        );
+
+        self.breaker_window = params.breaker_window;
+        self.breaker_max_slashes = params.breaker_max_slashes;
+        self.proof_grace = params.proof_grace;
+        self.reputation_half_life = params.reputation_half_life;
+        self.timeout_grace = params.timeout_grace;
+        self.no_proof_outcome = params.no_proof_outcome;
+        self.oracle = params.oracle;
+        self.stake_window = params.stake_window;
+        self.arbiter = params.arbiter;
+        self.reward_mint = params.reward_mint;
+        self.verification_duration = params.verification_duration;
+        self.insurance_bps = params.insurance_bps;
+        self.bond_cooldown = params.bond_cooldown;
+        self.assignment_window = params.assignment_window;
+        self.max_verifiers = params.max_verifiers;
+        self.partial_slash_bps = params.partial_slash_bps;
+        self.legal_authority = params.legal_authority;
+        self.max_appeal_window = params.max_appeal_window;
+        self.fee_bps = params.fee_bps;
+        self.treasury = params.treasury;
+        self.deadline_warning_window = params.deadline_warning_window;
+        self.auto_close_on_finalize = params.auto_close_on_finalize;
+        self.agent_premium_bps = params.agent_premium_bps;
+        self.verifier_reward_bps = params.verifier_reward_bps;
+        self.deadline_base_duration = params.deadline_base_duration;
+        self.min_votes = params.min_votes;
+        self.majority_num = params.majority_num;
+        self.majority_den = params.majority_den;
+        self.quorum_step = params.quorum_step;
+        self.stake_forfeit_bps = params.stake_forfeit_bps;
+        self.principal_premium_bps = params.principal_premium_bps;
+        self.min_appeal_window = params.min_appeal_window;
+        self.subcommittee_size = params.subcommittee_size;
+        self.agent_cover_multiple = params.agent_cover_multiple;
+        self.governance_program = params.governance_program;
+        Ok(())
+    }
+}
+
+/// Admin-tunable protocol parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigParams {
+    pub breaker_window: i64,          // seconds per slash-rate window
+    pub breaker_max_slashes: u64,     // slashes per window that trip the breaker, 0 = off
+    pub proof_grace: i64,             // seconds past the deadline a late proof is accepted
+    pub reputation_half_life: i64,    // seconds for reputation scores to halve, 0 = no decay
+    pub timeout_grace: i64,           // seconds past the deadline before finalize may time a bond out
+    pub no_proof_outcome: NoProofOutcome, // timed-out bonds that never received proof
+    pub oracle: Pubkey,               // authority allowed to oracle_settle, default = none
+    pub stake_window: i64,            // seconds after creation the agent has to stake, 0 = no expiry
+    pub arbiter: Pubkey,              // dispute authority, default = none
+    pub reward_mint: Pubkey,          // token verifier rewards are paid in, default = none
+    pub verification_duration: i64,   // seconds verifiers have after the first proof, 0 = until deadline + timeout_grace
+    pub insurance_bps: u16,           // share of finalize slashes routed to the insurance pool
+    pub bond_cooldown: i64,           // seconds before the same principal and agent may bond again, 0 = off
+    pub assignment_window: i64,       // seconds a sub-committee member has to vote before reassignment, 0 = off
+    pub max_verifiers: u64,           // cap on registered verifiers, 0 = unlimited
+    pub partial_slash_bps: u16,       // share of base collateral a majority-vote slash takes, 0 = all
+    pub legal_authority: Pubkey,      // may place legal holds on bonds, default = none
+    pub max_appeal_window: i64,       // longest per-bond emergency slash appeal window
+    pub fee_bps: u16,                 // protocol fee on slashed collateral, 0 = none
+    pub treasury: Pubkey,             // receives the protocol fee, required when fee_bps > 0
+    pub deadline_warning_window: i64, // seconds before the deadline poke_bond warns, 0 = off
+    pub auto_close_on_finalize: bool, // finalize_bond closes the bond; false keeps it for close_bond
+    pub agent_premium_bps: u16,       // agent's wrongful-slash cover premium, 0 = cover not offered
+    pub verifier_reward_bps: u16,     // share of a completed bond's payout split among approving verifiers, 0 = none
+    pub deadline_base_duration: i64,  // suggest_deadline's duration for a short tier-0 task, 0 = one day
+    pub min_votes: u64,               // counted votes finalize needs for quorum, 1..=Bond::MAX_VOTES
+    pub majority_num: u64,            // winning share is majority_num / majority_den, over half, e.g. 2 / 3
+    pub majority_den: u64,            // nonzero, at most Config::MAX_MAJORITY_DEN
+    pub quorum_step: u64,             // collateral lamports per extra required vote, 0 = flat min_votes
+    pub stake_forfeit_bps: u16,       // share of a losing verifier's stake paid to the winners, 0 = none
+    pub principal_premium_bps: u16,   // principal's no-proof cover premium on insured_amount, 0 = cover not offered
+    pub min_appeal_window: i64,       // shortest per-bond emergency slash appeal window, at least 1
+    pub subcommittee_size: u64,       // members form_subcommittee draws, 0 = sub-committees off
+    pub agent_cover_multiple: u16,    // wrongful-slash payout cap as a multiple of the agent's premium
+    pub governance_program: Pubkey,   // owner of accounts resolve_dao_decision accepts, default = DAO votes off
+}
+
+/// Every check create_bond applies to its arguments
+fn check_bond_params(
+    config: &Config,
+    task_description: &str,
+    collateral_amount: u64,
+    deadline: i64,
+    options: &BondOptions,
+) -> Result<()> {
+    require!(
+        task_description.len() <= 500,
+        AgberoError::DescriptionTooLong
+    );
+    require!(collateral_amount >= 1_000_000, AgberoError::CollateralTooLow); // 0.001 SOL min
+    require!(
+        (config.min_appeal_window..=config.max_appeal_window).contains(&options.appeal_window),
+        AgberoError::InvalidAppealWindow
+    );
+    require!(options.rubric.len() <= Bond::MAX_RUBRIC, AgberoError::RubricTooLong);
+    require!(
+        options.syndicate.len() <= Bond::MAX_SYNDICATE
+            && options
+                .syndicate
+                .iter()
+                .enumerate()
+                .all(|(i, member)| !options.syndicate[..i].contains(member)),
+        AgberoError::InvalidSyndicate
+    );
+    if !options.milestones.is_empty() {
+        let mut total = 0u64;
+        for milestone in &options.milestones {
+            require!(
+                milestone.amount > 0 && milestone.description.len() <= Milestone::MAX_DESCRIPTION,
+                AgberoError::InvalidMilestones
+            );
+            total = total
+                .checked_add(milestone.amount)
+                .ok_or(AgberoError::InvalidMilestones)?;
+        }
+        require!(
+            options.milestones.len() <= Bond::MAX_MILESTONES && total == collateral_amount,
+            AgberoError::InvalidMilestones
+        );
+    }
+    let now = Clock::get()?.unix_timestamp;
+    require!(deadline > now, AgberoError::InvalidDeadline);
+    options.validate()?;
+    // The last installment falls due before the deadline, or the bond
+    // could only go Active once it is already overdue
+    if options.installments > 1 {
+        let last_due = options
+            .installment_interval
+            .checked_mul(options.installments as i64)
+            .and_then(|span| now.checked_add(span));
+        require!(
+            last_due.is_some_and(|due| due < deadline),
+            AgberoError::InvalidInstallmentSchedule
+        );
+    }
+    require!(
+        options.insured_amount <= collateral_amount,
+        AgberoError::InvalidInsuredAmount
+    );
+    if let Some(callback) = &options.callback {
+        callback.validate()?;
+    }
+    Ok(())
+}
+
+/// Emergency slash payout for execute_emergency_slash:
+/// the whole vault goes to the principal and the agent takes the reputation hit
+fn settle_emergency_slash<'info>(
+    ctx: Context<'_, '_, '_, 'info, EmergencySlash<'info>>,
+) -> Result<()> {
+    let bond = &mut ctx.accounts.bond;
+    // Verifiers have already approved the work; only finalize may settle it
+    require!(
+        !bond.approve_quorum_reached(&ctx.accounts.config),
+        AgberoError::ApprovalQuorumReached
+    );
+    bond.status = BondStatus::Slashed;
+    bond.completed_at = Clock::get()?.unix_timestamp;
+    bond.slash_reason = Some(SlashReason::EmergencyPrincipal);
+
+    // No keeper involved: the unused bounty goes back to the principal too,
+    // and the tips to whoever funded them
+    let vault_balance = bond.vault_funds(ctx.accounts.bond_vault.lamports());
+    let tips = bond.tip_pool.min(vault_balance.saturating_sub(bond.keeper_bounty));
+    let amount_slashed = vault_balance
+        .saturating_sub(bond.keeper_bounty)
+        .saturating_sub(bond.tip_pool);
+    let fee = pay_protocol_fee(
+        &ctx.accounts.system_program,
+        &ctx.accounts.bond_vault,
+        bond,
+        &ctx.accounts.config,
+        amount_slashed,
+        ctx.remaining_accounts,
+    )?;
+    pay_slash(
+        &ctx.accounts.system_program,
+        &ctx.accounts.bond_vault,
+        &ctx.accounts.principal.to_account_info(),
+        bond,
+        &ctx.accounts.config,
+        amount_slashed - fee,
+        ctx.remaining_accounts,
+    )?;
+    transfer_from_vault(
+        &ctx.accounts.system_program,
+        &ctx.accounts.bond_vault,
+        &ctx.accounts.principal.to_account_info(),
+        &bond.key(),
+        bond.vault_bump,
+        vault_balance - amount_slashed - tips,
+    )?;
+    pay_principals(
+        &ctx.accounts.system_program,
+        &ctx.accounts.bond_vault,
+        &ctx.accounts.principal.to_account_info(),
+        bond,
+        tips,
+        ctx.remaining_accounts,
+    )?;
+    ctx.accounts.stats.record_slashed(bond.collateral_amount, amount_slashed);
+    let reputation = &mut ctx.accounts.reputation;
+    reputation.init_if_new(bond.agent, ctx.bumps.reputation);
+    reputation.record_slashed(Clock::get()?.unix_timestamp, ctx.accounts.config.reputation_half_life);
+    track_slash_volume(&mut ctx.accounts.config, &mut ctx.accounts.stats)?;
+
+    bond.record_settlement(amount_slashed, 0);
+    emit!(BondSlashed {
+        bond_id: bond.bond_id.clone(),
+        agent: bond.agent,
+        principal: bond.principal,
+        amount_slashed,
+        reason: SlashReason::EmergencyPrincipal,
+        justification: bond.slash_justification.clone(),
+    });
+
+    msg!("Emergency slash executed for bond: {}", bond.bond_id);
+    Ok(())
+}
+
+/// Move lamports out of a bond's vault PDA
+fn transfer_from_vault<'info>(
+    system_program: &Program<'info, System>,
+    bond_vault: &SystemAccount<'info>,
+    to: &AccountInfo<'info>,
+    bond_key: &Pubkey,
+    bump: u8,
+    amount: u64,
+) -> Result<()> {
+    transfer_signed(
+        system_program,
+        bond_vault,
+        to,
+        &[b"bond_vault", bond_key.as_ref(), &[bump]],
+        amount,
+    )
+}
+
+/// Move lamports out of a system-owned PDA signing with its seeds
+fn transfer_signed<'info>(
+    system_program: &Program<'info, System>,
+    from: &SystemAccount<'info>,
+    to: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    amount: u64,
+) -> Result<()> {
+    let signer = &[seeds];
+    let cpi_context = CpiContext::new_with_signer(
+        system_program.to_account_info(),
+        Transfer {
+            from: from.to_account_info(),
+            to: to.clone(),
+        },
+        signer,
+    );
+    anchor_lang::system_program::transfer(cpi_context, amount)
+}
+
+/// Insurance pool's cut of slash proceeds, skipped while it would leave
+/// the pool below rent exemption
+fn insurance_share(payout: u64, insurance_bps: u16, pool_balance: u64) -> Result<u64> {
+    let share = (payout as u128 * insurance_bps as u128 / 10_000) as u64;
+    if share == 0 || pool_balance + share < Rent::get()?.minimum_balance(0) {
+        return Ok(0);
+    }
+    Ok(share)
+}
+
+/// Checks and records a public verify_work-style vote for `signer`,
+/// returning the member it counts for (the signer, or whoever it proxies)
+fn cast_public_vote(
+    bond: &mut Account<Bond>,
+    signer: Pubkey,
+    approve: bool,
+    remaining_accounts: &[AccountInfo],
+) -> Result<Pubkey> {
+    require!(
+        bond.status == BondStatus::PendingVerification,
+        AgberoError::InvalidBondStatus
+    );
+    require!(
+        signer != bond.agent,
+        AgberoError::AgentCannotVerify
+    );
+    match bond.vote_mode {
+        VoteMode::Public => {}
+        VoteMode::Blind => return Err(AgberoError::PublicVotingDisabled.into()),
+    }
+
+    // A designated proxy always votes on behalf of the member it stands in for
+    let voter = bond.represented_member(&signer).unwrap_or(signer);
+    require!(
+        bond.is_eligible_verifier(&voter),
+        AgberoError::NotInSubcommittee
+    );
+    // One vote per verifier; a member and their proxy share it
+    require!(!bond.has_voted(&voter), AgberoError::AlreadyVoted);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        bond.verification_deadline == 0 || now <= bond.verification_deadline,
+        AgberoError::VerificationWindowClosed
+    );
+    // Bond::MAX_SIZE only budgets MAX_VOTES entries; slash_votes mirror these
+    require!(
+        bond.verification_votes.len() < Bond::MAX_VOTES,
+        AgberoError::VoteLimitReached
+    );
+
+    let vote = VerificationVote {
+        verifier: voter,
+        approve,
+        timestamp: now,
+        cast_by: signer,
+        excluded: false,
+        evidence: false,
+        stake: VerifierStake::amount_for(&bond.key(), &voter, remaining_accounts)?,
+    };
+    bond.verification_votes.push(vote);
+
+    VerifierProfile::record_for(bond, &voter, approve, remaining_accounts)?;
+
+    Ok(voter)
+}
+
+/// Pay a slash to the bond's slash destination: the principal and any
+/// syndicate co-principals by contribution, the treasury, or the incinerator. Co-principal, treasury and
+/// incinerator accounts come from `remaining_accounts`, matched by key
+fn pay_slash<'info>(
+    system_program: &Program<'info, System>,
+    bond_vault: &SystemAccount<'info>,
+    principal: &AccountInfo<'info>,
+    bond: &Account<'info, Bond>,
+    config: &Config,
+    amount: u64,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    // Treasury and burn destinations take the whole slash
+    let destination = match bond.slash_destination {
+        SlashDestination::Principal => None,
+        SlashDestination::Treasury => Some(config.treasury),
+        SlashDestination::Burn => Some(incinerator::ID),
+    };
+    if let Some(destination) = destination {
+        let account = remaining_accounts
+            .iter()
+            .find(|a| a.key() == destination)
+            .ok_or(AgberoError::SlashDestinationMissing)?;
+        return transfer_from_vault(system_program, bond_vault, account, &bond.key(), bond.vault_bump, amount);
+    }
+
+    pay_principals(system_program, bond_vault, principal, bond, amount, remaining_accounts)
+}
+
+/// Pay `amount` to the principal and any syndicate co-principals in
+/// proportion to the tips each funded, see Bond::principal_weights.
+/// Co-principal accounts come from `remaining_accounts`
+fn pay_principals<'info>(
+    system_program: &Program<'info, System>,
+    bond_vault: &SystemAccount<'info>,
+    principal: &AccountInfo<'info>,
+    bond: &Account<'info, Bond>,
+    amount: u64,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let shares = largest_remainder_split(amount, &bond.principal_weights());
+
+    let mut paid = 0u64;
+    for (member, &share) in bond.syndicate.iter().zip(&shares) {
+        if share == 0 {
+            continue;
+        }
+        let account = remaining_accounts
+            .iter()
+            .find(|a| a.key() == member.member)
+            .ok_or(AgberoError::SyndicateMemberMissing)?;
+        transfer_from_vault(system_program, bond_vault, account, &bond.key(), bond.vault_bump, share)?;
+        paid += share;
+    }
+    transfer_from_vault(
+        system_program,
+        bond_vault,
+        principal,
+        &bond.key(),
+        bond.vault_bump,
+        amount - paid,
+    )
+}
+
+/// Split Config::verifier_reward_bps of a completed bond's payout equally
+/// among the approving verifiers, passed in remaining accounts; the
+/// indivisible remainder goes to the treasury. A verifier whose account is
+/// not passed has their share parked in the bond account for
+/// claim_deferred_reward. Returns the total paid or parked
+fn pay_verifier_rewards<'info>(
+    system_program: &Program<'info, System>,
+    bond_vault: &SystemAccount<'info>,
+    bond: &mut Account<'info, Bond>,
+    config: &Config,
+    payout: u64,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<u64> {
+    let reward = (payout as u128 * config.verifier_reward_bps as u128 / 10_000) as u64;
+    let winners: Vec<(usize, Pubkey)> = bond
+        .verification_votes
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| !v.excluded && v.approve)
+        .map(|(i, v)| (i, v.verifier))
+        .collect();
+    if reward == 0 || winners.is_empty() {
+        return Ok(0);
+    }
+
+    let share = reward / winners.len() as u64;
+    let bond_info = bond.to_account_info();
+    for (index, verifier) in &winners {
+        match remaining_accounts.iter().find(|a| a.key() == *verifier) {
+            Some(account) => {
+                transfer_from_vault(system_program, bond_vault, account, &bond.key(), bond.vault_bump, share)?
+            }
+            None => {
+                transfer_from_vault(system_program, bond_vault, &bond_info, &bond.key(), bond.vault_bump, share)?;
+                bond.deferred_rewards |= 1 << index;
+            }
+        }
+    }
+    bond.reward_share = share;
+    let dust = reward - share * winners.len() as u64;
+    if dust > 0 {
+        let treasury = remaining_accounts
+            .iter()
+            .find(|a| a.key() == config.treasury)
+            .ok_or(AgberoError::TreasuryMissing)?;
+        transfer_from_vault(system_program, bond_vault, treasury, &bond.key(), bond.vault_bump, dust)?;
+    }
+
+    emit!(VerifierRewardsPaid {
+        bond_id: bond.bond_id.clone(),
+        verifiers: winners.len() as u8,
+        share,
+        dust,
+        deferred: bond.deferred_rewards.count_ones() as u8,
+    });
+    Ok(reward)
+}
+
+/// Return what is left in a settled bond's vault, its rent reserve, to the
+/// principal who funded it, emptying the vault as the bond is closed
+fn release_vault_rent<'info>(
+    system_program: &Program<'info, System>,
+    bond_vault: &SystemAccount<'info>,
+    principal: &AccountInfo<'info>,
+    bond: &Account<'info, Bond>,
+) -> Result<u64> {
+    let remaining = bond_vault.lamports();
+    if remaining > 0 {
+        transfer_from_vault(system_program, bond_vault, principal, &bond.key(), bond.vault_bump, remaining)?;
+    }
+    Ok(remaining)
+}
+
+/// BondClosed summary for a settled bond about to be closed
+fn emit_bond_closed(bond: &Account<Bond>, vault_rent: u64) {
+    let (approve_votes, slash_votes) = bond.tally_votes();
+    emit!(BondClosed {
+        bond_id: bond.bond_id.clone(),
+        status: bond.status,
+        total_slashed: bond.total_slashed,
+        total_released: bond.total_released,
+        approve_votes,
+        slash_votes,
+        duration: bond.completed_at - bond.created_at,
+        rent_refunded: bond.to_account_info().lamports() + vault_rent,
+    });
+}
+
+/// Send Config::fee_bps of a slash to the treasury, which the caller passes
+/// in remaining accounts. Rounds down, so fee never exceeds the slash.
+fn pay_protocol_fee<'info>(
+    system_program: &Program<'info, System>,
+    bond_vault: &SystemAccount<'info>,
+    bond: &Account<'info, Bond>,
+    config: &Config,
+    slashed: u64,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<u64> {
+    let fee = (slashed as u128 * config.fee_bps as u128 / 10_000) as u64;
+    if fee == 0 {
+        return Ok(0);
+    }
+    let treasury = remaining_accounts
+        .iter()
+        .find(|a| a.key() == config.treasury)
+        .ok_or(AgberoError::TreasuryMissing)?;
+    transfer_from_vault(
+        system_program,
+        bond_vault,
+        treasury,
+        &bond.key(),
+        bond.vault_bump,
+        fee,
+    )?;
+
+    emit!(ProtocolFeeCollected {
+        bond_id: bond.bond_id.clone(),
+        treasury: config.treasury,
+        amount: fee,
+    });
+    Ok(fee)
+}
+
+/// A system-owned vault must be left either empty or rent-exempt
+fn require_vault_remainder(balance: u64, amount: u64) -> Result<()> {
+    let remaining = balance
+        .checked_sub(amount)
+        .ok_or(AgberoError::InsufficientStakingBalance)?;
+    require!(
+        remaining == 0 || remaining >= Rent::get()?.minimum_balance(0),
+        AgberoError::InsufficientStakingBalance
+    );
+    Ok(())
+}
+
+/// Feed a slash into the circuit breaker, pausing the program on abnormal volume
+fn track_slash_volume(config: &mut Config, stats: &mut Stats) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    if stats.record_breaker_slash(now, config.breaker_window, config.breaker_max_slashes) {
+        config.paused = true;
+
+        emit!(CircuitBreakerTripped {
+            window_slashes: stats.breaker_window_slashes,
+            window_start: stats.breaker_window_start,
+        });
+
+        msg!("Circuit breaker tripped: program paused");
+    }
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct HealthMetrics {
+    pub slash_rate_bps: u64,
+    pub average_bond_size: u64,
+    pub total_value_locked: u64,
+    pub active_bonds: u64,
+}
+
+/// get_bond_status result, see Bond::quorum_snapshot
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct QuorumSnapshot {
+    pub total_votes: u32,
+    pub approve_votes: u32,
+    pub slash_votes: u32,
+    pub quorum_reached: bool,
+    pub tied: bool,                   // quorum with equal weight on each side; finalize refunds
+    pub can_finalize: bool,           // finalize_bond would settle the bond now
+    pub winning_outcome: Option<bool>, // true = complete, false = slash, None = undecided
+}
+
+/// replay_events result: every persisted timestamp and amount an indexer
+/// needs to rebuild the bond's event history, at most ~700 bytes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BondReplay {
+    pub bond_id: String,
+    pub principal: Pubkey,
+    pub agent: Pubkey,
+    pub status: BondStatus,
+    pub slash_reason: Option<SlashReason>,
+    pub collateral_amount: u64,
+    pub buffer_amount: u64,
+    pub keeper_bounty: u64,
+    pub tip_pool: u64,
+    pub created_at: i64,              // BondCreated
+    pub staked_at: i64,               // CollateralStaked, 0 = not staked
+    pub started_at: i64,              // begin_work, 0 = not started
+    pub deadline: i64,                // current deadline, after any extensions
+    pub slash_requested_at: i64,      // EmergencySlashRequested, 0 = none pending
+    pub slash_appealed: bool,
+    pub slash_acknowledged: bool,
+    pub completed_at: i64,            // terminal event, 0 = still open
+    pub total_slashed: u64,
+    pub total_released: u64,
+    pub proofs: Vec<ReplayProof>,     // ProofSubmitted, in order
+    pub votes: Vec<ReplayVote>,       // WorkVerified, in order
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ReplayProof {
+    pub submitted_at: i64,
+    pub late: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ReplayVote {
+    pub verifier: Pubkey,
+    pub approve: bool,
+    pub timestamp: i64,
+    pub excluded: bool,
+}
+
+/// estimate_participation result; advisory only
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ParticipationEstimate {
+    pub expected_verifiers: u64,
+    pub reward_per_verifier: u64,
+    pub reaches_quorum: bool,         // at least the config.min_votes finalize_bond needs
+}
+
+impl ParticipationEstimate {
+    /// Reward units a verifier's share must reach before voting is worth it
+    pub const PARTICIPATION_COST: u64 = 1_000_000;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BondPdas {
+    pub bond: Pubkey,
+    pub bond_bump: u8,
+    pub bond_vault: Pubkey,
+    pub vault_bump: u8,
+}
+
+/// audit_vault result; discrepancy = actual - expected, 0 when in balance
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VaultAudit {
+    pub expected: u64,
+    pub actual: u64,
+    pub discrepancy: i128,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BondTimeline {
+    pub created_at: i64,
+    pub staked_at: i64,
+    pub started_at: i64,
+    pub proof_submitted_at: i64,
+    pub finalized_at: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum BondStatus {
+    Pending,              // Created, waiting for stake
+    Active,               // Staked, work in progress
+    PendingVerification,  // Proof submitted, awaiting votes
+    Completed,            // Work verified, stake released
+    Slashed,              // Work failed/scam, stake slashed
+    Expired,              // No proof by the timeout, stake refunded (Config::no_proof_outcome)
+    Disputed,             // Escalated to the arbiter, awaiting resolve_dispute
+    Recovered,            // Arbiter routed the funds via finalize_override
+    SlashRequested,       // Emergency slash requested, frozen for the appeal window
+}
+
+/// What finalize_bond does with a bond that times out without any proof
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum NoProofOutcome {
+    Slash,                // collateral to the principal (default)
+    Refund,               // collateral back to the agent
+}
+
+/// Which path slashed a bond, kept for post-mortems
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum SlashReason {
+    TimeoutNoProof,       // Deadline + grace passed without any proof
+    TimeoutNoQuorum,      // Proof submitted but votes never reached quorum
+    MajorityVote,         // Verifiers voted to slash
+    EmergencyPrincipal,   // Principal's emergency slash
+    Oracle,               // Trusted oracle settled against the agent
+    Arbiter,              // Arbiter ruled against the agent in a dispute
+    MissedInstallment,    // Agent missed an installment due date
+}
+
+/// Which branch of finalize_bond settled a bond, reported in BondFinalized
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum FinalizePath {
+    QuorumApprove,        // Verifiers reached an approving majority
+    QuorumSlash,          // Verifiers reached a slashing majority
+    AutoSlash,            // Window lapsed without a deciding quorum
+    Tied,                 // Quorum split evenly; collateral refunded
+    NoProofRefund,        // Window lapsed without proof under NoProofOutcome::Refund
+}
+
+/// Verdict delivered by the trusted oracle in oracle_settle or finalize_from_tally
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum SettlementOutcome {
+    Complete,
+    Slash,
+}
+
+/// Where a bond's slashed collateral is sent, chosen by the principal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum SlashDestination {
+    Principal,            // the principal, shared with any syndicate
+    Treasury,             // Config::treasury
+    Burn,                 // the incinerator, removing the lamports from supply
+}
+
+/// How much each vote counts toward the majority
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum VoteWeighting {
+    Equal,                // one verifier, one vote
+    Linear,               // weight = verifier stake
+    Quadratic,            // weight = sqrt(verifier stake), damps whales
+}
+
+impl VoteWeighting {
+    pub fn weight(&self, stake: u64) -> u128 {
+        match self {
+            VoteWeighting::Equal => 1,
+            VoteWeighting::Linear => stake as u128,
+            VoteWeighting::Quadratic => isqrt(stake) as u128,
+        }
+    }
+}
+
+/// Integer square root (floor), Newton's method
+fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = x / 2 + x % 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// Whether votes are visible as cast or hidden behind commit-reveal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum VoteMode {
+    Public,               // verify_work, votes visible immediately
+    Blind,                // commit_vote / reveal_vote only
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VerificationVote {
+    pub verifier: Pubkey,
+    pub approve: bool,
+    pub timestamp: i64,
+    pub cast_by: Pubkey,              // verifier, or the proxy voting for them
+    pub excluded: bool,               // invalidated by the arbiter, ignored by finalize
+    pub evidence: bool,               // verifier attached an evidence URI, eligible for tips
+    pub stake: u64,                   // verifier's stake when the vote was cast; weighs the vote
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProxyDesignation {
+    pub member: Pubkey,
+    pub proxy: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProofEntry {
+    pub uri: String,                  // 4 + 200
+    pub submitted_at: i64,            // 8
+    pub late: bool,                   // 1
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VoteCommit {
+    pub verifier: Pubkey,
+    pub vote_hash: [u8; 32],
+    pub revealed: bool,
+}
+
+/// Milestone as requested in BondOptions
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MilestoneSpec {
+    pub description: String,
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Milestone {
+    pub description: String,          // 4 + 64
+    pub amount: u64,                  // 8, lamports released to the agent
+    pub released: bool,               // 1
+    pub approvers: Vec<Pubkey>,       // 4 + 32 * MAX_MILESTONE_APPROVERS
+}
+
+impl Milestone {
+    pub const MAX_DESCRIPTION: usize = 64;
+    pub const MAX_SIZE: usize = 4 + Self::MAX_DESCRIPTION + 8 + 1 + 4 + 32 * Bond::MAX_MILESTONE_APPROVERS;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SlashVote {
+    pub voter: Pubkey,
+    pub reason: String,               // grader's justification, at most MAX_REASON bytes
+    pub timestamp: i64,
+}
+
+impl SlashVote {
+    pub const MAX_REASON: usize = 100;
+    pub const MAX_SIZE: usize = 32 + 4 + Self::MAX_REASON + 8;
+}
+
+/// Optional per-bond settings chosen by the principal at creation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BondOptions {
+    pub vote_mode: VoteMode,
+    pub commit_window: i64,  // seconds after proof for commits (Blind only)
+    pub reveal_window: i64,  // seconds after commit phase for reveals (Blind only)
+    pub keeper_bounty: u64,  // lamports paid to whoever finalizes, funded by the principal
+    pub vote_weighting: VoteWeighting, // Linear/Quadratic weigh votes by VerifierStake
+    pub callback: Option<CompletionCallback>, // invoked on completion once the agent approves
+    pub insured_amount: u64, // pool cover if the agent vanishes without proof, at most the collateral
+    pub require_begin_work: bool, // agent must call begin_work before submitting proof
+    pub installments: u8,    // >1 posts collateral via fund_installment instead of stake_collateral
+    pub installment_interval: i64, // seconds between installment due dates
+    pub appeal_window: i64,  // seconds the agent has to appeal an emergency slash, within Config::min/max_appeal_window
+    pub category: u8,        // task category verifiers must opt into, 0 = uncategorised
+    pub escrow_authority: Pubkey, // neutral escrow (e.g. a multisig) co-signing vault releases, default = none
+    pub milestones: Vec<MilestoneSpec>, // checkpoints summing to the collateral, empty = all-or-nothing
+    pub syndicate: Vec<Pubkey>, // co-principals invited to fund tips and share slashes by contribution
+    pub slash_destination: SlashDestination, // principal (and syndicate), treasury or burn
+    pub rubric: String,      // criteria verifiers judge the proof against, at most Bond::MAX_RUBRIC bytes
+}
+
+impl BondOptions {
+    pub const MAX_PHASE_WINDOW: i64 = 30 * 86400;
+    pub const MAX_INSTALLMENTS: usize = 12;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            (0..=Self::MAX_PHASE_WINDOW).contains(&self.commit_window)
+                && (0..=Self::MAX_PHASE_WINDOW).contains(&self.reveal_window),
+            AgberoError::InvalidVotingWindow
+        );
+        let windows_set = self.commit_window > 0 && self.reveal_window > 0;
+        let windows_unset = self.commit_window == 0 && self.reveal_window == 0;
+        match self.vote_mode {
+            VoteMode::Public => require!(windows_unset, AgberoError::InvalidVotingWindow),
+            VoteMode::Blind => require!(windows_set, AgberoError::InvalidVotingWindow),
+        }
+        require!(
+            self.installments as usize <= Self::MAX_INSTALLMENTS
+                && (self.installments <= 1 || self.installment_interval > 0),
+            AgberoError::InvalidInstallmentSchedule
+        );
+        require!(self.appeal_window >= 0, AgberoError::InvalidAppealWindow);
+        Ok(())
+    }
+}
+
+#[error_code]
+pub enum AgberoError {
+    #[msg("Description too long (max 500 chars)")]
+    DescriptionTooLong,
+    #[msg("Collateral too low (min 0.001 SOL)")]
+    CollateralTooLow,
+    #[msg("Invalid deadline")]
+    InvalidDeadline,
+    #[msg("Invalid bond status")]
+    InvalidBondStatus,
+    #[msg("Unauthorized agent")]
+    UnauthorizedAgent,
+    #[msg("Unauthorized principal")]
+    UnauthorizedPrincipal,
+    #[msg("Agent cannot verify own work")]
+    AgentCannotVerify,
+    #[msg("Deadline exceeded")]
+    DeadlineExceeded,
+    #[msg("Proof URI too long")]
+    ProofUriTooLong,
+    #[msg("Proof submission limit reached")]
+    ProofLimitReached,
+    #[msg("Quorum not yet reached")]
+    QuorumNotReached,
+    #[msg("Invalid commit/reveal window")]
+    InvalidVotingWindow,
+    #[msg("Commit-reveal voting not enabled for this bond")]
+    CommitRevealDisabled,
+    #[msg("Bond requires commit-reveal voting")]
+    PublicVotingDisabled,
+    #[msg("Commit phase has closed")]
+    CommitPhaseClosed,
+    #[msg("Reveal phase not active")]
+    RevealPhaseNotActive,
+    #[msg("Verifier already committed a vote")]
+    AlreadyCommitted,
+    #[msg("Vote commitment limit reached")]
+    CommitLimitReached,
+    #[msg("No vote commitment found for verifier")]
+    CommitNotFound,
+    #[msg("Vote already revealed")]
+    AlreadyRevealed,
+    #[msg("Revealed vote does not match commitment")]
+    CommitMismatch,
+    #[msg("New collateral must be below the current amount")]
+    InvalidCollateralReduction,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Unauthorized admin")]
+    UnauthorizedAdmin,
+    #[msg("Invalid config parameters")]
+    InvalidConfig,
+    #[msg("Invalid sub-committee parameters")]
+    InvalidSubcommittee,
+    #[msg("Voting has already started on this bond")]
+    VotingAlreadyStarted,
+    #[msg("Verifier is not a member of the bond's sub-committee")]
+    NotInSubcommittee,
+    #[msg("Invalid proxy designation")]
+    InvalidProxy,
+    #[msg("Too many proxy designations")]
+    ProxyLimitReached,
+    #[msg("Verifier has already voted")]
+    AlreadyVoted,
+    #[msg("Stake amount must be positive")]
+    InvalidStakeAmount,
+    #[msg("Staking vault balance too low (must stay empty or rent-exempt)")]
+    InsufficientStakingBalance,
+    #[msg("Invalid new principal")]
+    InvalidPrincipalTransfer,
+    #[msg("Signer is not the configured oracle")]
+    UnauthorizedOracle,
+    #[msg("Bond account is already at its used size")]
+    NoRentToReclaim,
+    #[msg("Invalid completion callback")]
+    InvalidCallback,
+    #[msg("Callback program account not supplied")]
+    CallbackProgramMissing,
+    #[msg("Stake window has expired")]
+    StakeWindowExpired,
+    #[msg("Stake window is still open")]
+    StakeWindowOpen,
+    #[msg("Signer is not the configured arbiter")]
+    UnauthorizedArbiter,
+    #[msg("No vote recorded for this verifier")]
+    VoteNotFound,
+    #[msg("Vote already excluded")]
+    VoteAlreadyExcluded,
+    #[msg("Verifier stake account not supplied")]
+    VerifierStakeMissing,
+    #[msg("Reward amount must be positive")]
+    InvalidRewardAmount,
+    #[msg("Mint is not the configured reward mint")]
+    InvalidRewardMint,
+    #[msg("No reward due to this verifier")]
+    NoRewardDue,
+    #[msg("Reward already claimed")]
+    RewardAlreadyClaimed,
+    #[msg("Invalid team member")]
+    InvalidTeamMember,
+    #[msg("Team is full")]
+    TeamFull,
+    #[msg("Contributions would exceed the collateral amount")]
+    ContributionExceedsCollateral,
+    #[msg("Team member account not supplied")]
+    TeamMemberMissing,
+    #[msg("Only the team lead may submit proof")]
+    NotTeamLead,
+    #[msg("Verification window has closed")]
+    VerificationWindowClosed,
+    #[msg("Insured amount cannot exceed the collateral")]
+    InvalidInsuredAmount,
+    #[msg("No insurance is due on this bond")]
+    NoInsuranceDue,
+    #[msg("Insurance pool has no funds to pay out")]
+    InsurancePoolEmpty,
+    #[msg("Reputation account already uses the current layout")]
+    ReputationUpToDate,
+    #[msg("Batch accounts must be (bond, bond_vault) pairs")]
+    InvalidBatchAccounts,
+    #[msg("This principal and agent bonded too recently")]
+    BondCooldownActive,
+    #[msg("Work has already been started")]
+    WorkAlreadyStarted,
+    #[msg("Agent must begin work before submitting proof")]
+    WorkNotStarted,
+    #[msg("Only the principal or agent may do this")]
+    NotBondParty,
+    #[msg("Verifier assignment has not expired")]
+    AssignmentNotExpired,
+    #[msg("Recovery amount must be positive and within the vault balance")]
+    InvalidRecoveryAmount,
+    #[msg("Invalid installment schedule")]
+    InvalidInstallmentSchedule,
+    #[msg("Bond collateral is posted via its installment schedule")]
+    InstallmentScheduleActive,
+    #[msg("Installment is overdue")]
+    InstallmentOverdue,
+    #[msg("Installment is not yet overdue")]
+    InstallmentNotOverdue,
+    #[msg("Verifier pool is full")]
+    VerifierPoolFull,
+    #[msg("Slash reason too long (max 200 chars)")]
+    SlashReasonTooLong,
+    #[msg("Invalid vote bundle")]
+    InvalidVoteBundle,
+    #[msg("Bundled vote has no matching Ed25519 signature")]
+    MissingVoteSignature,
+    #[msg("Tipped verifier account not provided")]
+    TipRecipientMissing,
+    #[msg("Approve and slash majorities both reached")]
+    AmbiguousQuorum,
+    #[msg("Bond is under a legal hold")]
+    BondOnHold,
+    #[msg("Bond is not under a legal hold")]
+    BondNotOnHold,
+    #[msg("Unauthorized legal authority")]
+    UnauthorizedLegalAuthority,
+    #[msg("Invalid appeal window")]
+    InvalidAppealWindow,
+    #[msg("Emergency slash already requested")]
+    SlashAlreadyRequested,
+    #[msg("No emergency slash requested")]
+    SlashNotRequested,
+    #[msg("Appeal window still open")]
+    AppealWindowOpen,
+    #[msg("Only Pending bonds can be cancelled")]
+    CannotCancelActiveBond,
+    #[msg("Verifier has not opted into this bond's category")]
+    CategoryNotOptedIn,
+    #[msg("Invalid verifier categories")]
+    InvalidCategories,
+    #[msg("Vote does not belong to the tally's Merkle root")]
+    InvalidTallyProof,
+    #[msg("Sampled votes are inconsistent with the claimed tally")]
+    TallyMismatch,
+    #[msg("Protocol fee treasury account not provided")]
+    TreasuryMissing,
+    #[msg("Appeal window has closed")]
+    AppealWindowClosed,
+    #[msg("Emergency slash was already appealed on this bond")]
+    SlashAlreadyAppealed,
+    #[msg("Bond's escrow authority must co-sign")]
+    EscrowSignatureMissing,
+    #[msg("Bond already holds the maximum number of votes")]
+    VoteLimitReached,
+    #[msg("Deadline has already been extended the maximum number of times")]
+    ExtensionLimitReached,
+    #[msg("Agent cover is not available for this bond")]
+    AgentCoverUnavailable,
+    #[msg("Agent premium already paid")]
+    AgentPremiumAlreadyPaid,
+    #[msg("Arbiter has already ruled on this bond")]
+    AlreadyRuled,
+    #[msg("Verifiers have already approved this bond")]
+    ApprovalQuorumReached,
+    #[msg("Milestones must be non-zero, described briefly and sum to the collateral")]
+    InvalidMilestones,
+    #[msg("Milestone has not been approved by enough verifiers")]
+    MilestoneNotApproved,
+    #[msg("Milestone already has its approvals")]
+    MilestoneAlreadyApproved,
+    #[msg("Milestone already released")]
+    MilestoneAlreadyReleased,
+    #[msg("Syndicate lists at most Bond::MAX_SYNDICATE distinct co-principals")]
+    InvalidSyndicate,
+    #[msg("Syndicate co-principal account not provided")]
+    SyndicateMemberMissing,
+    #[msg("Bond id is reserved by another principal")]
+    BondIdReserved,
+    #[msg("Principal cannot bond themselves as the agent")]
+    PrincipalCannotBeAgent,
+    #[msg("Approving verifier account not provided")]
+    VerifierAccountMissing,
+    #[msg("Complexity tier above Config::MAX_COMPLEXITY_TIER")]
+    InvalidComplexityTier,
+    #[msg("Treasury slash destination needs a configured treasury")]
+    InvalidSlashDestination,
+    #[msg("Slash destination account not provided")]
+    SlashDestinationMissing,
+    #[msg("Verification rubric too long")]
+    RubricTooLong,
+    #[msg("Only bonds with votes on both sides can be escalated")]
+    DisputeNotContested,
+    #[msg("Deadline arithmetic overflowed")]
+    DeadlineOverflow,
+    #[msg("Bond id longer than the 32-byte seed limit")]
+    BondIdTooLong,
+    #[msg("Bond id cannot be empty")]
+    BondIdEmpty,
+    #[msg("Agent already acknowledged this emergency slash")]
+    SlashAlreadyAcknowledged,
+    #[msg("Stake must be placed before voting")]
+    StakeAfterVote,
+    #[msg("Losing stakes must be reclaimed before winners are paid")]
+    ForfeitsOutstanding,
+    #[msg("Principal cover is not available for this bond")]
+    PrincipalCoverUnavailable,
+    #[msg("Principal premium already paid")]
+    PrincipalPremiumAlreadyPaid,
+    #[msg("No proof has been submitted yet")]
+    ProofRequired,
+    #[msg("Stakes, rewards or cover are still outstanding on this bond")]
+    ClaimsOutstanding,
+    #[msg("Sub-committee already formed for this bond")]
+    SubcommitteeAlreadyFormed,
+    #[msg("Sub-committee draw already requested")]
+    SubcommitteeAlreadyRequested,
+    #[msg("No sub-committee draw has been requested")]
+    SubcommitteeNotRequested,
+    #[msg("Slot seeding the draw has not been reached")]
+    DrawNotReady,
+    #[msg("Slot seeding the draw is too old")]
+    DrawExpired,
+    #[msg("Drawn verifier's profile not supplied")]
+    CandidateMissing,
+    #[msg("Tally already committed for this bond")]
+    TallyAlreadyCommitted,
+    #[msg("No tally has been committed for this bond")]
+    TallyNotCommitted,
+    #[msg("SlotHashes sysvar not supplied")]
+    SlotHashesMissing,
+    #[msg("A sampled tally leaf was not proven")]
+    TallySampleMissing,
+    #[msg("Voter's verifier profile account missing")]
+    VerifierProfileMissing,
+    #[msg("Winning verifiers can still claim the reward pool")]
+    RewardsStillClaimable,
+    #[msg("Signer is not a co-principal on this bond")]
+    NotSyndicateMember,
+    #[msg("Governance account is not owned by the configured governance program")]
+    UnauthorizedGovernance,
+    #[msg("DAO decision was resolved on an earlier version of the task")]
+    StaleDaoDecision,
+    #[msg("DAO decision already resolved for the current task")]
+    DaoDecisionAlreadyResolved,
+}
+
+// Events for indexing
+#[event]
+pub struct BondCreated {
+    pub bond_id: String,
+    pub principal: Pubkey,
+    pub agent: Pubkey,
+    pub collateral_amount: u64,
+    pub deadline: i64,
+    pub keeper_bounty: u64,
+}
+
+#[event]
+pub struct BondIdReserved {
+    pub commitment: [u8; 32],
+    pub reserver: Pubkey,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct DaoDecisionResolved {
+    pub bond_id: String,
+    pub governance: Pubkey,
+    pub approve: bool,
+}
+
+#[event]
+pub struct VoteTied {
+    pub bond_id: String,
+    pub agent: Pubkey,
+    pub approve_votes: u32,
+    pub slash_votes: u32,
+    pub amount_refunded: u64,
+}
+
+#[event]
+pub struct RubricPublished {
+    pub bond_id: String,
+    pub rubric: String,
+}
+
+#[event]
+pub struct BondCancelled {
+    pub bond_id: String,
+    pub principal: Pubkey,
+    pub refunded: u64,
+}
+
+#[event]
+pub struct BondClosed {
+    pub bond_id: String,
+    pub status: BondStatus,
+    pub total_slashed: u64,
+    pub total_released: u64,
+    pub approve_votes: u64,
+    pub slash_votes: u64,
+    pub duration: i64,
+    pub rent_refunded: u64,
+}
+
+#[event]
+pub struct BufferToppedUp {
+    pub bond_id: String,
+    pub amount: u64,
+    pub buffer_amount: u64,
+}
+
+#[event]
+pub struct QuorumRaised {
+    pub bond_id: String,
+    pub required_votes: u64,
+}
+
+#[event]
+pub struct TeamContribution {
+    pub bond_id: String,
+    pub member: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct InstallmentFunded {
+    pub bond_id: String,
+    pub index: u8,
+    pub amount: u64,
+    pub remaining: u64,
+}
+
+#[event]
+pub struct CollateralStaked {
+    pub bond_id: String,
+    pub agent: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct StakingVaultDeposited {
+    pub agent: Pubkey,
+    pub amount: u64,
+    pub balance: u64,
+}
+
+#[event]
+pub struct StakingVaultWithdrawn {
+    pub agent: Pubkey,
+    pub amount: u64,
+    pub balance: u64,
+}
+
+#[event]
+pub struct WorkStarted {
+    pub bond_id: String,
+    pub agent: Pubkey,
+    pub started_at: i64,
+}
+
+#[event]
+pub struct ProofSubmitted {
+    pub bond_id: String,
+    pub agent: Pubkey,
+    pub proof_uri: String,
+    pub proof_index: u8,
+    pub late: bool,
+}
+
+#[event]
+pub struct WorkVerified {
+    pub bond_id: String,
+    pub verifier: Pubkey,
+    pub approve: bool,
+    pub cast_by: Pubkey,
+}
+
+#[event]
+pub struct ProxySet {
+    pub bond_id: String,
+    pub member: Pubkey,
+    pub proxy: Pubkey,
+}
+
+#[event]
+pub struct PrincipalTransferred {
+    pub bond_id: String,
+    pub old_principal: Pubkey,
+    pub new_principal: Pubkey,
+}
+
+#[event]
+pub struct VerifierStaked {
+    pub bond_id: String,
+    pub verifier: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct VerifierStakeReclaimed {
+    pub bond_id: String,
+    pub verifier: Pubkey,
+    pub returned: u64,                // stake less forfeit plus any share of forfeits
+    pub forfeited: u64,
+}
+
+#[event]
+pub struct ReputationMigrated {
+    pub agent: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+#[event]
+pub struct InsuranceFunded {
+    pub bond_id: String,
+    pub amount: u64,
+}
+
+#[event]
+pub struct InsuranceClaimed {
+    pub bond_id: String,
+    pub principal: Pubkey,
+    pub amount: u64,
+    pub remaining_due: u64,
+}
+
+#[event]
+pub struct VoteRentReclaimed {
+    pub bond_id: String,
+    pub bytes_released: u64,
+    pub lamports_refunded: u64,
+}
+
+#[event]
+pub struct VoteInvalidated {
+    pub bond_id: String,
+    pub verifier: Pubkey,
+    pub stake_slashed: u64,
+}
+
+#[event]
+pub struct EmergencySlashRequested {
+    pub bond_id: String,
+    pub principal: Pubkey,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct EmergencySlashAppealed {
+    pub bond_id: String,
+    pub agent: Pubkey,
+    pub status: BondStatus,
+}
+
+#[event]
+pub struct SlashAcknowledged {
+    pub bond_id: String,
+    pub agent: Pubkey,
+}
+
+#[event]
+pub struct HoldPlaced {
+    pub bond_id: String,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct HoldLifted {
+    pub bond_id: String,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct TipsFunded {
+    pub bond_id: String,
+    pub amount: u64,
+    pub tip_pool: u64,
+}
+
+#[event]
+pub struct SyndicateJoined {
+    pub bond_id: String,
+    pub member: Pubkey,
+    pub contribution: u64,
+    pub tip_pool: u64,
+}
+
+#[event]
+pub struct EvidenceAttached {
+    pub bond_id: String,
+    pub verifier: Pubkey,
+    pub evidence_uri: String,
+}
+
+#[event]
+pub struct VerifierTipped {
+    pub bond_id: String,
+    pub verifier: Pubkey,
+    pub amount: u64,
+    pub deferred: bool,               // parked in the bond account for claim_deferred_reward
+}
+
+#[event]
+pub struct VerifierRewardsFunded {
+    pub bond_id: String,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub reward_pool: u64,
+}
+
+#[event]
+pub struct VerifierRewardClaimed {
+    pub bond_id: String,
+    pub verifier: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct VerifierRewardsReclaimed {
+    pub bond_id: String,
+    pub principal: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TaskAmended {
+    pub bond_id: String,
+    pub new_deadline: i64,
+}
+
+#[event]
+pub struct CollateralReduced {
+    pub bond_id: String,
+    pub old_amount: u64,
+    pub new_amount: u64,
+    pub refunded: u64,
 }
 
-#[derive(Accounts)]
-pub struct SubmitProof<'info> {
-    #[account(mut)]
-    pub agent: Signer<'info>,
-    
-    #[account(
-        mut,
-        constraint = bond.agent == agent.key()
-    )]
-    pub bond: Account<'info, Bond>,
+#[event]
+pub struct VoteCommitted {
+    pub bond_id: String,
+    pub verifier: Pubkey,
 }
 
-#[derive(Accounts)]
-pub struct VerifyWork<'info> {
-    pub verifier: Signer<'info>,
-    
-    #[account(mut)]
-    pub bond: Account<'info, Bond>,
+#[event]
+pub struct VerifierReassigned {
+    pub bond_id: String,
+    pub previous: Pubkey,
+    pub replacement: Pubkey,
 }
 
-#[derive(Accounts)]
-pub struct FinalizeBond<'info> {
-    /// CHECK: Anyone can call to execute autonomously
-    pub executor: Signer<'info>,
-    
-    #[account(mut)]
-    pub bond: Account<'info, Bond>,
-    
-    #[account(
-        mut,
-        seeds = [b"bond_vault", bond.key().as_ref()],
-        bump = bond.bump
-    )]
-    pub bond_vault: SystemAccount<'info>,
-    
-    /// CHECK: Agent account for refund
-    #[account(mut, address = bond.agent)]
-    pub agent: AccountInfo<'info>,
-    
-    /// CHECK: Principal account for slash payout
-    #[account(mut, address = bond.principal)]
-    pub principal: AccountInfo<'info>,
-    
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct SubcommitteeRequested {
+    pub bond_id: String,
+    pub draw_slot: u64,
 }
 
-#[derive(Accounts)]
-pub struct EmergencySlash<'info> {
-    #[account(mut)]
-    pub principal: Signer<'info>,
-    
-    #[account(
-        mut,
-        constraint = bond.principal == principal.key()
-    )]
-    pub bond: Account<'info, Bond>,
-    
-    #[account(
-        mut,
-        seeds = [b"bond_vault", bond.key().as_ref()],
-        bump = bond.bump
-    )]
-    pub bond_vault: SystemAccount<'info>,
-    
-    /// CHECK: Principal receives slash
-    #[account(mut, address = bond.principal)]
-    pub principal_vault: AccountInfo<'info>,
-    
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct SubcommitteeFormed {
+    pub bond_id: String,
+    pub members: Vec<Pubkey>,
 }
 
-#[account]
-pub struct Bond {
-    pub bond_id: String,              // 4 + 50
-    pub principal: Pubkey,            // 32
-    pub agent: Pubkey,                // 32
-    pub task_description: String,     // 4 + 500
-    pub collateral_amount: u64,       // 8
-    pub deadline: i64,                // 8
-    pub status: BondStatus,           // 1
-    pub created_at: i64,              // 8
-    pub completed_at: i64,            // 8
-    pub verification_votes: Vec<VerificationVote>, // 4 + (41 * 10)
-    pub slash_votes: Vec<SlashVote>,  // 4 + (41 * 10)
-    pub proof_uri: String,            // 4 + 200
-    pub bump: u8,                     // 1
+#[event]
+pub struct BondFinalized {
+    pub bond_id: String,
+    pub status: BondStatus,
+    pub total_votes: u32,
+    pub approve_votes: u32,
+    pub slash_votes: u32,
+    pub path: FinalizePath,
 }
 
-impl Bond {
-    pub const MAX_SIZE: usize = 
-        4 + 50 +    // bond_id
-        32 +        // principal
-        32 +        // agent
-        4 + 500 +   // task_description
-        8 +         // collateral_amount
-        8 +         // deadline
-        1 +         // status
-        8 +         // created_at
-        8 +         // completed_at
-        4 + (41 * 10) + // verification_votes (max 10)
-        4 + (41 * 10) + // slash_votes (max 10)
-        4 + 200 +   // proof_uri
-        1;          // bump
+#[event]
+pub struct BondCompleted {
+    pub bond_id: String,
+    pub agent: Pubkey,
+    pub stake_released: u64,
+    pub final_proof_uri: String,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
-pub enum BondStatus {
-    Pending,              // Created, waiting for stake
-    Active,               // Staked, work in progress
-    PendingVerification,  // Proof submitted, awaiting votes
-    Completed,            // Work verified, stake released
-    Slashed,              // Work failed/scam, stake slashed
+#[event]
+pub struct DisputeEscalated {
+    pub bond_id: String,
+    pub raised_by: Pubkey,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct VerificationVote {
+#[event]
+pub struct FundsRecovered {
+    pub bond_id: String,
+    pub arbiter: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct VerifierPenalized {
+    pub bond_id: String,
     pub verifier: Pubkey,
-    pub approve: bool,
-    pub timestamp: i64,
+    pub stake_slashed: u64,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct SlashVote {
+#[event]
+pub struct TallyCommitted {
+    pub bond_id: String,
+    pub root: [u8; 32],
+    pub approve_count: u32,
+    pub total: u32,
+}
+
+#[event]
+pub struct TallySettled {
+    pub bond_id: String,
+    pub root: [u8; 32],
+    pub approve_count: u32,
+    pub total: u32,
+    pub proofs_checked: u32,
+    pub outcome: SettlementOutcome,
+}
+
+#[event]
+pub struct OracleSettled {
+    pub bond_id: String,
+    pub oracle: Pubkey,
+    pub outcome: SettlementOutcome,
+}
+
+#[event]
+pub struct BondExpired {
+    pub bond_id: String,
+    pub agent: Pubkey,
+    pub amount_refunded: u64,
+}
+
+#[event]
+pub struct BondSlashed {
+    pub bond_id: String,
+    pub agent: Pubkey,
+    pub principal: Pubkey,
+    pub amount_slashed: u64,
+    pub reason: SlashReason,
+    pub justification: String,        // principal's explanation, emergency slashes only
+}
+
+#[event]
+pub struct CallbackInvoked {
+    pub bond_id: String,
+    pub program_id: Pubkey,
+}
+
+#[event]
+pub struct ProtocolFeeCollected {
+    pub bond_id: String,
+    pub treasury: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SlashVoteCast {
+    pub bond_id: String,
     pub voter: Pubkey,
     pub reason: String,
-    pub timestamp: i64,
 }
 
-#[error_code]
-pub enum AgberoError {
-    #[msg("Description too long (max 500 chars)")]
-    DescriptionTooLong,
-    #[msg("Collateral too low (min 0.001 SOL)")]
-    CollateralTooLow,
-    #[msg("Invalid deadline")]
-    InvalidDeadline,
-    #[msg("Invalid bond status")]
-    InvalidBondStatus,
-    #[msg("Unauthorized agent")]
-    UnauthorizedAgent,
-    #[msg("Unauthorized principal")]
-    UnauthorizedPrincipal,
-    #[msg("Agent cannot verify own work")]
-    AgentCannotVerify,
-    #[msg("Deadline exceeded")]
-    DeadlineExceeded,
-    #[msg("Proof URI too long")]
-    ProofUriTooLong,
-    #[msg("Quorum not yet reached")]
-    QuorumNotReached,
+#[event]
+pub struct MilestoneApproved {
+    pub bond_id: String,
+    pub index: u8,
+    pub verifier: Pubkey,
 }
 
-// Events for indexing
 #[event]
-pub struct BondCreated {
+pub struct MilestoneReleased {
+    pub bond_id: String,
+    pub index: u8,
+    pub amount: u64,
+    pub total_released: u64,
+}
+
+#[event]
+pub struct PrincipalPremiumPaid {
     pub bond_id: String,
     pub principal: Pubkey,
+    pub premium: u64,
+}
+
+#[event]
+pub struct AgentPremiumPaid {
+    pub bond_id: String,
     pub agent: Pubkey,
-    pub collateral_amount: u64,
-    pub deadline: i64,
+    pub premium: u64,
 }
 
 #[event]
-pub struct CollateralStaked {
+pub struct WrongfulSlashRuled {
     pub bond_id: String,
     pub agent: Pubkey,
-    pub amount: u64,
+    pub compensation_due: u64,
 }
 
 #[event]
-pub struct ProofSubmitted {
+pub struct AgentCompensated {
     pub bond_id: String,
     pub agent: Pubkey,
-    pub proof_uri: String,
+    pub amount: u64,
+    pub remaining_due: u64,
 }
 
 #[event]
-pub struct WorkVerified {
+pub struct DeadlineExtended {
     pub bond_id: String,
-    pub verifier: Pubkey,
-    pub approve: bool,
+    pub old_deadline: i64,
+    pub new_deadline: i64,
+    pub extension_count: u8,
 }
 
 #[event]
-pub struct BondCompleted {
+pub struct DeadlineApproaching {
     pub bond_id: String,
     pub agent: Pubkey,
-    pub stake_released: u64,
+    pub deadline: i64,
+    pub remaining: i64,
 }
 
 #[event]
-pub struct BondSlashed {
+pub struct DeadlinePassed {
     pub bond_id: String,
     pub agent: Pubkey,
-    pub principal: Pubkey,
-    pub amount_slashed: u64,
+    pub deadline: i64,
+    pub overdue_by: i64,
+}
+
+#[event]
+pub struct VerifierRewardsPaid {
+    pub bond_id: String,
+    pub verifiers: u8,
+    pub share: u64,
+    pub dust: u64,
+    pub deferred: u8,
+}
+
+#[event]
+pub struct DeferredRewardClaimed {
+    pub bond_id: String,
+    pub verifier: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct KeeperPaid {
+    pub bond_id: String,
+    pub keeper: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct CircuitBreakerTripped {
+    pub window_slashes: u64,
+    pub window_start: i64,
+}
+
+#[event]
+pub struct ProgramPauseSet {
+    pub admin: Pubkey,
+    pub paused: bool,
+}
+
+#[event]
+pub struct CircuitBreakerReset {
+    pub admin: Pubkey,
 }
@@ -1,6 +1,7 @@
 import * as anchor from '@coral-xyz/anchor';
 import { Program, AnchorProvider, web3, BN } from '@coral-xyz/anchor';
 import { PublicKey, SystemProgram, Connection, Commitment } from '@solana/web3.js';
+import { keccak_256 } from '@noble/hashes/sha3';
 
 /**
  * Agbero SDK - The Enforcer
@@ -38,12 +39,62 @@ export enum BondStatus {
   Slashed = 4,
 }
 
+/**
+ * Per-bond settings passed to create_bond; see defaultBondOptions
+ */
+export interface BondOptions {
+  voteMode: Record<string, object>;
+  commitWindow: BN;
+  revealWindow: BN;
+  keeperBounty: BN;
+  voteWeighting: Record<string, object>;
+  callback: { programId: PublicKey; data: Buffer } | null;
+  insuredAmount: BN;
+  requireBeginWork: boolean;
+  installments: number;
+  installmentInterval: BN;
+  appealWindow: BN;
+  category: number;
+  escrowAuthority: PublicKey;
+  milestones: { description: string; amount: BN }[];
+  syndicate: PublicKey[];
+  slashDestination: Record<string, object>;
+  rubric: string;
+}
+
+/**
+ * Public vote, no extras, slashes paid to the principal
+ */
+export function defaultBondOptions(overrides: Partial<BondOptions> = {}): BondOptions {
+  return {
+    voteMode: { public: {} },
+    commitWindow: new BN(0),
+    revealWindow: new BN(0),
+    keeperBounty: new BN(0),
+    voteWeighting: { equal: {} },
+    callback: null,
+    insuredAmount: new BN(0),
+    requireBeginWork: false,
+    installments: 0,
+    installmentInterval: new BN(0),
+    appealWindow: new BN(1),
+    category: 0,
+    escrowAuthority: PublicKey.default,
+    milestones: [],
+    syndicate: [],
+    slashDestination: { principal: {} },
+    rubric: '',
+    ...overrides,
+  };
+}
+
 export interface CreateBondParams {
   bondId: string;
   agent: PublicKey;
   taskDescription: string;
   collateralAmount: BN;
   deadline: BN; // Unix timestamp
+  options?: BondOptions; // defaultBondOptions() when omitted
 }
 
 export class AgberoClient {
@@ -80,6 +131,69 @@ export class AgberoClient {
     );
   }
 
+  /**
+   * Derive a global PDA (config, stats) or an agent's reputation PDA
+   */
+  getConfigPDA(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync([Buffer.from('config')], this.program.programId);
+  }
+
+  getStatsPDA(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync([Buffer.from('stats')], this.program.programId);
+  }
+
+  getReputationPDA(agent: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('reputation'), agent.toBuffer()],
+      this.program.programId
+    );
+  }
+
+  getInsurancePoolPDA(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('insurance_pool')],
+      this.program.programId
+    );
+  }
+
+  /**
+   * Derive the cooldown record for a principal/agent pair
+   */
+  getPairPDA(principal: PublicKey, agent: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('pair'), principal.toBuffer(), agent.toBuffer()],
+      this.program.programId
+    );
+  }
+
+  /**
+   * Derive the reservation PDA, keyed by keccak(bondId) so reserving does
+   * not reveal the id
+   */
+  getReservationPDA(bondId: string): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('reservation'), Buffer.from(keccak_256(Buffer.from(bondId)))],
+      this.program.programId
+    );
+  }
+
+  /**
+   * Derive a verifier's registration and their stake on one bond
+   */
+  getVerifierProfilePDA(verifier: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('verifier'), verifier.toBuffer()],
+      this.program.programId
+    );
+  }
+
+  getVerifierStakePDA(bondPDA: PublicKey, verifier: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('verifier_stake'), bondPDA.toBuffer(), verifier.toBuffer()],
+      this.program.programId
+    );
+  }
+
   /**
    * Create a new performance bond
    */
@@ -87,18 +201,26 @@ export class AgberoClient {
     const [bondPDA] = this.getBondPDA(params.bondId);
     const [bondVaultPDA] = this.getBondVaultPDA(bondPDA);
 
+    const principal = this.provider.wallet.publicKey;
+
     const tx = await this.program.methods
       .createBond(
         params.bondId,
         params.taskDescription,
         params.collateralAmount,
-        params.deadline
+        params.deadline,
+        params.options ?? defaultBondOptions()
       )
       .accounts({
-        principal: this.provider.wallet.publicKey,
+        principal,
         agent: params.agent,
         bond: bondPDA,
         bondVault: bondVaultPDA,
+        stats: this.getStatsPDA()[0],
+        config: this.getConfigPDA()[0],
+        reputation: this.getReputationPDA(params.agent)[0],
+        pair: this.getPairPDA(principal, params.agent)[0],
+        reservation: this.getReservationPDA(params.bondId)[0],
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -119,6 +241,9 @@ export class AgberoClient {
         agent: this.provider.wallet.publicKey,
         bond: bondPDA,
         bondVault: bondVaultPDA,
+        stats: this.getStatsPDA()[0],
+        config: this.getConfigPDA()[0],
+        stakingVault: null,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -137,6 +262,7 @@ export class AgberoClient {
       .accounts({
         agent: this.provider.wallet.publicKey,
         bond: bondPDA,
+        config: this.getConfigPDA()[0],
       })
       .rpc();
 
@@ -144,17 +270,23 @@ export class AgberoClient {
   }
 
   /**
-   * Verifier votes on bond completion
+   * Verifier votes on bond completion. The verifier must be registered;
+   * the vote carries their stake and profile accounts
    */
   async verifyWork(bondId: string, approve: boolean): Promise<string> {
     const [bondPDA] = this.getBondPDA(bondId);
+    const verifier = this.provider.wallet.publicKey;
 
     const tx = await this.program.methods
       .verifyWork(approve)
       .accounts({
-        verifier: this.provider.wallet.publicKey,
+        verifier,
         bond: bondPDA,
       })
+      .remainingAccounts([
+        { pubkey: this.getVerifierStakePDA(bondPDA, verifier)[0], isSigner: false, isWritable: false },
+        { pubkey: this.getVerifierProfilePDA(verifier)[0], isSigner: false, isWritable: true },
+      ])
       .rpc();
 
     return tx;
@@ -177,6 +309,10 @@ export class AgberoClient {
         bondVault: bondVaultPDA,
         agent: bond.agent,
         principal: bond.principal,
+        stats: this.getStatsPDA()[0],
+        config: this.getConfigPDA()[0],
+        reputation: this.getReputationPDA(bond.agent)[0],
+        insurancePool: this.getInsurancePoolPDA()[0],
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -185,20 +321,43 @@ export class AgberoClient {
   }
 
   /**
-   * Emergency slash by principal
+   * Principal requests an emergency slash, opening the bond's appeal window
+   */
+  async requestEmergencySlash(bondId: string, reason: string): Promise<string> {
+    const [bondPDA] = this.getBondPDA(bondId);
+    const [configPDA] = this.getConfigPDA();
+
+    const tx = await this.program.methods
+      .requestEmergencySlash(reason)
+      .accounts({
+        principal: this.provider.wallet.publicKey,
+        bond: bondPDA,
+        config: configPDA,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Principal executes a requested emergency slash once the appeal window
+   * has passed or the agent acknowledged it
    */
-  async emergencySlash(bondId: string): Promise<string> {
+  async executeEmergencySlash(bondId: string): Promise<string> {
     const [bondPDA] = this.getBondPDA(bondId);
     const [bondVaultPDA] = this.getBondVaultPDA(bondPDA);
     const bond = await this.getBond(bondId);
 
     const tx = await this.program.methods
-      .emergencySlash()
+      .executeEmergencySlash()
       .accounts({
         principal: this.provider.wallet.publicKey,
         bond: bondPDA,
         bondVault: bondVaultPDA,
         principalVault: bond.principal,
+        stats: this.getStatsPDA()[0],
+        config: this.getConfigPDA()[0],
+        reputation: this.getReputationPDA(bond.agent)[0],
         systemProgram: SystemProgram.programId,
       })
       .rpc();
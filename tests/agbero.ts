@@ -2,7 +2,24 @@ import * as anchor from '@coral-xyz/anchor';
 import { Program } from '@coral-xyz/anchor';
 import { Agbero } from '../target/types/agbero';
 import { expect } from 'chai';
-import { PublicKey, SystemProgram, LAMPORTS_PER_SOL } from '@solana/web3.js';
+import {
+  PublicKey,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  Keypair,
+  Transaction,
+  Ed25519Program,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  SYSVAR_SLOT_HASHES_PUBKEY,
+} from '@solana/web3.js';
+import { keccak_256 } from '@noble/hashes/sha3';
+import {
+  TOKEN_PROGRAM_ID,
+  createMint,
+  getAccount,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token';
 
 describe('Agbero - Performance Bonds', () => {
   const provider = anchor.AnchorProvider.env();
@@ -17,11 +34,347 @@ describe('Agbero - Performance Bonds', () => {
   const verifier2 = anchor.web3.Keypair.generate();
   const verifier3 = anchor.web3.Keypair.generate();
 
+  const [statsPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from('stats')],
+    program.programId
+  );
+
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from('config')],
+    program.programId
+  );
+
+  const [reputationPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from('reputation'), agent.publicKey.toBuffer()],
+    program.programId
+  );
+
+  const [pairPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from('pair'), principal.publicKey.toBuffer(), agent.publicKey.toBuffer()],
+    program.programId
+  );
+
+  const [insurancePoolPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from('insurance_pool')],
+    program.programId
+  );
+
+  // Default protocol parameters; individual tests override and restore
+  const configParams = (overrides: Record<string, unknown> = {}) => ({
+    breakerWindow: new anchor.BN(3600),
+    breakerMaxSlashes: new anchor.BN(100),
+    proofGrace: new anchor.BN(0),
+    reputationHalfLife: new anchor.BN(30 * 86400),
+    timeoutGrace: new anchor.BN(86400),
+    noProofOutcome: { slash: {} } as Record<string, object>,
+    oracle: PublicKey.default,
+    stakeWindow: new anchor.BN(7 * 86400),
+    arbiter: PublicKey.default,
+    rewardMint: PublicKey.default,
+    verificationDuration: new anchor.BN(0),
+    insuranceBps: 0,
+    bondCooldown: new anchor.BN(0),
+    assignmentWindow: new anchor.BN(0),
+    maxVerifiers: new anchor.BN(0),
+    partialSlashBps: 0,
+    legalAuthority: PublicKey.default,
+    maxAppealWindow: new anchor.BN(86400),
+    feeBps: 0,
+    treasury: PublicKey.default,
+    deadlineWarningWindow: new anchor.BN(0),
+    autoCloseOnFinalize: false,
+    agentPremiumBps: 0,
+    verifierRewardBps: 0,
+    deadlineBaseDuration: new anchor.BN(0),
+    minVotes: new anchor.BN(3),
+    majorityNum: new anchor.BN(2),
+    majorityDen: new anchor.BN(3),
+    quorumStep: new anchor.BN(0),
+    stakeForfeitBps: 0,
+    principalPremiumBps: 0,
+    minAppealWindow: new anchor.BN(1),
+    subcommitteeSize: new anchor.BN(2),
+    agentCoverMultiple: 0,
+    governanceProgram: PublicKey.default,
+    ...overrides,
+  });
+
+  const updateConfig = (overrides: Record<string, unknown> = {}) =>
+    program.methods
+      .updateConfig(configParams(overrides))
+      .accounts({ admin: provider.wallet.publicKey, config: configPDA })
+      .rpc();
+
   const bondId = 'test-bond-001';
   let bondPDA: PublicKey;
   let bondVaultPDA: PublicKey;
   let bump: number;
 
+  const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+  // Rent reserve the principal pre-funds into every bond vault
+  const vaultRent = () => provider.connection.getMinimumBalanceForRentExemption(0);
+
+  // Default create_bond options: public voting
+  const bondOptions = (overrides: Record<string, unknown> = {}) => ({
+    voteMode: { public: {} } as Record<string, object>,
+    commitWindow: new anchor.BN(0),
+    revealWindow: new anchor.BN(0),
+    keeperBounty: new anchor.BN(0),
+    voteWeighting: { equal: {} } as Record<string, object>,
+    callback: null as { programId: PublicKey; data: Buffer } | null,
+    insuredAmount: new anchor.BN(0),
+    requireBeginWork: false,
+    installments: 0,
+    installmentInterval: new anchor.BN(0),
+    appealWindow: new anchor.BN(1),
+    category: 0,
+    escrowAuthority: PublicKey.default,
+    milestones: [] as { description: string; amount: anchor.BN }[],
+    syndicate: [] as PublicKey[],
+    slashDestination: { principal: {} } as Record<string, object>,
+    rubric: '',
+    ...overrides,
+  });
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err.error?.errorCode?.code ?? err.toString()).to.include(code);
+    }
+  };
+
+  const deriveBond = (id: string): [PublicKey, PublicKey] => {
+    const [bond] = PublicKey.findProgramAddressSync(
+      [Buffer.from('bond'), Buffer.from(id)],
+      program.programId
+    );
+    const [vault] = PublicKey.findProgramAddressSync(
+      [Buffer.from('bond_vault'), bond.toBuffer()],
+      program.programId
+    );
+    return [bond, vault];
+  };
+
+  const deriveReservation = (id: string): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('reservation'), Buffer.from(keccak_256(Buffer.from(id)))],
+      program.programId
+    )[0];
+
+  const createBond = async (
+    id: string,
+    {
+      options = bondOptions(),
+      collateral = new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+      deadline = Math.floor(Date.now() / 1000) + 86400,
+    }: { options?: ReturnType<typeof bondOptions>; collateral?: anchor.BN; deadline?: number } = {}
+  ): Promise<[PublicKey, PublicKey]> => {
+    const [bond, vault] = deriveBond(id);
+
+    await program.methods
+      .createBond(id, 'Helper task', collateral, new anchor.BN(deadline), options)
+      .accounts({
+        principal: principal.publicKey,
+        agent: agent.publicKey,
+        bond,
+        bondVault: vault,
+        stats: statsPDA,
+        config: configPDA,
+        reputation: reputationPDA,
+        pair: pairPDA,
+        reservation: deriveReservation(id),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([principal])
+      .rpc();
+
+    return [bond, vault];
+  };
+
+  const stakeBond = (bond: PublicKey, vault: PublicKey) =>
+    program.methods
+      .stakeCollateral()
+      .accounts({
+        agent: agent.publicKey,
+        bond,
+        bondVault: vault,
+        stats: statsPDA,
+        config: configPDA,
+        stakingVault: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent])
+      .rpc();
+
+  const submitProof = (bond: PublicKey, uri: string) =>
+    program.methods
+      .submitProof(uri)
+      .accounts({ agent: agent.publicKey, bond, config: configPDA })
+      .signers([agent])
+      .rpc();
+
+  const deriveStake = (bond: PublicKey, verifier: PublicKey): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('verifier_stake'), bond.toBuffer(), verifier.toBuffer()],
+      program.programId
+    )[0];
+
+  // Every vote carries the voter's stake address, staked or not
+  // Stake and profile accounts every recorded vote names
+  const voterAccounts = (bond: PublicKey, verifier: PublicKey) => [
+    { pubkey: deriveStake(bond, verifier), isSigner: false, isWritable: false },
+    { pubkey: deriveProfile(verifier), isSigner: false, isWritable: true },
+  ];
+
+  // Registers the voter on first use
+  const vote = async (bond: PublicKey, verifier: Keypair, approve: boolean) => {
+    await ensureRegistered(verifier);
+    return program.methods
+      .verifyWork(approve)
+      .accounts({ verifier: verifier.publicKey, bond })
+      .remainingAccounts(voterAccounts(bond, verifier.publicKey))
+      .signers([verifier])
+      .rpc();
+  };
+
+  const finalizeAccounts = (bond: PublicKey, vault: PublicKey, executor = verifier1.publicKey) => ({
+    executor,
+    bond,
+    bondVault: vault,
+    agent: agent.publicKey,
+    principal: principal.publicKey,
+    stats: statsPDA,
+    config: configPDA,
+    reputation: reputationPDA,
+    insurancePool: insurancePoolPDA,
+    systemProgram: SystemProgram.programId,
+  });
+
+  const finalize = (bond: PublicKey, vault: PublicKey, executor = verifier1) =>
+    program.methods
+      .finalizeBond()
+      .accounts(finalizeAccounts(bond, vault, executor.publicKey))
+      .signers([executor])
+      .rpc();
+
+  const deriveProfile = (verifier: PublicKey): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('verifier'), verifier.toBuffer()],
+      program.programId
+    )[0];
+
+  // Emergency slash the agent concedes, so it can execute inside the window
+  const concedeSlash = async (bond: PublicKey, signer = principal) => {
+    await program.methods
+      .requestEmergencySlash('Agent went silent')
+      .accounts({ principal: signer.publicKey, bond, config: configPDA })
+      .signers([signer])
+      .rpc();
+    await program.methods
+      .acknowledgeSlash()
+      .accounts({ agent: agent.publicKey, bond })
+      .signers([agent])
+      .rpc();
+  };
+
+  const executeSlash = (bond: PublicKey, vault: PublicKey, signer = principal) =>
+    program.methods
+      .executeEmergencySlash()
+      .accounts({
+        principal: signer.publicKey,
+        bond,
+        bondVault: vault,
+        principalVault: signer.publicKey,
+        stats: statsPDA,
+        config: configPDA,
+        reputation: reputationPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc();
+
+  // Every registered verifier's keypair, for tests acting as drawn members
+  const registered = new Map<string, Keypair>();
+
+  const registerVerifier = async (verifier: Keypair) => {
+    await program.methods
+      .registerVerifier()
+      .accounts({
+        verifier: verifier.publicKey,
+        profile: deriveProfile(verifier.publicKey),
+        stats: statsPDA,
+        config: configPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([verifier])
+      .rpc();
+    registered.set(verifier.publicKey.toBase58(), verifier);
+  };
+
+  // Register unless an earlier test already did
+  // Fresh keypairs are funded first, since the verifier pays the profile rent
+  const ensureRegistered = async (verifier: Keypair) => {
+    if (await provider.connection.getAccountInfo(deriveProfile(verifier.publicKey))) {
+      return;
+    }
+    if ((await provider.connection.getBalance(verifier.publicKey)) < LAMPORTS_PER_SOL / 10) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(verifier.publicKey, LAMPORTS_PER_SOL)
+      );
+    }
+    await registerVerifier(verifier);
+  };
+
+  // Every registered profile, so whichever verifiers a draw picks are present
+  const profileAccounts = async () =>
+    (await program.account.verifierProfile.all()).map(p => ({
+      pubkey: p.publicKey,
+      isSigner: false,
+      isWritable: false,
+    }));
+
+  const requestSubcommittee = (bond: PublicKey, party = principal) =>
+    program.methods
+      .requestSubcommittee()
+      .accounts({ party: party.publicKey, bond, config: configPDA })
+      .signers([party])
+      .rpc();
+
+  const drawSubcommittee = async (bond: PublicKey) =>
+    program.methods
+      .formSubcommittee()
+      .accounts({
+        caller: provider.wallet.publicKey,
+        bond,
+        config: configPDA,
+        stats: statsPDA,
+        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+      })
+      .remainingAccounts(await profileAccounts())
+      .rpc();
+
+  // Request a draw, wait out the draw slot, then draw
+  const formSubcommittee = async (bond: PublicKey) => {
+    await requestSubcommittee(bond);
+    await sleep(2000);
+    await drawSubcommittee(bond);
+  };
+
+  // Create, stake and submit proof for a fresh bond
+  const setupBond = async (
+    id: string,
+    options = bondOptions(),
+    collateral = new anchor.BN(0.1 * LAMPORTS_PER_SOL)
+  ): Promise<[PublicKey, PublicKey]> => {
+    const [bond, vault] = await createBond(id, { options, collateral });
+    await stakeBond(bond, vault);
+    await submitProof(bond, `https://example.com/proof/${id}`);
+    return [bond, vault];
+  };
+
   before(async () => {
     // Airdrop SOL to test accounts
     const signatures = await Promise.all([
@@ -44,6 +397,28 @@ describe('Agbero - Performance Bonds', () => {
       [Buffer.from('bond_vault'), bondPDA.toBuffer()],
       program.programId
     );
+
+    await program.methods
+      .initializeStats()
+      .accounts({
+        payer: provider.wallet.publicKey,
+        stats: statsPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await program.methods
+      .initializeConfig(configParams())
+      .accounts({
+        admin: provider.wallet.publicKey,
+        config: configPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    for (const verifier of [verifier1, verifier2, verifier3]) {
+      await registerVerifier(verifier);
+    }
   });
 
   it('Creates a bond', async () => {
@@ -55,13 +430,19 @@ describe('Agbero - Performance Bonds', () => {
         bondId,
         'Build a Solana escrow program',
         collateralAmount,
-        new anchor.BN(deadline)
+        new anchor.BN(deadline),
+        bondOptions()
       )
       .accounts({
         principal: principal.publicKey,
         agent: agent.publicKey,
         bond: bondPDA,
         bondVault: bondVaultPDA,
+        stats: statsPDA,
+        config: configPDA,
+        reputation: reputationPDA,
+        pair: pairPDA,
+        reservation: deriveReservation(bondId),
         systemProgram: SystemProgram.programId,
       })
       .signers([principal])
@@ -86,6 +467,9 @@ describe('Agbero - Performance Bonds', () => {
         agent: agent.publicKey,
         bond: bondPDA,
         bondVault: bondVaultPDA,
+        stats: statsPDA,
+        config: configPDA,
+        stakingVault: null,
         systemProgram: SystemProgram.programId,
       })
       .signers([agent])
@@ -106,13 +490,15 @@ describe('Agbero - Performance Bonds', () => {
       .accounts({
         agent: agent.publicKey,
         bond: bondPDA,
+        config: configPDA,
       })
       .signers([agent])
       .rpc();
 
     const bond = await program.account.bond.fetch(bondPDA);
     
-    expect(bond.proofUri).to.equal(proofUri);
+    expect(bond.proofs.length).to.equal(1);
+    expect(bond.proofs[0].uri).to.equal(proofUri);
     expect(bond.status).to.deep.equal({ pendingVerification: {} });
   });
 
@@ -124,6 +510,7 @@ describe('Agbero - Performance Bonds', () => {
         verifier: verifier1.publicKey,
         bond: bondPDA,
       })
+      .remainingAccounts(voterAccounts(bondPDA, verifier1.publicKey))
       .signers([verifier1])
       .rpc();
 
@@ -134,6 +521,7 @@ describe('Agbero - Performance Bonds', () => {
         verifier: verifier2.publicKey,
         bond: bondPDA,
       })
+      .remainingAccounts(voterAccounts(bondPDA, verifier2.publicKey))
       .signers([verifier2])
       .rpc();
 
@@ -144,6 +532,7 @@ describe('Agbero - Performance Bonds', () => {
         verifier: verifier3.publicKey,
         bond: bondPDA,
       })
+      .remainingAccounts(voterAccounts(bondPDA, verifier3.publicKey))
       .signers([verifier3])
       .rpc();
 
@@ -164,6 +553,10 @@ describe('Agbero - Performance Bonds', () => {
         bondVault: bondVaultPDA,
         agent: agent.publicKey,
         principal: principal.publicKey,
+        stats: statsPDA,
+        config: configPDA,
+        reputation: reputationPDA,
+        insurancePool: insurancePoolPDA,
         systemProgram: SystemProgram.programId,
       })
       .signers([verifier1])
@@ -198,13 +591,19 @@ describe('Agbero - Performance Bonds', () => {
         fraudBondId,
         'Fraudulent task',
         collateralAmount,
-        new anchor.BN(deadline)
+        new anchor.BN(deadline),
+        bondOptions()
       )
       .accounts({
         principal: principal.publicKey,
         agent: agent.publicKey,
         bond: fraudBondPDA,
         bondVault: fraudVaultPDA,
+        stats: statsPDA,
+        config: configPDA,
+        reputation: reputationPDA,
+        pair: pairPDA,
+        reservation: deriveReservation(fraudBondId),
         systemProgram: SystemProgram.programId,
       })
       .signers([principal])
@@ -217,6 +616,9 @@ describe('Agbero - Performance Bonds', () => {
         agent: agent.publicKey,
         bond: fraudBondPDA,
         bondVault: fraudVaultPDA,
+        stats: statsPDA,
+        config: configPDA,
+        stakingVault: null,
         systemProgram: SystemProgram.programId,
       })
       .signers([agent])
@@ -228,6 +630,7 @@ describe('Agbero - Performance Bonds', () => {
       .accounts({
         agent: agent.publicKey,
         bond: fraudBondPDA,
+        config: configPDA,
       })
       .signers([agent])
       .rpc();
@@ -239,6 +642,7 @@ describe('Agbero - Performance Bonds', () => {
         verifier: verifier1.publicKey,
         bond: fraudBondPDA,
       })
+      .remainingAccounts(voterAccounts(fraudBondPDA, verifier1.publicKey))
       .signers([verifier1])
       .rpc();
 
@@ -248,6 +652,7 @@ describe('Agbero - Performance Bonds', () => {
         verifier: verifier2.publicKey,
         bond: fraudBondPDA,
       })
+      .remainingAccounts(voterAccounts(fraudBondPDA, verifier2.publicKey))
       .signers([verifier2])
       .rpc();
 
@@ -257,6 +662,7 @@ describe('Agbero - Performance Bonds', () => {
         verifier: verifier3.publicKey,
         bond: fraudBondPDA,
       })
+      .remainingAccounts(voterAccounts(fraudBondPDA, verifier3.publicKey))
       .signers([verifier3])
       .rpc();
 
@@ -271,6 +677,10 @@ describe('Agbero - Performance Bonds', () => {
         bondVault: fraudVaultPDA,
         agent: agent.publicKey,
         principal: principal.publicKey,
+        stats: statsPDA,
+        config: configPDA,
+        reputation: reputationPDA,
+        insurancePool: insurancePoolPDA,
         systemProgram: SystemProgram.programId,
       })
       .signers([verifier1])
@@ -280,6 +690,4528 @@ describe('Agbero - Performance Bonds', () => {
     const principalBalanceAfter = await provider.connection.getBalance(principal.publicKey);
 
     expect(fraudBond.status).to.deep.equal({ slashed: {} });
+    expect(fraudBond.slashReason).to.deep.equal({ majorityVote: {} });
     expect(principalBalanceAfter).to.be.greaterThan(principalBalanceBefore);
   });
+
+  it('Counts only reveals matching their commitment', async () => {
+    const commitment = (approve: boolean, salt: Buffer, verifier: PublicKey) =>
+      Array.from(keccak_256(Buffer.concat([Buffer.from([approve ? 1 : 0]), salt, verifier.toBuffer()])));
+
+    const [crBond] = await setupBond(
+      'commit-reveal-001',
+      bondOptions({
+        voteMode: { blind: {} },
+        commitWindow: new anchor.BN(3),
+        revealWindow: new anchor.BN(60),
+      })
+    );
+
+    // Public votes are rejected on commit-reveal bonds
+    await expectError(
+      program.methods
+        .verifyWork(true)
+        .accounts({ verifier: verifier1.publicKey, bond: crBond })
+        .remainingAccounts(voterAccounts(crBond, verifier1.publicKey))
+        .signers([verifier1])
+        .rpc(),
+      'PublicVotingDisabled'
+    );
+
+    const salt1 = Buffer.alloc(32, 1);
+    const salt2 = Buffer.alloc(32, 2);
+
+    await program.methods
+      .commitVote(commitment(true, salt1, verifier1.publicKey))
+      .accounts({ verifier: verifier1.publicKey, bond: crBond })
+      .remainingAccounts(voterAccounts(crBond, verifier1.publicKey))
+      .signers([verifier1])
+      .rpc();
+
+    await program.methods
+      .commitVote(commitment(false, salt2, verifier2.publicKey))
+      .accounts({ verifier: verifier2.publicKey, bond: crBond })
+      .remainingAccounts(voterAccounts(crBond, verifier2.publicKey))
+      .signers([verifier2])
+      .rpc();
+
+    // Reveals are not accepted during the commit phase
+    await expectError(
+      program.methods
+        .revealVote(true, Array.from(salt1))
+        .accounts({ verifier: verifier1.publicKey, bond: crBond })
+        .remainingAccounts(voterAccounts(crBond, verifier1.publicKey))
+        .signers([verifier1])
+        .rpc(),
+      'RevealPhaseNotActive'
+    );
+
+    await sleep(5000);
+
+    await program.methods
+      .revealVote(true, Array.from(salt1))
+      .accounts({ verifier: verifier1.publicKey, bond: crBond })
+      .remainingAccounts(voterAccounts(crBond, verifier1.publicKey))
+      .signers([verifier1])
+      .rpc();
+
+    // Verifier 2 tries to flip their committed vote
+    await expectError(
+      program.methods
+        .revealVote(true, Array.from(salt2))
+        .accounts({ verifier: verifier2.publicKey, bond: crBond })
+        .remainingAccounts(voterAccounts(crBond, verifier2.publicKey))
+        .signers([verifier2])
+        .rpc(),
+      'CommitMismatch'
+    );
+
+    const bond = await program.account.bond.fetch(crBond);
+    expect(bond.verificationVotes.length).to.equal(1);
+    expect(bond.verificationVotes[0].verifier.toBase58()).to.equal(verifier1.publicKey.toBase58());
+    expect(bond.voteCommits.filter(c => c.revealed).length).to.equal(1);
+    expect(bond.revealDeadline.toNumber()).to.be.greaterThan(bond.commitDeadline.toNumber());
+  });
+
+  it('Reports protocol health metrics from stats', async () => {
+    const stats = await program.account.stats.fetch(statsPDA);
+    const health = await program.methods
+      .protocolHealth()
+      .accounts({ stats: statsPDA })
+      .view();
+
+    const finalized = stats.bondsCompleted.add(stats.bondsSlashed);
+    expect(stats.bondsCompleted.toNumber()).to.be.greaterThan(0);
+    expect(stats.bondsSlashed.toNumber()).to.be.greaterThan(0);
+    expect(health.slashRateBps.toNumber()).to.equal(
+      stats.bondsSlashed.muln(10_000).div(finalized).toNumber()
+    );
+    expect(health.averageBondSize.toNumber()).to.equal(
+      stats.totalCollateralStaked.div(stats.bondsStaked).toNumber()
+    );
+    expect(health.totalValueLocked.toNumber()).to.equal(stats.totalValueLocked.toNumber());
+    // The commit-reveal bond is still awaiting votes
+    expect(health.activeBonds.toNumber()).to.equal(1);
+    expect(health.totalValueLocked.toNumber()).to.equal(0.1 * LAMPORTS_PER_SOL);
+  });
+
+  it('Refunds excess collateral after a mutually agreed reduction', async () => {
+    const [reduceBond, reduceVault] = await setupBond('reduce-001');
+    const agentBalanceBefore = await provider.connection.getBalance(agent.publicKey);
+    const newAmount = new anchor.BN(0.04 * LAMPORTS_PER_SOL);
+
+    // The principal alone cannot lower the requirement
+    const attacker = anchor.web3.Keypair.generate();
+    await expectError(
+      program.methods
+        .reduceCollateral(newAmount)
+        .accounts({
+          principal: principal.publicKey,
+          agent: attacker.publicKey,
+          bond: reduceBond,
+          bondVault: reduceVault,
+          stats: statsPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([principal, attacker])
+        .rpc(),
+      'UnauthorizedAgent'
+    );
+
+    await program.methods
+      .reduceCollateral(newAmount)
+      .accounts({
+        principal: principal.publicKey,
+        agent: agent.publicKey,
+        bond: reduceBond,
+        bondVault: reduceVault,
+        stats: statsPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([principal, agent])
+      .rpc();
+
+    const bond = await program.account.bond.fetch(reduceBond);
+    const agentBalanceAfter = await provider.connection.getBalance(agent.publicKey);
+
+    expect(bond.collateralAmount.toNumber()).to.equal(newAmount.toNumber());
+    expect(agentBalanceAfter - agentBalanceBefore).to.equal(0.06 * LAMPORTS_PER_SOL);
+  });
+
+  it('Trips the circuit breaker on abnormal slash volume', async () => {
+    const bonds = [
+      await setupBond('breaker-001'),
+      await setupBond('breaker-002'),
+      await setupBond('breaker-003'),
+    ];
+    for (const [bond] of bonds) {
+      await concedeSlash(bond);
+    }
+
+    // Start a clean window with a two-slash threshold
+    await program.methods
+      .resetCircuitBreaker()
+      .accounts({ admin: provider.wallet.publicKey, config: configPDA, stats: statsPDA })
+      .rpc();
+    await updateConfig({ breakerMaxSlashes: new anchor.BN(2) });
+
+    await executeSlash(...bonds[0]);
+    await executeSlash(...bonds[1]);
+
+    let config = await program.account.config.fetch(configPDA);
+    expect(config.paused).to.equal(true);
+
+    await expectError(executeSlash(...bonds[2]), 'ProgramPaused');
+
+    // Admin reset lifts the pause
+    await program.methods
+      .resetCircuitBreaker()
+      .accounts({ admin: provider.wallet.publicKey, config: configPDA, stats: statsPDA })
+      .rpc();
+    await updateConfig();
+
+    config = await program.account.config.fetch(configPDA);
+    expect(config.paused).to.equal(false);
+
+    await executeSlash(...bonds[2]);
+    const bond = await program.account.bond.fetch(bonds[2][0]);
+    expect(bond.status).to.deep.equal({ slashed: {} });
+    expect(bond.slashReason).to.deep.equal({ emergencyPrincipal: {} });
+  });
+
+  it('Accumulates resubmitted proofs in order', async () => {
+    const [proofBond] = await setupBond('proofs-001');
+    const revisedUri = 'https://example.com/proof/proofs-001/v2';
+
+    await submitProof(proofBond, revisedUri);
+
+    const bond = await program.account.bond.fetch(proofBond);
+    expect(bond.proofs.length).to.equal(2);
+    expect(bond.proofs[0].uri).to.equal('https://example.com/proof/proofs-001');
+    expect(bond.proofs[1].uri).to.equal(revisedUri);
+    expect(bond.proofs[1].submittedAt.toNumber()).to.be.at.least(bond.proofs[0].submittedAt.toNumber());
+    expect(bond.status).to.deep.equal({ pendingVerification: {} });
+  });
+
+  it('Accepts late proof only within the configured grace', async () => {
+    const deadline = Math.floor(Date.now() / 1000) + 3;
+    const [lateBond, lateVault] = await createBond('late-proof-001', { deadline });
+    await stakeBond(lateBond, lateVault);
+
+    await sleep(6000);
+
+    // Late proof is never accepted past the point the bond times out
+    await expectError(
+      updateConfig({ proofGrace: new anchor.BN(120), timeoutGrace: new anchor.BN(60) }),
+      'InvalidConfig'
+    );
+
+    // Past the deadline and beyond a 1 second grace
+    await updateConfig({ proofGrace: new anchor.BN(1) });
+    await expectError(submitProof(lateBond, 'https://example.com/late'), 'DeadlineExceeded');
+
+    // Within a 2 minute grace the proof lands but is flagged late
+    await updateConfig({ proofGrace: new anchor.BN(120) });
+    await submitProof(lateBond, 'https://example.com/late');
+    await updateConfig();
+
+    const bond = await program.account.bond.fetch(lateBond);
+    expect(bond.proofs.length).to.equal(1);
+    expect(bond.proofs[0].late).to.equal(true);
+    expect(bond.status).to.deep.equal({ pendingVerification: {} });
+  });
+
+  it('Restricts voting to a sub-committee drawn from registered verifiers', async () => {
+    const [committeeBond, committeeVault] = await setupBond('subcommittee-001');
+    const verifiers = [verifier1, verifier2, verifier3];
+    for (const verifier of verifiers) {
+      await ensureRegistered(verifier);
+    }
+
+    await expectError(drawSubcommittee(committeeBond), 'SubcommitteeNotRequested');
+    await expectError(requestSubcommittee(committeeBond, verifier1), 'NotBondParty');
+    await requestSubcommittee(committeeBond);
+    await expectError(requestSubcommittee(committeeBond, agent), 'SubcommitteeAlreadyRequested');
+    await sleep(2000);
+    await drawSubcommittee(committeeBond);
+
+    // Formed once: neither party can ask for a fresh draw
+    await expectError(requestSubcommittee(committeeBond), 'SubcommitteeAlreadyFormed');
+    await expectError(drawSubcommittee(committeeBond), 'SubcommitteeAlreadyFormed');
+
+    const bond = await program.account.bond.fetch(committeeBond);
+    expect(bond.subcommittee.length).to.equal(2);
+    expect(bond.subcommittee.every(m => registered.has(m.toBase58()))).to.be.true;
+
+    const members = bond.subcommittee.map(m => m.toBase58());
+    const member = verifiers.find(v => members.includes(v.publicKey.toBase58()));
+    const outsider = verifiers.find(v => !members.includes(v.publicKey.toBase58()));
+
+    await expectError(
+      program.methods
+        .verifyWork(true)
+        .accounts({ verifier: outsider.publicKey, bond: committeeBond })
+        .remainingAccounts(voterAccounts(committeeBond, outsider.publicKey))
+        .signers([outsider])
+        .rpc(),
+      'NotInSubcommittee'
+    );
+
+    await program.methods
+      .verifyWork(true)
+      .accounts({ verifier: member.publicKey, bond: committeeBond })
+      .remainingAccounts(voterAccounts(committeeBond, member.publicKey))
+      .signers([member])
+      .rpc();
+
+    const after = await program.account.bond.fetch(committeeBond);
+    expect(after.verificationVotes.length).to.equal(1);
+
+    // Two members cannot reach the configured three votes; the quorum
+    // shrinks to the sub-committee instead of timing the bond out
+    const second = verifiers.find(
+      v => v !== member && members.includes(v.publicKey.toBase58())
+    );
+    await program.methods
+      .verifyWork(true)
+      .accounts({ verifier: second.publicKey, bond: committeeBond })
+      .remainingAccounts(voterAccounts(committeeBond, second.publicKey))
+      .signers([second])
+      .rpc();
+    await finalize(committeeBond, committeeVault);
+    const finalized = await program.account.bond.fetch(committeeBond);
+    expect(finalized.status).to.deep.equal({ completed: {} });
+  });
+
+  it('Pays the keeper bounty only after every payout succeeds', async () => {
+    const bounty = new anchor.BN(0.01 * LAMPORTS_PER_SOL);
+    const [keeperBond, keeperVault] = await setupBond(
+      'keeper-001',
+      bondOptions({ keeperBounty: bounty })
+    );
+    await vote(keeperBond, verifier1, true);
+    await vote(keeperBond, verifier2, true);
+    await vote(keeperBond, verifier3, true);
+
+    const keeperBefore = await provider.connection.getBalance(verifier1.publicKey);
+
+    // Simulate a recipient that cannot be credited: the agent payout fails
+    const ix = await program.methods
+      .finalizeBond()
+      .accounts(finalizeAccounts(keeperBond, keeperVault))
+      .instruction();
+    ix.keys.find(k => k.pubkey.equals(agent.publicKey)).isWritable = false;
+
+    let reverted = false;
+    try {
+      await provider.sendAndConfirm(new Transaction().add(ix), [verifier1]);
+    } catch {
+      reverted = true;
+    }
+    expect(reverted).to.equal(true);
+    expect(await provider.connection.getBalance(verifier1.publicKey)).to.equal(keeperBefore);
+    let bond = await program.account.bond.fetch(keeperBond);
+    expect(bond.status).to.deep.equal({ pendingVerification: {} });
+
+    // A clean finalize pays the keeper
+    await finalize(keeperBond, keeperVault);
+    bond = await program.account.bond.fetch(keeperBond);
+    expect(bond.status).to.deep.equal({ completed: {} });
+    expect(await provider.connection.getBalance(verifier1.publicKey)).to.equal(
+      keeperBefore + bounty.toNumber()
+    );
+  });
+
+  it('Honours the vote mode chosen at creation', async () => {
+    // Blind bonds must define both phases
+    await expectError(
+      createBond('vote-mode-bad', { options: bondOptions({ voteMode: { blind: {} } }) }),
+      'InvalidVotingWindow'
+    );
+
+    const [publicBond] = await setupBond('vote-mode-public');
+    const [blindBond] = await setupBond(
+      'vote-mode-blind',
+      bondOptions({
+        voteMode: { blind: {} },
+        commitWindow: new anchor.BN(60),
+        revealWindow: new anchor.BN(60),
+      })
+    );
+
+    const hash = Array.from(Buffer.alloc(32, 7));
+
+    // Public: votes recorded immediately, commitments refused
+    await vote(publicBond, verifier1, true);
+    await expectError(
+      program.methods
+        .commitVote(hash)
+        .accounts({ verifier: verifier2.publicKey, bond: publicBond })
+        .signers([verifier2])
+        .rpc(),
+      'CommitRevealDisabled'
+    );
+
+    // Blind: commitments accepted, public votes refused
+    await program.methods
+      .commitVote(hash)
+      .accounts({ verifier: verifier1.publicKey, bond: blindBond })
+      .signers([verifier1])
+      .rpc();
+    await expectError(vote(blindBond, verifier2, true), 'PublicVotingDisabled');
+
+    const publicState = await program.account.bond.fetch(publicBond);
+    const blind = await program.account.bond.fetch(blindBond);
+    expect(publicState.voteMode).to.deep.equal({ public: {} });
+    expect(publicState.verificationVotes.length).to.equal(1);
+    expect(blind.voteMode).to.deep.equal({ blind: {} });
+    expect(blind.verificationVotes.length).to.equal(0);
+    expect(blind.voteCommits.length).to.equal(1);
+  });
+
+  it('Records the slash reason in the BondSlashed event', async () => {
+    const [reasonBond, reasonVault] = await setupBond('slash-reason-001');
+    await vote(reasonBond, verifier1, false);
+    await vote(reasonBond, verifier2, false);
+    await vote(reasonBond, verifier3, false);
+
+    let reason: object | undefined;
+    const listener = program.addEventListener('BondSlashed', event => {
+      if (event.bondId === 'slash-reason-001') reason = event.reason;
+    });
+
+    await finalize(reasonBond, reasonVault);
+    await sleep(1000);
+    await program.removeEventListener(listener);
+
+    const bond = await program.account.bond.fetch(reasonBond);
+    expect(bond.slashReason).to.deep.equal({ majorityVote: {} });
+    expect(reason).to.deep.equal({ majorityVote: {} });
+    // Still-open bonds carry no reason
+    const open = await program.account.bond.fetch(deriveBond('proofs-001')[0]);
+    expect(open.slashReason).to.equal(null);
+  });
+
+  it('Rejects a second vote from the same verifier', async () => {
+    const [bond] = await setupBond('duplicate-vote-001');
+    await vote(bond, verifier1, true);
+    await expectError(vote(bond, verifier1, true), 'AlreadyVoted');
+    await expectError(vote(bond, verifier1, false), 'AlreadyVoted');
+    expect((await program.account.bond.fetch(bond)).verificationVotes.length).to.equal(1);
+  });
+
+  it('Lets a proxy vote for an absent committee member', async () => {
+    const [proxyBond] = await setupBond('proxy-001');
+    const proxy = Keypair.generate();
+
+    await program.methods
+      .setProxy(proxy.publicKey)
+      .accounts({ member: verifier1.publicKey, bond: proxyBond })
+      .signers([verifier1])
+      .rpc();
+
+    // The proxy names the member's stake and profile, since the vote is theirs
+    await program.methods
+      .verifyWork(true)
+      .accounts({ verifier: proxy.publicKey, bond: proxyBond })
+      .remainingAccounts(voterAccounts(proxyBond, verifier1.publicKey))
+      .signers([proxy])
+      .rpc();
+
+    const bond = await program.account.bond.fetch(proxyBond);
+    expect(bond.verificationVotes.length).to.equal(1);
+    expect(bond.verificationVotes[0].verifier.toBase58()).to.equal(verifier1.publicKey.toBase58());
+    expect(bond.verificationVotes[0].castBy.toBase58()).to.equal(proxy.publicKey.toBase58());
+
+    // The member returns but their vote has already been cast by the proxy
+    await expectError(vote(proxyBond, verifier1, true), 'AlreadyVoted');
+    await expectError(vote(proxyBond, proxy, false), 'AlreadyVoted');
+  });
+
+  it('Decays reputation with age and caps each score', async () => {
+    await updateConfig({ reputationHalfLife: new anchor.BN(2) });
+    try {
+      const [repBond, repVault] = await setupBond('reputation-001');
+      for (const verifier of [verifier1, verifier2, verifier3]) {
+        await vote(repBond, verifier, true);
+      }
+      await finalize(repBond, repVault);
+
+      const fresh = await program.account.agentReputation.fetch(reputationPDA);
+      expect(fresh.agent.toBase58()).to.equal(agent.publicKey.toBase58());
+      expect(fresh.completedScore.toNumber()).to.be.at.least(1000);
+      expect(fresh.completedScore.toNumber()).to.be.at.most(100_000);
+
+      // Two full half-lives later the score has at least quartered
+      await sleep(5000);
+      await program.methods
+        .refreshReputation()
+        .accounts({ reputation: reputationPDA, config: configPDA })
+        .rpc();
+
+      const aged = await program.account.agentReputation.fetch(reputationPDA);
+      expect(aged.completedScore.toNumber()).to.be.at.most(fresh.completedScore.toNumber() / 4);
+      expect(aged.lastUpdated.toNumber()).to.be.greaterThan(fresh.lastUpdated.toNumber());
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Flags a vault balance that disagrees with the bond status', async () => {
+    const [checkedBond, checkedVault] = await setupBond('invariants-001');
+    const checkInvariants = () =>
+      program.methods
+        .checkInvariants()
+        .accounts({ bond: checkedBond, bondVault: checkedVault })
+        .view();
+
+    expect(await checkInvariants()).to.equal(0);
+
+    // Stray lamports leave the vault out of step with the recorded collateral
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: provider.wallet.publicKey,
+          toPubkey: checkedVault,
+          lamports: 1000,
+        })
+      )
+    );
+
+    const VIOLATION_VAULT_BALANCE = 1 << 0;
+    expect(await checkInvariants()).to.equal(VIOLATION_VAULT_BALANCE);
+  });
+
+  it('Stakes collateral from the agent staking vault', async () => {
+    const [stakingVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from('staking_vault'), agent.publicKey.toBuffer()],
+      program.programId
+    );
+    const stakingAccounts = {
+      agent: agent.publicKey,
+      stakingVault,
+      systemProgram: SystemProgram.programId,
+    };
+
+    await program.methods
+      .depositStake(new anchor.BN(0.5 * LAMPORTS_PER_SOL))
+      .accounts(stakingAccounts)
+      .signers([agent])
+      .rpc();
+
+    const collateral = new anchor.BN(0.2 * LAMPORTS_PER_SOL);
+    const [pooledBond, pooledVault] = await createBond('pooled-001', { collateral });
+    const agentBalanceBefore = await provider.connection.getBalance(agent.publicKey);
+
+    await program.methods
+      .stakeCollateral()
+      .accounts({
+        agent: agent.publicKey,
+        bond: pooledBond,
+        bondVault: pooledVault,
+        stats: statsPDA,
+        config: configPDA,
+        stakingVault,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent])
+      .rpc();
+
+    const bond = await program.account.bond.fetch(pooledBond);
+    expect(bond.status).to.deep.equal({ active: {} });
+    expect(await provider.connection.getBalance(pooledVault)).to.equal(collateral.toNumber());
+    expect(await provider.connection.getBalance(stakingVault)).to.equal(0.3 * LAMPORTS_PER_SOL);
+    expect(await provider.connection.getBalance(agent.publicKey)).to.equal(agentBalanceBefore);
+
+    // The remainder can be withdrawn, but never more than is pooled
+    await expectError(
+      program.methods
+        .withdrawStake(new anchor.BN(LAMPORTS_PER_SOL))
+        .accounts(stakingAccounts)
+        .signers([agent])
+        .rpc(),
+      'InsufficientStakingBalance'
+    );
+    await program.methods
+      .withdrawStake(new anchor.BN(0.3 * LAMPORTS_PER_SOL))
+      .accounts(stakingAccounts)
+      .signers([agent])
+      .rpc();
+    expect(await provider.connection.getBalance(stakingVault)).to.equal(0);
+  });
+
+  describe('Timeout without proof', () => {
+    // Staked bond whose deadline lapses before any proof arrives
+    const expireWithoutProof = async (id: string) => {
+      const [bond, vault] = await createBond(id, {
+        deadline: Math.floor(Date.now() / 1000) + 2,
+      });
+      await stakeBond(bond, vault);
+      await sleep(4000);
+      return [bond, vault] as [PublicKey, PublicKey];
+    };
+
+    afterEach(() => updateConfig());
+
+    it('Slashes to the principal by default', async () => {
+      await updateConfig({ timeoutGrace: new anchor.BN(0) });
+      const [bond, vault] = await expireWithoutProof('no-proof-slash-001');
+      const principalBefore = await provider.connection.getBalance(principal.publicKey);
+
+      await finalize(bond, vault);
+
+      const account = await program.account.bond.fetch(bond);
+      expect(account.status).to.deep.equal({ slashed: {} });
+      expect(account.slashReason).to.deep.equal({ timeoutNoProof: {} });
+      expect(await provider.connection.getBalance(principal.publicKey)).to.be.greaterThan(principalBefore);
+    });
+
+    it('Refunds the agent when configured for benefit of the doubt', async () => {
+      await updateConfig({ timeoutGrace: new anchor.BN(0), noProofOutcome: { refund: {} } });
+      const [bond, vault] = await expireWithoutProof('no-proof-refund-001');
+      const agentBefore = await provider.connection.getBalance(agent.publicKey);
+
+      await finalize(bond, vault);
+
+      const account = await program.account.bond.fetch(bond);
+      expect(account.status).to.deep.equal({ expired: {} });
+      expect(account.slashReason).to.be.null;
+      expect(await provider.connection.getBalance(agent.publicKey) - agentBefore).to.equal(
+        0.1 * LAMPORTS_PER_SOL
+      );
+    });
+  });
+
+  it('Transfers principal rights to a new account', async () => {
+    const [movedBond, movedVault] = await setupBond('transfer-principal-001');
+    const newPrincipal = Keypair.generate();
+
+    await program.methods
+      .transferPrincipal(newPrincipal.publicKey)
+      .accounts({ principal: principal.publicKey, bond: movedBond })
+      .signers([principal])
+      .rpc();
+
+    const bond = await program.account.bond.fetch(movedBond);
+    expect(bond.principal.toBase58()).to.equal(newPrincipal.publicKey.toBase58());
+
+    // The old principal has lost its rights; the new one holds them
+    await expectError(concedeSlash(movedBond), 'UnauthorizedPrincipal');
+    await concedeSlash(movedBond, newPrincipal);
+    await executeSlash(movedBond, movedVault, newPrincipal);
+
+    const slashed = await program.account.bond.fetch(movedBond);
+    expect(slashed.status).to.deep.equal({ slashed: {} });
+    expect(await provider.connection.getBalance(newPrincipal.publicKey)).to.equal(
+      0.1 * LAMPORTS_PER_SOL
+    );
+  });
+
+  it('Lets the configured oracle settle a bond in one step', async () => {
+    const oracle = verifier2;
+    const [completeBond, completeVault] = await setupBond('oracle-complete-001');
+    const [slashBond, slashVault] = await setupBond('oracle-slash-001');
+
+    const settle = (
+      bond: PublicKey,
+      vault: PublicKey,
+      outcome: Record<string, object>,
+      signer = oracle
+    ) =>
+      program.methods
+        .oracleSettle(outcome)
+        .accounts({
+          oracle: signer.publicKey,
+          bond,
+          bondVault: vault,
+          agent: agent.publicKey,
+          principal: principal.publicKey,
+          stats: statsPDA,
+          config: configPDA,
+          reputation: reputationPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([signer])
+        .rpc();
+
+    // No oracle is configured by default
+    await expectError(settle(completeBond, completeVault, { complete: {} }), 'UnauthorizedOracle');
+
+    await updateConfig({ oracle: oracle.publicKey });
+    try {
+      await expectError(
+        settle(completeBond, completeVault, { complete: {} }, verifier1),
+        'UnauthorizedOracle'
+      );
+
+      const agentBefore = await provider.connection.getBalance(agent.publicKey);
+      await settle(completeBond, completeVault, { complete: {} });
+      const completed = await program.account.bond.fetch(completeBond);
+      expect(completed.status).to.deep.equal({ completed: {} });
+      expect(completed.verificationVotes.length).to.equal(0);
+      expect(await provider.connection.getBalance(agent.publicKey) - agentBefore).to.equal(
+        0.1 * LAMPORTS_PER_SOL
+      );
+
+      const principalBefore = await provider.connection.getBalance(principal.publicKey);
+      await settle(slashBond, slashVault, { slash: {} });
+      const slashed = await program.account.bond.fetch(slashBond);
+      expect(slashed.status).to.deep.equal({ slashed: {} });
+      expect(slashed.slashReason).to.deep.equal({ oracle: {} });
+      expect(await provider.connection.getBalance(principal.publicKey) - principalBefore).to.equal(
+        0.1 * LAMPORTS_PER_SOL
+      );
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Finalizes a full 10-vote bond within its compute budget', async () => {
+    // Generous ceiling for a 10-vote tally; a regression to per-vote
+    // re-scans or extra clones shows up here first
+    const FINALIZE_CU_BUDGET = 100_000;
+
+    const [cuBond, cuVault] = await setupBond('compute-001');
+    const voters = Array.from({ length: 10 }, () => Keypair.generate());
+    for (const voter of voters) {
+      await vote(cuBond, voter, true);
+    }
+
+    const signature = await finalize(cuBond, cuVault);
+    await provider.connection.confirmTransaction(signature, 'confirmed');
+    const tx = await provider.connection.getTransaction(signature, {
+      commitment: 'confirmed',
+      maxSupportedTransactionVersion: 0,
+    });
+
+    const consumed = tx.meta.computeUnitsConsumed;
+    expect(consumed).to.be.below(FINALIZE_CU_BUDGET);
+  });
+
+  it('Snapshots agent reputation at creation and keeps it fixed', async () => {
+    const reputationBefore = await program.account.agentReputation.fetch(reputationPDA);
+    const [snapBond, snapVault] = await setupBond('snapshot-001');
+
+    const created = await program.account.bond.fetch(snapBond);
+    const snapshot = created.reputationSnapshot;
+    expect(snapshot.takenAt.toNumber()).to.equal(created.createdAt.toNumber());
+    expect(snapshot.completedScore.toNumber()).to.be.greaterThan(0);
+    expect(snapshot.completedScore.toNumber()).to.be.at.most(reputationBefore.completedScore.toNumber());
+
+    // Completing this very bond moves the live reputation, not the snapshot
+    for (const verifier of [verifier1, verifier2, verifier3]) {
+      await vote(snapBond, verifier, true);
+    }
+    await finalize(snapBond, snapVault);
+
+    const reputationAfter = await program.account.agentReputation.fetch(reputationPDA);
+    expect(reputationAfter.completedScore.toNumber()).to.be.greaterThan(snapshot.completedScore.toNumber());
+
+    const settled = await program.account.bond.fetch(snapBond);
+    expect(settled.reputationSnapshot.completedScore.toNumber()).to.equal(snapshot.completedScore.toNumber());
+    expect(settled.reputationSnapshot.slashedScore.toNumber()).to.equal(snapshot.slashedScore.toNumber());
+    expect(settled.reputationSnapshot.takenAt.toNumber()).to.equal(snapshot.takenAt.toNumber());
+  });
+
+  it('Damps a staked whale under quadratic weighting', async () => {
+    const stakeAccount = (bond: PublicKey, verifier: Keypair) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from('verifier_stake'), bond.toBuffer(), verifier.publicKey.toBuffer()],
+        program.programId
+      )[0];
+
+    // Whale stakes 9x each small verifier and is alone in voting to slash
+    const distribution: [Keypair, number, boolean][] = [
+      [verifier1, (9 * LAMPORTS_PER_SOL) / 100, false],
+      [verifier2, LAMPORTS_PER_SOL / 100, true],
+      [verifier3, LAMPORTS_PER_SOL / 100, true],
+    ];
+
+    const runBond = async (id: string, voteWeighting: Record<string, object>) => {
+      const [bond, vault] = await setupBond(id, bondOptions({ voteWeighting }));
+      for (const [verifier, amount, approve] of distribution) {
+        await program.methods
+          .stakeVerifier(new anchor.BN(amount))
+          .accounts({
+            verifier: verifier.publicKey,
+            bond,
+            verifierStake: stakeAccount(bond, verifier),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([verifier])
+          .rpc();
+        await vote(bond, verifier, approve);
+      }
+      const finalizeWeighted = () =>
+        program.methods
+          .finalizeBond()
+          .accounts(finalizeAccounts(bond, vault))
+          .remainingAccounts(
+            distribution.map(([verifier]) => ({
+              pubkey: stakeAccount(bond, verifier),
+              isSigner: false,
+              isWritable: false,
+            }))
+          )
+          .signers([verifier1])
+          .rpc();
+      return { bond, finalizeWeighted };
+    };
+
+    const reclaim = (bond: PublicKey, verifier: Keypair) =>
+      program.methods
+        .reclaimVerifierStake()
+        .accounts({
+          verifier: verifier.publicKey,
+          bond,
+          verifierStake: stakeAccount(bond, verifier),
+        })
+        .rpc();
+
+    // Linear: 0.09 vs 0.02 SOL, the whale alone clears the 2/3 bar
+    const linear = await runBond('weighting-linear-001', { linear: {} });
+    await linear.finalizeWeighted();
+    const linearBond = await program.account.bond.fetch(linear.bond);
+    expect(linearBond.status).to.deep.equal({ slashed: {} });
+
+    // Quadratic: sqrt weights 9486 vs 6324, nobody reaches 2/3
+    const quadratic = await runBond('weighting-quadratic-001', { quadratic: {} });
+    await expectError(quadratic.finalizeWeighted(), 'QuorumNotReached');
+    const quadraticBond = await program.account.bond.fetch(quadratic.bond);
+    expect(quadraticBond.status).to.deep.equal({ pendingVerification: {} });
+
+    // Stakes unlock only once a bond is final
+    await expectError(reclaim(quadratic.bond, verifier1), 'InvalidBondStatus');
+    for (const [verifier] of distribution) {
+      await reclaim(linear.bond, verifier);
+      expect(await provider.connection.getAccountInfo(stakeAccount(linear.bond, verifier))).to.be.null;
+    }
+  });
+
+  it('Returns rent for unused vote slots to the principal', async () => {
+    const [rentBond, rentVault] = await setupBond('vote-rent-001');
+    const reclaim = () =>
+      program.methods
+        .reclaimVoteRent()
+        .accounts({ principal: principal.publicKey, bond: rentBond })
+        .signers([principal])
+        .rpc();
+
+    // Slots are still needed while voting is open
+    await expectError(reclaim(), 'InvalidBondStatus');
+
+    for (const verifier of [verifier1, verifier2, verifier3]) {
+      await vote(rentBond, verifier, true);
+    }
+    await finalize(rentBond, rentVault);
+
+    const allocated = (await provider.connection.getAccountInfo(rentBond)).data.length;
+    const principalBefore = await provider.connection.getBalance(principal.publicKey);
+
+    await reclaim();
+
+    const shrunk = await provider.connection.getAccountInfo(rentBond);
+    const used = shrunk.data.length;
+    const expectedRefund =
+      (await provider.connection.getMinimumBalanceForRentExemption(allocated)) -
+      (await provider.connection.getMinimumBalanceForRentExemption(used));
+
+    expect(used).to.be.lessThan(allocated);
+    expect(await provider.connection.getBalance(principal.publicKey) - principalBefore).to.equal(expectedRefund);
+
+    // The shrunk bond still decodes, and there is nothing left to reclaim
+    const bond = await program.account.bond.fetch(rentBond);
+    expect(bond.verificationVotes.length).to.equal(3);
+    await expectError(reclaim(), 'NoRentToReclaim');
+  });
+
+  it('Invokes an opted-in completion callback via CPI', async () => {
+    // SPL Memo stands in for a downstream program; it just logs the data
+    const MEMO_PROGRAM_ID = new PublicKey('MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr');
+    const callback = { programId: MEMO_PROGRAM_ID, data: Buffer.from('agbero: bond complete') };
+
+    const completeWithCallback = async (id: string, approve: boolean) => {
+      const [bond, vault] = await setupBond(id, bondOptions({ callback }));
+      if (approve) {
+        await program.methods
+          .approveCallback()
+          .accounts({ agent: agent.publicKey, bond })
+          .signers([agent])
+          .rpc();
+      }
+      for (const verifier of [verifier1, verifier2, verifier3]) {
+        await vote(bond, verifier, true);
+      }
+      const signature = await program.methods
+        .finalizeBond()
+        .accounts(finalizeAccounts(bond, vault))
+        .remainingAccounts([{ pubkey: MEMO_PROGRAM_ID, isSigner: false, isWritable: false }])
+        .signers([verifier1])
+        .rpc({ commitment: 'confirmed' });
+      const tx = await provider.connection.getTransaction(signature, {
+        commitment: 'confirmed',
+        maxSupportedTransactionVersion: 0,
+      });
+      return tx.meta.logMessages.join('\n');
+    };
+
+    const invoked = await completeWithCallback('callback-001', true);
+    expect(invoked).to.include(`Program ${MEMO_PROGRAM_ID.toBase58()} invoke`);
+
+    // Without the agent's opt-in the callback never runs
+    const skipped = await completeWithCallback('callback-002', false);
+    expect(skipped).to.not.include(MEMO_PROGRAM_ID.toBase58());
+  });
+
+  it('Cancels an unstaked bond once its stake window expires', async () => {
+    await updateConfig({ stakeWindow: new anchor.BN(2) });
+    try {
+      const [staleBond, staleVault] = await createBond('unstaked-001');
+      const cancel = () =>
+        program.methods
+          .cancelUnstaked()
+          .accounts({
+            caller: verifier2.publicKey,
+            bond: staleBond,
+            bondVault: staleVault,
+            principal: principal.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([verifier2])
+          .rpc();
+
+      await expectError(cancel(), 'StakeWindowOpen');
+      await sleep(4000);
+      await expectError(stakeBond(staleBond, staleVault), 'StakeWindowExpired');
+
+      const rent =
+        (await provider.connection.getBalance(staleBond)) + (await vaultRent());
+      const principalBefore = await provider.connection.getBalance(principal.publicKey);
+
+      await cancel();
+
+      expect(await provider.connection.getAccountInfo(staleBond)).to.be.null;
+      expect(await provider.connection.getBalance(principal.publicKey) - principalBefore).to.equal(rent);
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Lets the arbiter strike a fraudulent vote on a disputed bond', async () => {
+    const [disputedBond, disputedVault] = await setupBond('invalidate-vote-001');
+    const verifier4 = Keypair.generate();
+    const verifier5 = Keypair.generate();
+    const [fraudStake] = PublicKey.findProgramAddressSync(
+      [Buffer.from('verifier_stake'), disputedBond.toBuffer(), verifier1.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .stakeVerifier(new anchor.BN(LAMPORTS_PER_SOL / 100))
+      .accounts({
+        verifier: verifier1.publicKey,
+        bond: disputedBond,
+        verifierStake: fraudStake,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([verifier1])
+      .rpc();
+
+    // 2-3 split: no 2/3 majority either way, and not a tie
+    await vote(disputedBond, verifier1, false);
+    await vote(disputedBond, verifier2, false);
+    await vote(disputedBond, verifier3, true);
+    await vote(disputedBond, verifier4, true);
+    await vote(disputedBond, verifier5, true);
+    await expectError(finalize(disputedBond, disputedVault), 'QuorumNotReached');
+
+    const invalidate = () =>
+      program.methods
+        .arbiterInvalidateVote(verifier1.publicKey, true)
+        .accounts({
+          arbiter: provider.wallet.publicKey,
+          config: configPDA,
+          bond: disputedBond,
+          verifierStake: fraudStake,
+          principal: principal.publicKey,
+        })
+        .rpc();
+
+    await expectError(invalidate(), 'UnauthorizedArbiter');
+
+    await updateConfig({ arbiter: provider.wallet.publicKey });
+    try {
+      // Only a bond handed to the arbiter can have its votes struck
+      await expectError(invalidate(), 'InvalidBondStatus');
+      await program.methods
+        .escalateToArbiter()
+        .accounts({ party: agent.publicKey, bond: disputedBond, config: configPDA })
+        .signers([agent])
+        .rpc();
+
+      const principalBefore = await provider.connection.getBalance(principal.publicKey);
+      await invalidate();
+      expect(await provider.connection.getBalance(principal.publicKey) - principalBefore).to.equal(
+        LAMPORTS_PER_SOL / 100
+      );
+      await expectError(invalidate(), 'VoteAlreadyExcluded');
+
+      const bond = await program.account.bond.fetch(disputedBond);
+      expect(bond.verificationVotes.filter(v => v.excluded).length).to.equal(1);
+
+      await program.methods
+        .resolveDispute(true)
+        .accounts({
+          arbiter: provider.wallet.publicKey,
+          bond: disputedBond,
+          bondVault: disputedVault,
+          agent: agent.publicKey,
+          principal: principal.publicKey,
+          stats: statsPDA,
+          config: configPDA,
+          reputation: reputationPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      const settled = await program.account.bond.fetch(disputedBond);
+      expect(settled.status).to.deep.equal({ completed: {} });
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Pays token rewards to winning verifiers while collateral stays in SOL', async () => {
+    const payer = (provider.wallet as anchor.Wallet).payer;
+    const rewardMint = await createMint(provider.connection, payer, payer.publicKey, null, 0);
+    const tokenAccount = async (owner: PublicKey) =>
+      (await getOrCreateAssociatedTokenAccount(provider.connection, payer, rewardMint, owner)).address;
+
+    const principalToken = await tokenAccount(principal.publicKey);
+    await mintTo(provider.connection, payer, rewardMint, principalToken, payer, 1000);
+
+    const [rewardBond, rewardVault] = await setupBond('token-reward-001');
+    const [rewardEscrow] = PublicKey.findProgramAddressSync(
+      [Buffer.from('reward_escrow'), rewardBond.toBuffer()],
+      program.programId
+    );
+
+    await updateConfig({ rewardMint });
+    try {
+      await program.methods
+        .fundVerifierRewards(new anchor.BN(901))
+        .accounts({
+          principal: principal.publicKey,
+          bond: rewardBond,
+          config: configPDA,
+          rewardMint,
+          principalToken,
+          rewardEscrow,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([principal])
+        .rpc();
+    } finally {
+      await updateConfig();
+    }
+
+    await vote(rewardBond, verifier1, true);
+    await vote(rewardBond, verifier2, true);
+    await vote(rewardBond, verifier3, false);
+
+    const agentBefore = await provider.connection.getBalance(agent.publicKey);
+    await finalize(rewardBond, rewardVault);
+    expect(await provider.connection.getBalance(agent.publicKey) - agentBefore).to.equal(
+      0.1 * LAMPORTS_PER_SOL
+    );
+
+    const claim = async (verifier: Keypair) => {
+      const verifierToken = await tokenAccount(verifier.publicKey);
+      await program.methods
+        .claimVerifierReward()
+        .accounts({
+          verifier: verifier.publicKey,
+          bond: rewardBond,
+          rewardEscrow,
+          verifierToken,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([verifier])
+        .rpc();
+      return Number((await getAccount(provider.connection, verifierToken)).amount);
+    };
+
+    const reclaim = async () => {
+      const before = Number((await getAccount(provider.connection, principalToken)).amount);
+      await program.methods
+        .reclaimVerifierRewards()
+        .accounts({
+          principal: principal.publicKey,
+          bond: rewardBond,
+          rewardEscrow,
+          principalToken,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([principal])
+        .rpc();
+      return Number((await getAccount(provider.connection, principalToken)).amount) - before;
+    };
+
+    // Two approving verifiers split the pool; the dissenter gets nothing
+    expect(await claim(verifier1)).to.equal(450);
+    await expectError(reclaim(), 'RewardsStillClaimable');
+    expect(await claim(verifier2)).to.equal(450);
+    await expectError(claim(verifier3), 'NoRewardDue');
+    await expectError(claim(verifier1), 'RewardAlreadyClaimed');
+
+    // The rounding dust goes back to the principal, once
+    expect(await reclaim()).to.equal(1);
+    await expectError(reclaim(), 'NoRewardDue');
+    expect(Number((await getAccount(provider.connection, rewardEscrow)).amount)).to.equal(0);
+  });
+
+  it('Pre-validates create_bond parameters without creating anything', async () => {
+    const errorCode = (name: string) => program.idl.errors.find(e => e.name === name).code;
+    const validate = ({
+      description = 'Valid task',
+      collateral = new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+      deadline = Math.floor(Date.now() / 1000) + 86400,
+      options = bondOptions(),
+    }: {
+      description?: string;
+      collateral?: anchor.BN;
+      deadline?: number;
+      options?: ReturnType<typeof bondOptions>;
+    } = {}) =>
+      program.methods
+        .validateBondParams(description, collateral, new anchor.BN(deadline), options)
+        .accounts({ config: configPDA })
+        .view();
+
+    expect(await validate()).to.equal(0);
+    expect(await validate({ description: 'x'.repeat(501) })).to.equal(errorCode('DescriptionTooLong'));
+    expect(await validate({ collateral: new anchor.BN(999_999) })).to.equal(errorCode('CollateralTooLow'));
+    expect(await validate({ deadline: Math.floor(Date.now() / 1000) - 60 })).to.equal(
+      errorCode('InvalidDeadline')
+    );
+    expect(await validate({ options: bondOptions({ voteMode: { blind: {} } }) })).to.equal(
+      errorCode('InvalidVotingWindow')
+    );
+    expect(
+      await validate({ options: bondOptions({ appealWindow: new anchor.BN(86401) }) })
+    ).to.equal(errorCode('InvalidAppealWindow'));
+    expect(
+      await validate({
+        options: bondOptions({ callback: { programId: program.programId, data: Buffer.from([]) } }),
+      })
+    ).to.equal(errorCode('InvalidCallback'));
+  });
+
+  it('Splits a team bond pro rata and only lets the lead submit proof', async () => {
+    const teammate = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(teammate.publicKey, LAMPORTS_PER_SOL)
+    );
+    const [teamBond, teamVault] = await createBond('team-001');
+
+    await program.methods
+      .contributeCollateral(new anchor.BN(0.04 * LAMPORTS_PER_SOL))
+      .accounts({
+        member: teammate.publicKey,
+        bond: teamBond,
+        bondVault: teamVault,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([teammate])
+      .rpc();
+    await program.methods
+      .setTeamLead(teammate.publicKey)
+      .accounts({ agent: agent.publicKey, bond: teamBond })
+      .signers([agent])
+      .rpc();
+
+    const agentBeforeStake = await provider.connection.getBalance(agent.publicKey);
+    await stakeBond(teamBond, teamVault);
+    expect(agentBeforeStake - await provider.connection.getBalance(agent.publicKey)).to.equal(
+      0.06 * LAMPORTS_PER_SOL
+    );
+
+    await expectError(submitProof(teamBond, 'https://example.com/proof/team-001'), 'NotTeamLead');
+    await program.methods
+      .submitProof('https://example.com/proof/team-001')
+      .accounts({ agent: teammate.publicKey, bond: teamBond, config: configPDA })
+      .signers([teammate])
+      .rpc();
+
+    await vote(teamBond, verifier1, true);
+    await vote(teamBond, verifier2, true);
+    await vote(teamBond, verifier3, true);
+
+    const agentBefore = await provider.connection.getBalance(agent.publicKey);
+    const teammateBefore = await provider.connection.getBalance(teammate.publicKey);
+    await program.methods
+      .finalizeBond()
+      .accounts(finalizeAccounts(teamBond, teamVault))
+      .remainingAccounts([{ pubkey: teammate.publicKey, isSigner: false, isWritable: true }])
+      .signers([verifier1])
+      .rpc();
+
+    expect(await provider.connection.getBalance(teammate.publicKey) - teammateBefore).to.equal(
+      0.04 * LAMPORTS_PER_SOL
+    );
+    expect(await provider.connection.getBalance(agent.publicKey) - agentBefore).to.equal(
+      0.06 * LAMPORTS_PER_SOL
+    );
+  });
+
+  it('Times verification from proof submission rather than the task deadline', async () => {
+    await updateConfig({ verificationDuration: new anchor.BN(2) });
+    try {
+      // Task deadline is a day away; only the verification window can lapse
+      const [windowBond, windowVault] = await setupBond('verification-window-001');
+      const bond = await program.account.bond.fetch(windowBond);
+      const proofAt = bond.proofs[0].submittedAt.toNumber();
+      expect(bond.verificationDeadline.toNumber()).to.equal(proofAt + 2);
+      expect(bond.deadline.toNumber() - proofAt).to.be.greaterThan(86000);
+
+      await vote(windowBond, verifier1, true);
+      await expectError(finalize(windowBond, windowVault), 'QuorumNotReached');
+
+      await sleep(4000);
+      await expectError(vote(windowBond, verifier2, true), 'VerificationWindowClosed');
+
+      await finalize(windowBond, windowVault);
+      const settled = await program.account.bond.fetch(windowBond);
+      expect(settled.status).to.deep.equal({ slashed: {} });
+      expect(settled.slashReason).to.deep.equal({ timeoutNoQuorum: {} });
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Scores a completed bond from timeliness, approval margin and collateral', async () => {
+    const [scoredBond, scoredVault] = await setupBond('score-001');
+    await vote(scoredBond, verifier1, true);
+    await vote(scoredBond, verifier2, true);
+    await vote(scoredBond, verifier3, true);
+    await finalize(scoredBond, scoredVault);
+
+    const bond = await program.account.bond.fetch(scoredBond);
+    const allotted = bond.deadline.toNumber() - bond.createdAt.toNumber();
+    const spare = bond.deadline.toNumber() - bond.proofs[0].submittedAt.toNumber();
+    const timelinessBps = Math.floor((spare * 10_000) / allotted);
+    // Unanimous approval = 10_000 bps margin; 0.1 SOL = 100 units, sqrt = 10
+    const expected = (timelinessBps + 10_000) * 10;
+    expect(bond.score.toNumber()).to.equal(expected);
+
+    // Recomputing is idempotent
+    await program.methods.computeBondScore().accounts({ bond: scoredBond }).rpc();
+    const recomputed = await program.account.bond.fetch(scoredBond);
+    expect(recomputed.score.toNumber()).to.equal(expected);
+  });
+
+  it('Funds the insurance pool from slashes and pays cover when an agent absconds', async () => {
+    await updateConfig({
+      insuranceBps: 5000,
+      timeoutGrace: new anchor.BN(0),
+      principalPremiumBps: 1000,
+    });
+    try {
+      const poolBefore = await provider.connection.getBalance(insurancePoolPDA);
+
+      // Majority slash: half of the 0.1 SOL goes to the pool
+      const [fraudBond, fraudVault] = await setupBond('insurance-slash-001');
+      await vote(fraudBond, verifier1, false);
+      await vote(fraudBond, verifier2, false);
+      await vote(fraudBond, verifier3, false);
+      await finalize(fraudBond, fraudVault);
+      expect(await provider.connection.getBalance(insurancePoolPDA) - poolBefore).to.equal(
+        0.05 * LAMPORTS_PER_SOL
+      );
+
+      // Insured bond whose agent never delivers
+      const deadline = Math.floor(Date.now() / 1000) + 6;
+      const [insuredBond, insuredVault] = await createBond('insurance-claim-001', {
+        options: bondOptions({ insuredAmount: new anchor.BN(0.08 * LAMPORTS_PER_SOL) }),
+        deadline,
+      });
+      // Registered but unpaid cover pays nothing
+      const [unpaidBond, unpaidVault] = await createBond('insurance-unpaid-001', {
+        options: bondOptions({ insuredAmount: new anchor.BN(0.08 * LAMPORTS_PER_SOL) }),
+        deadline,
+      });
+      const payPremium = (bond: PublicKey) =>
+        program.methods
+          .payPrincipalPremium()
+          .accounts({
+            principal: principal.publicKey,
+            bond,
+            config: configPDA,
+            insurancePool: insurancePoolPDA,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([principal])
+          .rpc();
+
+      // 10% of the 0.08 SOL insured
+      const poolBeforePremium = await provider.connection.getBalance(insurancePoolPDA);
+      await payPremium(insuredBond);
+      expect(await provider.connection.getBalance(insurancePoolPDA) - poolBeforePremium).to.equal(
+        0.008 * LAMPORTS_PER_SOL
+      );
+      await expectError(payPremium(insuredBond), 'PrincipalPremiumAlreadyPaid');
+
+      await stakeBond(insuredBond, insuredVault);
+      await stakeBond(unpaidBond, unpaidVault);
+      await sleep(Math.max(0, deadline * 1000 - Date.now()) + 2000);
+      await finalize(insuredBond, insuredVault);
+      await finalize(unpaidBond, unpaidVault);
+      expect((await program.account.bond.fetch(unpaidBond)).insuranceDue.toNumber()).to.equal(0);
+
+      // Half the 0.1 SOL slash already reached the principal; cover pays the
+      // 0.03 SOL the principal is still short of the 0.08 SOL insured
+      const slashed = await program.account.bond.fetch(insuredBond);
+      expect(slashed.slashReason).to.deep.equal({ timeoutNoProof: {} });
+      expect(slashed.insuranceDue.toNumber()).to.equal(0.03 * LAMPORTS_PER_SOL);
+
+      const claim = () =>
+        program.methods
+          .claimInsurance()
+          .accounts({
+            principal: principal.publicKey,
+            bond: insuredBond,
+            insurancePool: insurancePoolPDA,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([principal])
+          .rpc();
+
+      const principalBefore = await provider.connection.getBalance(principal.publicKey);
+      await claim();
+      expect(await provider.connection.getBalance(principal.publicKey) - principalBefore).to.equal(
+        0.03 * LAMPORTS_PER_SOL
+      );
+      await expectError(claim(), 'NoInsuranceDue');
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Accepts proof past the original deadline only within the extension', async () => {
+    // Enough headroom for both bonds to stake before the original deadline
+    const deadline = Math.floor(Date.now() / 1000) + 4;
+    const [extendedBond, extendedVault] = await createBond('extended-001', { deadline });
+    const [shortBond, shortVault] = await createBond('extended-002', { deadline });
+    await stakeBond(extendedBond, extendedVault);
+    await stakeBond(shortBond, shortVault);
+
+    const extend = (bond: PublicKey, newDeadline: number) =>
+      program.methods
+        .extendDeadline(new anchor.BN(newDeadline))
+        .accounts({ principal: principal.publicKey, bond })
+        .signers([principal])
+        .rpc();
+
+    await extend(extendedBond, deadline + 60);
+    await extend(shortBond, deadline + 2);
+    await sleep(8000);
+
+    // Past the original deadline, inside the extension
+    await submitProof(extendedBond, 'https://example.com/proof/extended-001');
+    const bond = await program.account.bond.fetch(extendedBond);
+    expect(bond.status).to.deep.equal({ pendingVerification: {} });
+    expect(bond.proofs[0].late).to.equal(false);
+
+    // Past the extended deadline as well
+    await expectError(submitProof(shortBond, 'https://example.com/proof/extended-002'), 'DeadlineExceeded');
+  });
+
+  it('Migrates a v1 reputation account preserving its scores', async () => {
+    // Preloaded from tests/fixtures/reputation-v1.json
+    const v1Agent = new PublicKey('9TYZuKLMu838QTQmtVtNspYVTuS6mUf1bHgH5Y38R4iJ');
+    const [v1Reputation] = PublicKey.findProgramAddressSync(
+      [Buffer.from('reputation'), v1Agent.toBuffer()],
+      program.programId
+    );
+    const before = await provider.connection.getAccountInfo(v1Reputation);
+    expect(before.data.length).to.equal(8 + 57);
+
+    const migrate = () =>
+      program.methods
+        .migrateReputation()
+        .accounts({
+          payer: provider.wallet.publicKey,
+          agent: v1Agent,
+          reputation: v1Reputation,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    await migrate();
+
+    const reputation = await program.account.agentReputation.fetch(v1Reputation);
+    expect(reputation.version).to.equal(3);
+    expect(reputation.bondsCompleted.toNumber()).to.equal(0);
+    expect(reputation.agent.equals(v1Agent)).to.be.true;
+    expect(reputation.completedScore.toNumber()).to.equal(3000);
+    expect(reputation.slashedScore.toNumber()).to.equal(1000);
+    expect(reputation.lastUpdated.toNumber()).to.equal(1700000000);
+
+    await expectError(migrate(), 'ReputationUpToDate');
+  });
+
+  it('Batch-stakes three bonds from one funder', async () => {
+    const bonds = await Promise.all(
+      ['batch-001', 'batch-002', 'batch-003'].map(id => createBond(id))
+    );
+    const principalBefore = await provider.connection.getBalance(principal.publicKey);
+
+    await program.methods
+      .batchStake()
+      .accounts({
+        funder: principal.publicKey,
+        stats: statsPDA,
+        config: configPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(
+        bonds.flatMap(([bond, vault]) => [
+          { pubkey: bond, isSigner: false, isWritable: true },
+          { pubkey: vault, isSigner: false, isWritable: true },
+        ])
+      )
+      .signers([principal])
+      .rpc();
+
+    expect(principalBefore - await provider.connection.getBalance(principal.publicKey)).to.equal(
+      0.3 * LAMPORTS_PER_SOL
+    );
+    for (const [bond, vault] of bonds) {
+      const staked = await program.account.bond.fetch(bond);
+      expect(staked.status).to.deep.equal({ active: {} });
+      expect(await provider.connection.getBalance(vault)).to.equal(
+        0.1 * LAMPORTS_PER_SOL + (await vaultRent())
+      );
+    }
+  });
+
+  it('Blocks a repeat bond between the same pair inside the cooldown', async () => {
+    // Fresh agent so earlier bonds with the shared agent don't trip the cooldown
+    const soloAgent = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(soloAgent.publicKey, LAMPORTS_PER_SOL)
+    );
+    const [soloPair] = PublicKey.findProgramAddressSync(
+      [Buffer.from('pair'), principal.publicKey.toBuffer(), soloAgent.publicKey.toBuffer()],
+      program.programId
+    );
+    const [soloReputation] = PublicKey.findProgramAddressSync(
+      [Buffer.from('reputation'), soloAgent.publicKey.toBuffer()],
+      program.programId
+    );
+    const create = (id: string) => {
+      const [bond, vault] = deriveBond(id);
+      return program.methods
+        .createBond(
+          id,
+          'Cooldown task',
+          new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+          new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+          bondOptions()
+        )
+        .accounts({
+          principal: principal.publicKey,
+          agent: soloAgent.publicKey,
+          bond,
+          bondVault: vault,
+          stats: statsPDA,
+          config: configPDA,
+          reputation: soloReputation,
+          pair: soloPair,
+          reservation: deriveReservation(id),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([principal])
+        .rpc();
+    };
+
+    await updateConfig({ bondCooldown: new anchor.BN(3600) });
+    try {
+      await create('cooldown-001');
+      const [bond, vault] = deriveBond('cooldown-001');
+      await program.methods
+        .stakeCollateral()
+        .accounts({
+          agent: soloAgent.publicKey,
+          bond,
+          bondVault: vault,
+          stats: statsPDA,
+          config: configPDA,
+          stakingVault: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([soloAgent])
+        .rpc();
+      await program.methods
+        .submitProof('https://example.com/proof/cooldown-001')
+        .accounts({ agent: soloAgent.publicKey, bond, config: configPDA })
+        .signers([soloAgent])
+        .rpc();
+      await vote(bond, verifier1, true);
+      await vote(bond, verifier2, true);
+      await vote(bond, verifier3, true);
+      await program.methods
+        .finalizeBond()
+        .accounts({
+          ...finalizeAccounts(bond, vault),
+          agent: soloAgent.publicKey,
+          reputation: soloReputation,
+        })
+        .signers([verifier1])
+        .rpc();
+      const settled = await program.account.bond.fetch(bond);
+      expect(settled.status).to.deep.equal({ completed: {} });
+
+      await expectError(create('cooldown-002'), 'BondCooldownActive');
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Records begin_work and requires it before proof when configured', async () => {
+    const [wipBond, wipVault] = await createBond('begin-work-001', {
+      options: bondOptions({ requireBeginWork: true }),
+    });
+    await stakeBond(wipBond, wipVault);
+
+    const beginWork = () =>
+      program.methods
+        .beginWork()
+        .accounts({ agent: agent.publicKey, bond: wipBond })
+        .signers([agent])
+        .rpc();
+
+    await expectError(submitProof(wipBond, 'https://example.com/proof/begin-work-001'), 'WorkNotStarted');
+
+    const before = Math.floor(Date.now() / 1000);
+    await beginWork();
+    const started = await program.account.bond.fetch(wipBond);
+    expect(started.status).to.deep.equal({ active: {} });
+    expect(started.startedAt.toNumber()).to.be.within(before - 5, before + 5);
+
+    await expectError(beginWork(), 'WorkAlreadyStarted');
+    await submitProof(wipBond, 'https://example.com/proof/begin-work-001');
+  });
+
+  it('Returns the full lifecycle timeline', async () => {
+    const [timelineBond, timelineVault] = await createBond('timeline-001');
+    await stakeBond(timelineBond, timelineVault);
+    await program.methods
+      .beginWork()
+      .accounts({ agent: agent.publicKey, bond: timelineBond })
+      .signers([agent])
+      .rpc();
+    await submitProof(timelineBond, 'https://example.com/proof/timeline-001');
+    await vote(timelineBond, verifier1, true);
+    await vote(timelineBond, verifier2, true);
+    await vote(timelineBond, verifier3, true);
+    await finalize(timelineBond, timelineVault);
+
+    const timeline = await program.methods
+      .getTimeline()
+      .accounts({ bond: timelineBond })
+      .view();
+    const bond = await program.account.bond.fetch(timelineBond);
+
+    expect(timeline.createdAt.toNumber()).to.equal(bond.createdAt.toNumber());
+    expect(timeline.stakedAt.toNumber()).to.equal(bond.stakedAt.toNumber());
+    expect(timeline.startedAt.toNumber()).to.equal(bond.startedAt.toNumber());
+    expect(timeline.proofSubmittedAt.toNumber()).to.equal(bond.proofs[0].submittedAt.toNumber());
+    expect(timeline.finalizedAt.toNumber()).to.equal(bond.completedAt.toNumber());
+
+    const steps = [
+      timeline.createdAt,
+      timeline.stakedAt,
+      timeline.startedAt,
+      timeline.proofSubmittedAt,
+      timeline.finalizedAt,
+    ].map(t => t.toNumber());
+    steps.forEach((t, i) => {
+      expect(t).to.be.greaterThan(0);
+      if (i > 0) expect(t).to.be.at.least(steps[i - 1]);
+    });
+  });
+
+  it('Penalizes only verifiers who voted against the arbiter ruling', async () => {
+    const pda = (...seeds: Buffer[]) => PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+    const [disputedBond, disputedVault] = await setupBond('dispute-001');
+    const stakeOf = (verifier: Keypair) =>
+      pda(Buffer.from('verifier_stake'), disputedBond.toBuffer(), verifier.publicKey.toBuffer());
+    const profileOf = (verifier: Keypair) => pda(Buffer.from('verifier'), verifier.publicKey.toBuffer());
+
+    for (const verifier of [verifier1, verifier3]) {
+      await ensureRegistered(verifier);
+      await program.methods
+        .stakeVerifier(new anchor.BN(LAMPORTS_PER_SOL / 100))
+        .accounts({
+          verifier: verifier.publicKey,
+          bond: disputedBond,
+          verifierStake: stakeOf(verifier),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([verifier])
+        .rpc();
+    }
+
+    // Majority approves; verifier3 alone votes to slash
+    await vote(disputedBond, verifier1, true);
+    await vote(disputedBond, verifier2, true);
+    await vote(disputedBond, verifier3, false);
+
+    await updateConfig({ arbiter: provider.wallet.publicKey });
+    try {
+      await program.methods
+        .escalateToArbiter()
+        .accounts({ party: principal.publicKey, bond: disputedBond, config: configPDA })
+        .signers([principal])
+        .rpc();
+      await expectError(finalize(disputedBond, disputedVault), 'InvalidBondStatus');
+
+      const principalBefore = await provider.connection.getBalance(principal.publicKey);
+      await program.methods
+        .resolveDispute(false)
+        .accounts({
+          arbiter: provider.wallet.publicKey,
+          bond: disputedBond,
+          bondVault: disputedVault,
+          agent: agent.publicKey,
+          principal: principal.publicKey,
+          stats: statsPDA,
+          config: configPDA,
+          reputation: reputationPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          [stakeOf(verifier1), stakeOf(verifier3), profileOf(verifier1), profileOf(verifier3)].map(
+            pubkey => ({ pubkey, isSigner: false, isWritable: true })
+          )
+        )
+        .rpc();
+
+      const bond = await program.account.bond.fetch(disputedBond);
+      expect(bond.status).to.deep.equal({ slashed: {} });
+      expect(bond.slashReason).to.deep.equal({ arbiter: {} });
+      expect(bond.arbiterRuling).to.equal(false);
+
+      // Collateral plus verifier1's forfeited stake
+      expect(await provider.connection.getBalance(principal.publicKey) - principalBefore).to.equal(
+        0.1 * LAMPORTS_PER_SOL + LAMPORTS_PER_SOL / 100
+      );
+      expect((await program.account.verifierStake.fetch(stakeOf(verifier1))).amount.toNumber()).to.equal(0);
+      expect((await program.account.verifierStake.fetch(stakeOf(verifier3))).amount.toNumber()).to.equal(
+        LAMPORTS_PER_SOL / 100
+      );
+      expect((await program.account.verifierProfile.fetch(profileOf(verifier1))).penalties).to.equal(1);
+      expect((await program.account.verifierProfile.fetch(profileOf(verifier3))).penalties).to.equal(0);
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Reassigns an inactive sub-committee member to a registered verifier', async () => {
+    const standby = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(standby.publicKey, LAMPORTS_PER_SOL)
+    );
+    await registerVerifier(standby);
+
+    await updateConfig({ assignmentWindow: new anchor.BN(2) });
+    try {
+      const [idleBond, idleVault] = await createBond('reassign-001');
+      await stakeBond(idleBond, idleVault);
+      await formSubcommittee(idleBond);
+      await submitProof(idleBond, 'https://example.com/proof/reassign-001');
+
+      const drawn = (await program.account.bond.fetch(idleBond)).subcommittee;
+      const [active, idle] = drawn.map(m => registered.get(m.toBase58()));
+
+      // The caller supplies profiles but the bond's draw picks the replacement
+      const reassign = async (member: Keypair) =>
+        program.methods
+          .reassignInactive(member.publicKey)
+          .accounts({
+            caller: provider.wallet.publicKey,
+            bond: idleBond,
+            config: configPDA,
+            stats: statsPDA,
+          })
+          .remainingAccounts(await profileAccounts())
+          .rpc();
+
+      await expectError(reassign(idle), 'AssignmentNotExpired');
+      await vote(idleBond, active, true);
+      await sleep(4000);
+
+      await expectError(reassign(active), 'AlreadyVoted');
+      await reassign(idle);
+
+      const bond = await program.account.bond.fetch(idleBond);
+      expect(bond.subcommittee[0].equals(drawn[0])).to.be.true;
+      expect(bond.subcommittee.some(m => m.equals(idle.publicKey))).to.be.false;
+      const replacement = registered.get(bond.subcommittee[1].toBase58());
+      expect(replacement).to.not.be.undefined;
+
+      await expectError(vote(idleBond, idle, true), 'NotInSubcommittee');
+      await vote(idleBond, replacement, true);
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Lets the arbiter route a disputed bond to a recovery wallet', async () => {
+    const recovery = Keypair.generate();
+    const [lostBond, lostVault] = await setupBond('recovery-001');
+    await program.methods
+      .fundTips(new anchor.BN(0.01 * LAMPORTS_PER_SOL))
+      .accounts({
+        principal: principal.publicKey,
+        bond: lostBond,
+        bondVault: lostVault,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([principal])
+      .rpc();
+    await vote(lostBond, verifier1, true);
+    await vote(lostBond, verifier2, false);
+
+    await updateConfig({ arbiter: provider.wallet.publicKey });
+    try {
+      await program.methods
+        .escalateToArbiter()
+        .accounts({ party: agent.publicKey, bond: lostBond, config: configPDA })
+        .signers([agent])
+        .rpc();
+
+      const override = (
+        arbiter: PublicKey,
+        signers: Keypair[] = [],
+        amount = 0.1 * LAMPORTS_PER_SOL
+      ) =>
+        program.methods
+          .finalizeOverride(recovery.publicKey, new anchor.BN(amount))
+          .accounts({
+            arbiter,
+            config: configPDA,
+            bond: lostBond,
+            bondVault: lostVault,
+            recoveryWallet: recovery.publicKey,
+            principal: principal.publicKey,
+            stats: statsPDA,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers(signers)
+          .rpc();
+
+      await expectError(override(principal.publicKey, [principal]), 'UnauthorizedArbiter');
+      // Only the collateral is the arbiter's to route; the tips are not
+      await expectError(
+        override(provider.wallet.publicKey, [], 0.11 * LAMPORTS_PER_SOL),
+        'InvalidRecoveryAmount'
+      );
+      const principalBefore = await provider.connection.getBalance(principal.publicKey);
+      await override(provider.wallet.publicKey);
+
+      expect(await provider.connection.getBalance(recovery.publicKey)).to.equal(0.1 * LAMPORTS_PER_SOL);
+      expect(await provider.connection.getBalance(principal.publicKey) - principalBefore).to.equal(
+        0.01 * LAMPORTS_PER_SOL
+      );
+      expect(await provider.connection.getBalance(lostVault)).to.equal(await vaultRent());
+      const bond = await program.account.bond.fetch(lostBond);
+      expect(bond.status).to.deep.equal({ recovered: {} });
+      expect(bond.totalSlashed.toNumber()).to.equal(0.1 * LAMPORTS_PER_SOL);
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Keeps an installment bond pending until the final installment lands', async () => {
+    const collateral = 0.1 * LAMPORTS_PER_SOL;
+    // Every installment must fall due before the bond's deadline
+    await expectError(
+      createBond('installments-002', {
+        options: bondOptions({ installments: 3, installmentInterval: new anchor.BN(86400) }),
+      }),
+      'InvalidInstallmentSchedule'
+    );
+    const [scheduledBond, scheduledVault] = await createBond('installments-001', {
+      options: bondOptions({ installments: 3, installmentInterval: new anchor.BN(3600) }),
+    });
+
+    const fundInstallment = () =>
+      program.methods
+        .fundInstallment()
+        .accounts({
+          agent: agent.publicKey,
+          bond: scheduledBond,
+          bondVault: scheduledVault,
+          stats: statsPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+
+    await expectError(stakeBond(scheduledBond, scheduledVault), 'InstallmentScheduleActive');
+    await fundInstallment();
+    await fundInstallment();
+
+    const installment = Math.floor(collateral / 3);
+    const partial = await program.account.bond.fetch(scheduledBond);
+    expect(partial.status).to.deep.equal({ pending: {} });
+    expect(partial.installmentsPaid).to.equal(2);
+    expect(partial.fundedAmount.toNumber()).to.equal(2 * installment);
+    expect(await provider.connection.getBalance(scheduledVault)).to.equal(
+      2 * installment + (await vaultRent())
+    );
+    await expectError(submitProof(scheduledBond, 'https://example.com/proof/installments-001'), 'InvalidBondStatus');
+
+    // Final installment carries the rounding remainder and activates the bond
+    await fundInstallment();
+    const funded = await program.account.bond.fetch(scheduledBond);
+    expect(funded.status).to.deep.equal({ active: {} });
+    expect(funded.fundedAmount.toNumber()).to.equal(collateral);
+  });
+
+  it('Derives the canonical bond PDAs from a bond_id', async () => {
+    const pdas = await program.methods.derivePdas('pda-001').accounts({}).view();
+
+    const [bond, bondBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from('bond'), Buffer.from('pda-001')],
+      program.programId
+    );
+    const [vault, vaultBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from('bond_vault'), bond.toBuffer()],
+      program.programId
+    );
+    expect(pdas.bond.equals(bond)).to.be.true;
+    expect(pdas.bondBump).to.equal(bondBump);
+    expect(pdas.bondVault.equals(vault)).to.be.true;
+    expect(pdas.vaultBump).to.equal(vaultBump);
+  });
+
+  it('Rejects verifier registration once the pool is full', async () => {
+    const candidates = [Keypair.generate(), Keypair.generate()];
+    await Promise.all(
+      candidates.map(async c =>
+        provider.connection.confirmTransaction(
+          await provider.connection.requestAirdrop(c.publicKey, LAMPORTS_PER_SOL)
+        )
+      )
+    );
+
+    // Leave room for exactly one more verifier
+    const { registeredVerifiers } = await program.account.stats.fetch(statsPDA);
+    await updateConfig({ maxVerifiers: registeredVerifiers.addn(1) });
+    try {
+      await registerVerifier(candidates[0]);
+      const stats = await program.account.stats.fetch(statsPDA);
+      expect(stats.registeredVerifiers.toNumber()).to.equal(registeredVerifiers.toNumber() + 1);
+
+      await expectError(registerVerifier(candidates[1]), 'VerifierPoolFull');
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Partial slash consumes the buffer and preserves the base', async () => {
+    await updateConfig({ partialSlashBps: 2000 });
+    try {
+      const [bond, vault] = await setupBond('buffer-001');
+      await program.methods
+        .topUpBuffer(new anchor.BN(0.03 * LAMPORTS_PER_SOL))
+        .accounts({
+          agent: agent.publicKey,
+          bond,
+          config: configPDA,
+          bondVault: vault,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+      expect((await program.account.bond.fetch(bond)).bufferAmount.toNumber()).to.equal(
+        0.03 * LAMPORTS_PER_SOL
+      );
+
+      await vote(bond, verifier1, false);
+      await vote(bond, verifier2, false);
+      await vote(bond, verifier3, false);
+
+      const principalBefore = await provider.connection.getBalance(principal.publicKey);
+      const agentBefore = await provider.connection.getBalance(agent.publicKey);
+      await finalize(bond, vault);
+
+      // 20% of the 0.1 SOL base comes out of the 0.03 SOL buffer
+      const slashed = await program.account.bond.fetch(bond);
+      expect(slashed.status).to.deep.equal({ slashed: {} });
+      expect(slashed.bufferAmount.toNumber()).to.equal(0.01 * LAMPORTS_PER_SOL);
+      expect(await provider.connection.getBalance(principal.publicKey) - principalBefore).to.equal(
+        0.02 * LAMPORTS_PER_SOL
+      );
+      expect(await provider.connection.getBalance(agent.publicKey) - agentBefore).to.equal(
+        0.11 * LAMPORTS_PER_SOL
+      );
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Emits a BondClosed summary when a finalized bond is closed', async () => {
+    const [bond, vault] = await setupBond('close-001');
+    await vote(bond, verifier1, true);
+    await vote(bond, verifier2, true);
+    await vote(bond, verifier3, false);
+    await finalize(bond, vault);
+    const finalized = await program.account.bond.fetch(bond);
+
+    let summary: any;
+    const listener = program.addEventListener('BondClosed', event => {
+      if (event.bondId === 'close-001') summary = event;
+    });
+
+    const rent = (await provider.connection.getBalance(bond)) + (await vaultRent());
+    const principalBefore = await provider.connection.getBalance(principal.publicKey);
+    await program.methods
+      .closeBond()
+      .accounts({
+        principal: principal.publicKey,
+        bond,
+        bondVault: vault,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([principal])
+      .rpc();
+    await sleep(1000);
+    await program.removeEventListener(listener);
+
+    expect(summary.status).to.deep.equal({ completed: {} });
+    expect(summary.totalSlashed.toNumber()).to.equal(0);
+    expect(summary.totalReleased.toNumber()).to.equal(0.1 * LAMPORTS_PER_SOL);
+    expect(summary.approveVotes.toNumber()).to.equal(2);
+    expect(summary.slashVotes.toNumber()).to.equal(1);
+    expect(summary.duration.toNumber()).to.equal(
+      finalized.completedAt.toNumber() - finalized.createdAt.toNumber()
+    );
+    expect(summary.rentRefunded.toNumber()).to.equal(rent);
+    expect(await provider.connection.getBalance(principal.publicKey) - principalBefore).to.equal(rent);
+    expect(await provider.connection.getAccountInfo(bond)).to.equal(null);
+  });
+
+  it('Stores and emits the principal justification on emergency slash', async () => {
+    const [bond, vault] = await setupBond('justified-001');
+    const justification = 'Agent delivered plagiarised output';
+
+    let emitted: string | undefined;
+    const listener = program.addEventListener('BondSlashed', event => {
+      if (event.bondId === 'justified-001') emitted = event.justification;
+    });
+
+    const request = (reason: string) =>
+      program.methods
+        .requestEmergencySlash(reason)
+        .accounts({ principal: principal.publicKey, bond, config: configPDA })
+        .signers([principal])
+        .rpc();
+
+    await expectError(request('x'.repeat(201)), 'SlashReasonTooLong');
+    await request(justification);
+    await program.methods
+      .acknowledgeSlash()
+      .accounts({ agent: agent.publicKey, bond })
+      .signers([agent])
+      .rpc();
+    await executeSlash(bond, vault);
+    await sleep(1000);
+    await program.removeEventListener(listener);
+
+    const slashed = await program.account.bond.fetch(bond);
+    expect(slashed.slashReason).to.deep.equal({ emergencyPrincipal: {} });
+    expect(slashed.slashJustification).to.equal(justification);
+    expect(emitted).to.equal(justification);
+  });
+
+  it('Splits a non-divisible team refund by largest remainder with no dust', async () => {
+    const teammates = [Keypair.generate(), Keypair.generate()];
+    for (const teammate of teammates) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(teammate.publicKey, LAMPORTS_PER_SOL)
+      );
+    }
+    const collateral = 10_000_000;
+    const [bond, vault] = await createBond('split-001', { collateral: new anchor.BN(collateral) });
+    for (const teammate of teammates) {
+      await program.methods
+        .contributeCollateral(new anchor.BN(3_333_333))
+        .accounts({
+          member: teammate.publicKey,
+          bond,
+          bondVault: vault,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([teammate])
+        .rpc();
+    }
+    await stakeBond(bond, vault);
+    await submitProof(bond, 'https://example.com/proof/split-001');
+    await vote(bond, verifier1, false);
+    await vote(bond, verifier2, false);
+    await vote(bond, verifier3, false);
+
+    // A 10% partial slash leaves 9_000_000 to split 3_333_333 : 3_333_333 : 3_333_334
+    await updateConfig({ partialSlashBps: 1000 });
+    try {
+      const parties = [...teammates.map(t => t.publicKey), agent.publicKey];
+      const before = await Promise.all(parties.map(p => provider.connection.getBalance(p)));
+      await program.methods
+        .finalizeBond()
+        .accounts(finalizeAccounts(bond, vault))
+        .remainingAccounts(
+          teammates.map(t => ({ pubkey: t.publicKey, isSigner: false, isWritable: true }))
+        )
+        .signers([verifier1])
+        .rpc();
+      const after = await Promise.all(parties.map(p => provider.connection.getBalance(p)));
+      const received = after.map((balance, i) => balance - before[i]);
+
+      expect(received).to.deep.equal([3_000_000, 3_000_000, 3_000_000]);
+      expect(received.reduce((a, b) => a + b, 0)).to.equal(collateral - 1_000_000);
+      expect(await provider.connection.getBalance(vault)).to.equal(await vaultRent());
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Records a bundle of off-chain signed votes in one transaction', async () => {
+    const [bond] = await setupBond('bundle-001');
+    const votes = [
+      { verifier: verifier1, approve: true },
+      { verifier: verifier2, approve: true },
+      { verifier: verifier3, approve: false },
+    ];
+    const { taskDescription } = await program.account.bond.fetch(bond);
+    const message = (approve: boolean, task = taskDescription) =>
+      Buffer.concat([
+        Buffer.from('agbero:vote'),
+        bond.toBuffer(),
+        Buffer.from(keccak_256(Buffer.from(task))),
+        Buffer.from([approve ? 1 : 0]),
+      ]);
+
+    const submit = (signed: anchor.web3.TransactionInstruction[]) =>
+      program.methods
+        .submitVoteBundle(votes.map(v => ({ verifier: v.verifier.publicKey, approve: v.approve })))
+        .accounts({
+          submitter: provider.wallet.publicKey,
+          bond,
+          config: configPDA,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .remainingAccounts(votes.flatMap(v => voterAccounts(bond, v.verifier.publicKey)))
+        .preInstructions(signed)
+        .rpc();
+    const signatures = votes.map(v =>
+      Ed25519Program.createInstructionWithPrivateKey({
+        privateKey: v.verifier.secretKey,
+        message: message(v.approve),
+      })
+    );
+
+    // A vote signed for the other outcome does not count
+    const forged = Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: verifier3.secretKey,
+      message: message(true),
+    });
+    await expectError(submit([...signatures.slice(0, 2), forged]), 'MissingVoteSignature');
+
+    // Signatures over a different task text do not count either
+    const stale = votes.map(v =>
+      Ed25519Program.createInstructionWithPrivateKey({
+        privateKey: v.verifier.secretKey,
+        message: message(v.approve, 'Earlier task'),
+      })
+    );
+    await expectError(submit(stale), 'MissingVoteSignature');
+
+    const votesRecorded = () =>
+      Promise.all(
+        votes.map(async v =>
+          (await program.account.verifierProfile.fetch(deriveProfile(v.verifier.publicKey))).votesRecorded
+        )
+      );
+    const recordedBefore = await votesRecorded();
+    await submit(signatures);
+    // Bundled votes land in each verifier's history like any other vote
+    expect(await votesRecorded()).to.deep.equal(recordedBefore.map(n => n + 1));
+    const recorded = await program.account.bond.fetch(bond);
+    expect(recorded.verificationVotes.map(v => [v.verifier.toBase58(), v.approve])).to.deep.equal(
+      votes.map(v => [v.verifier.publicKey.toBase58(), v.approve])
+    );
+    expect(recorded.verificationVotes.every(v => v.castBy.equals(provider.wallet.publicKey))).to.equal(
+      true
+    );
+  });
+
+  it('Amends the task by mutual consent and clears stale proof, votes and tally', async () => {
+    const [bond] = await setupBond('amend-001');
+    await vote(bond, verifier1, true);
+    // An oracle tally committed against the old scope must not survive it
+    await updateConfig({ oracle: verifier2.publicKey });
+    try {
+      await program.methods
+        .commitTally(Array.from(Buffer.alloc(32, 7)), 1, 1)
+        .accounts({ oracle: verifier2.publicKey, bond, config: configPDA })
+        .signers([verifier2])
+        .rpc();
+    } finally {
+      await updateConfig();
+    }
+    const newDeadline = Math.floor(Date.now() / 1000) + 7200;
+
+    const amend = (description = 'Revised scope: summarise 20 reports') =>
+      program.methods
+        .amendTask(description, new anchor.BN(newDeadline))
+        .accounts({ principal: principal.publicKey, agent: agent.publicKey, bond })
+        .signers([principal, agent])
+        .rpc();
+
+    await expectError(amend('x'.repeat(501)), 'DescriptionTooLong');
+    await amend();
+
+    const amended = await program.account.bond.fetch(bond);
+    expect(amended.taskDescription).to.equal('Revised scope: summarise 20 reports');
+    expect(amended.deadline.toNumber()).to.equal(newDeadline);
+    expect(amended.status).to.deep.equal({ active: {} });
+    expect(amended.proofs.length).to.equal(0);
+    expect(amended.verificationVotes.length).to.equal(0);
+    expect(amended.tallyRoot).to.deep.equal(Array(32).fill(0));
+    expect(amended.tallyApprove).to.equal(0);
+    expect(amended.tallyTotal).to.equal(0);
+    expect(amended.tallySlot.toNumber()).to.equal(0);
+  });
+
+  it('Folds each vote into the verifier history hash', async () => {
+    const auditor = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(auditor.publicKey, LAMPORTS_PER_SOL)
+    );
+    await registerVerifier(auditor);
+    const [profile] = PublicKey.findProgramAddressSync(
+      [Buffer.from('verifier'), auditor.publicKey.toBuffer()],
+      program.programId
+    );
+    const historyHash = async () =>
+      Buffer.from(
+        await program.methods.getVerifierHistoryHash().accounts({ profile }).view()
+      );
+
+    let expected = Buffer.alloc(32);
+    expect((await historyHash()).equals(expected)).to.equal(true);
+
+    const [first] = await setupBond('history-001');
+    const [second] = await setupBond('history-002');
+    for (const [bond, approve] of [[first, true], [second, false]] as [PublicKey, boolean][]) {
+      await vote(bond, auditor, approve);
+      expected = Buffer.from(
+        keccak_256(Buffer.concat([expected, bond.toBuffer(), Buffer.from([approve ? 1 : 0])]))
+      );
+      expect((await historyHash()).equals(expected)).to.equal(true);
+    }
+    expect((await program.account.verifierProfile.fetch(profile)).votesRecorded).to.equal(2);
+  });
+
+  it('Pays tips only to winning verifiers who attached evidence', async () => {
+    const [bond, vault] = await setupBond('tips-001');
+    await program.methods
+      .fundTips(new anchor.BN(0.03 * LAMPORTS_PER_SOL))
+      .accounts({
+        principal: principal.publicKey,
+        bond,
+        bondVault: vault,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([principal])
+      .rpc();
+
+    const evidencePda = (verifier: Keypair) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from('evidence'), bond.toBuffer(), verifier.publicKey.toBuffer()],
+        program.programId
+      )[0];
+    const attachEvidence = (verifier: Keypair) =>
+      program.methods
+        .attachEvidence(`https://example.com/evidence/${verifier.publicKey.toBase58()}`)
+        .accounts({
+          verifier: verifier.publicKey,
+          bond,
+          evidence: evidencePda(verifier),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([verifier])
+        .rpc();
+
+    await expectError(attachEvidence(verifier1), 'VoteNotFound');
+    await vote(bond, verifier1, true);
+    await vote(bond, verifier2, true);
+    await vote(bond, verifier3, false);
+    await attachEvidence(verifier1);
+    await attachEvidence(verifier3); // evidence, but on the losing side
+    expect((await program.account.evidence.fetch(evidencePda(verifier1))).uri).to.equal(
+      `https://example.com/evidence/${verifier1.publicKey.toBase58()}`
+    );
+
+    const verifiers = [verifier1, verifier2, verifier3];
+    const before = await Promise.all(verifiers.map(v => provider.connection.getBalance(v.publicKey)));
+    const agentBefore = await provider.connection.getBalance(agent.publicKey);
+    await program.methods
+      .finalizeBond()
+      .accounts(finalizeAccounts(bond, vault, principal.publicKey))
+      .remainingAccounts(
+        // verifier1's wallet is left out: its tip is parked, not lost
+        [verifier2, verifier3].map(v => ({ pubkey: v.publicKey, isSigner: false, isWritable: true }))
+      )
+      .signers([principal])
+      .rpc();
+    expect(await provider.connection.getBalance(verifier1.publicKey)).to.equal(before[0]);
+    expect((await program.account.bond.fetch(bond)).deferredTips).to.equal(1);
+
+    await program.methods
+      .claimDeferredReward()
+      .accounts({ verifier: verifier1.publicKey, bond })
+      .rpc();
+    const after = await Promise.all(verifiers.map(v => provider.connection.getBalance(v.publicKey)));
+
+    expect(after.map((balance, i) => balance - before[i])).to.deep.equal([
+      0.03 * LAMPORTS_PER_SOL,
+      0,
+      0,
+    ]);
+    // Tips come on top of the collateral, never out of it
+    expect(await provider.connection.getBalance(agent.publicKey) - agentBefore).to.equal(
+      0.1 * LAMPORTS_PER_SOL
+    );
+
+    // Evidence rent goes back once the bond is final
+    await program.methods
+      .closeEvidence()
+      .accounts({ verifier: verifier1.publicKey, bond, evidence: evidencePda(verifier1) })
+      .rpc();
+    expect(await provider.connection.getAccountInfo(evidencePda(verifier1))).to.equal(null);
+  });
+
+  it('Blocks finalize while a legal hold is in place', async () => {
+    const [bond, vault] = await setupBond('hold-001');
+    await vote(bond, verifier1, true);
+    await vote(bond, verifier2, true);
+    await vote(bond, verifier3, true);
+
+    const setHold = (place: boolean) =>
+      (place ? program.methods.placeHold() : program.methods.liftHold())
+        .accounts({ authority: provider.wallet.publicKey, config: configPDA, bond })
+        .rpc();
+
+    await expectError(setHold(true), 'UnauthorizedLegalAuthority');
+    await updateConfig({ legalAuthority: provider.wallet.publicKey });
+    try {
+      await setHold(true);
+      await expectError(finalize(bond, vault), 'BondOnHold');
+      expect((await program.account.bond.fetch(bond)).status).to.deep.equal({
+        pendingVerification: {},
+      });
+
+      await setHold(false);
+      await finalize(bond, vault);
+      expect((await program.account.bond.fetch(bond)).status).to.deep.equal({ completed: {} });
+
+      // A held bond cannot be withdrawn before the agent stakes either
+      const [pendingBond, pendingVault] = await createBond('hold-002');
+      await program.methods
+        .placeHold()
+        .accounts({ authority: provider.wallet.publicKey, config: configPDA, bond: pendingBond })
+        .rpc();
+      await expectError(
+        program.methods
+          .cancelBond()
+          .accounts({
+            principal: principal.publicKey,
+            bond: pendingBond,
+            bondVault: pendingVault,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([principal])
+          .rpc(),
+        'BondOnHold'
+      );
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Signs for the vault with its own bump through a full bond lifecycle', async () => {
+    const [bond, vault] = await createBond('vault-bump-001');
+    const [, vaultBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from('bond_vault'), bond.toBuffer()],
+      program.programId
+    );
+    const created = await program.account.bond.fetch(bond);
+    expect(created.vaultBump).to.equal(vaultBump);
+
+    await stakeBond(bond, vault);
+    await submitProof(bond, 'https://example.com/proof/vault-bump-001');
+    await vote(bond, verifier1, true);
+    await vote(bond, verifier2, true);
+    await vote(bond, verifier3, true);
+
+    const agentBefore = await provider.connection.getBalance(agent.publicKey);
+    await finalize(bond, vault);
+    expect(await provider.connection.getBalance(agent.publicKey) - agentBefore).to.equal(
+      0.1 * LAMPORTS_PER_SOL
+    );
+    expect(await provider.connection.getBalance(vault)).to.equal(await vaultRent());
+  });
+
+  it('Holds emergency slashes for each bond\'s own appeal window', async () => {
+    const windowed = async (id: string, seconds: number) => {
+      const [bond, vault] = await createBond(id, {
+        options: bondOptions({ appealWindow: new anchor.BN(seconds) }),
+      });
+      await stakeBond(bond, vault);
+      return [bond, vault] as [PublicKey, PublicKey];
+    };
+    const slashAccounts = (bond: PublicKey, vault: PublicKey) => ({
+      principal: principal.publicKey,
+      bond,
+      bondVault: vault,
+      principalVault: principal.publicKey,
+      stats: statsPDA,
+      config: configPDA,
+      reputation: reputationPDA,
+      systemProgram: SystemProgram.programId,
+    });
+    const request = (bond: PublicKey) =>
+      program.methods
+        .requestEmergencySlash('Agent went silent')
+        .accounts({ principal: principal.publicKey, bond, config: configPDA })
+        .signers([principal])
+        .rpc();
+    const execute = (bond: PublicKey, vault: PublicKey) =>
+      program.methods
+        .executeEmergencySlash()
+        .accounts(slashAccounts(bond, vault))
+        .signers([principal])
+        .rpc();
+
+    await expectError(
+      createBond('appeal-000', { options: bondOptions({ appealWindow: new anchor.BN(86401) }) }),
+      'InvalidAppealWindow'
+    );
+    // No bond can be slashed on the spot
+    await expectError(
+      createBond('appeal-000', { options: bondOptions({ appealWindow: new anchor.BN(0) }) }),
+      'InvalidAppealWindow'
+    );
+    const [shortBond, shortVault] = await windowed('appeal-001', 2);
+    const [longBond, longVault] = await windowed('appeal-002', 3600);
+
+    await expectError(execute(shortBond, shortVault), 'SlashNotRequested');
+
+    await request(shortBond);
+    await request(longBond);
+    await expectError(execute(shortBond, shortVault), 'AppealWindowOpen');
+    await sleep(3000);
+
+    await execute(shortBond, shortVault);
+    expect((await program.account.bond.fetch(shortBond)).status).to.deep.equal({ slashed: {} });
+    await expectError(execute(longBond, longVault), 'AppealWindowOpen');
+    expect((await program.account.bond.fetch(longBond)).status).to.deep.equal({ slashRequested: {} });
+  });
+
+  it('Audits a funded vault against the bond accounting', async () => {
+    const [bond, vault] = await createBond('audit-001', {
+      options: bondOptions({ keeperBounty: new anchor.BN(0.01 * LAMPORTS_PER_SOL) }),
+    });
+    await stakeBond(bond, vault);
+    await program.methods
+      .fundTips(new anchor.BN(0.02 * LAMPORTS_PER_SOL))
+      .accounts({
+        principal: principal.publicKey,
+        bond,
+        bondVault: vault,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([principal])
+      .rpc();
+
+    const audit = await program.methods.auditVault().accounts({ bond, bondVault: vault }).view();
+    expect(audit.expected.toNumber()).to.equal(0.13 * LAMPORTS_PER_SOL);
+    expect(audit.actual.toNumber()).to.equal(await provider.connection.getBalance(vault));
+    expect(audit.discrepancy.toNumber()).to.equal(0);
+  });
+
+  it('Lets the principal cancel a bond only before the agent stakes', async () => {
+    const cancel = (bond: PublicKey, vault: PublicKey) =>
+      program.methods
+        .cancelBond()
+        .accounts({
+          principal: principal.publicKey,
+          bond,
+          bondVault: vault,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([principal])
+        .rpc();
+
+    const [pendingBond, pendingVault] = await createBond('cancel-001', {
+      options: bondOptions({ keeperBounty: new anchor.BN(0.01 * LAMPORTS_PER_SOL) }),
+    });
+    const refund =
+      (await provider.connection.getBalance(pendingBond)) +
+      (await provider.connection.getBalance(pendingVault));
+    const principalBefore = await provider.connection.getBalance(principal.publicKey);
+    await cancel(pendingBond, pendingVault);
+    expect(await provider.connection.getBalance(principal.publicKey) - principalBefore).to.equal(
+      refund
+    );
+    expect(await provider.connection.getAccountInfo(pendingBond)).to.equal(null);
+    expect(await provider.connection.getBalance(pendingVault)).to.equal(0);
+
+    const [stakedBond, stakedVault] = await createBond('cancel-002');
+    await stakeBond(stakedBond, stakedVault);
+    await expectError(cancel(stakedBond, stakedVault), 'CannotCancelActiveBond');
+  });
+
+  it('Only lets verifiers vote in the categories they opted into', async () => {
+    const specialist = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(specialist.publicKey, LAMPORTS_PER_SOL)
+    );
+    await registerVerifier(specialist);
+    const [profile] = PublicKey.findProgramAddressSync(
+      [Buffer.from('verifier'), specialist.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .setVerifierCategories(Buffer.from([2]))
+      .accounts({ verifier: specialist.publicKey, profile })
+      .signers([specialist])
+      .rpc();
+
+    const voteAs = (bond: PublicKey, withProfile = true) =>
+      program.methods
+        .verifyWork(true)
+        .accounts({ verifier: specialist.publicKey, bond })
+        .remainingAccounts(voterAccounts(bond, specialist.publicKey).slice(0, withProfile ? 2 : 1))
+        .signers([specialist])
+        .rpc();
+
+    const [ownCategory] = await setupBond('category-001', bondOptions({ category: 2 }));
+    const [otherCategory] = await setupBond('category-002', bondOptions({ category: 3 }));
+
+    await expectError(voteAs(ownCategory, false), 'VerifierProfileMissing');
+    await voteAs(ownCategory);
+    await expectError(voteAs(otherCategory), 'CategoryNotOptedIn');
+    expect((await program.account.bond.fetch(ownCategory)).verificationVotes.length).to.equal(1);
+
+    // Blind voting checks the opt-in at commit time too
+    const blind = (category: number) =>
+      bondOptions({
+        category,
+        voteMode: { blind: {} },
+        commitWindow: new anchor.BN(60),
+        revealWindow: new anchor.BN(60),
+      });
+    const [ownBlind] = await setupBond('category-003', blind(2));
+    const [otherBlind] = await setupBond('category-004', blind(3));
+    const commitAs = (bond: PublicKey, withProfile = true) =>
+      program.methods
+        .commitVote(Array.from(Buffer.alloc(32, 9)))
+        .accounts({ verifier: specialist.publicKey, bond })
+        .remainingAccounts(withProfile ? [{ pubkey: profile, isSigner: false, isWritable: false }] : [])
+        .signers([specialist])
+        .rpc();
+    await expectError(commitAs(ownBlind, false), 'CategoryNotOptedIn');
+    await expectError(commitAs(otherBlind), 'CategoryNotOptedIn');
+    await commitAs(ownBlind);
+    expect((await program.account.bond.fetch(ownBlind)).voteCommits.length).to.equal(1);
+  });
+
+  it('Settles a bond from a Merkle-proven off-chain tally', async () => {
+    const oracle = verifier2;
+    const [tallyBond, tallyVault] = await setupBond('tally-001');
+
+    // Four off-chain votes, three approving, hashed into a sorted-pair tree
+    const voters = Array.from({ length: 4 }, () => Keypair.generate().publicKey);
+    const approvals = [true, true, false, true];
+    const hashPair = (a: Buffer, b: Buffer) =>
+      Buffer.from(keccak_256(Buffer.compare(a, b) <= 0 ? Buffer.concat([a, b]) : Buffer.concat([b, a])));
+    const leafOf = (index: number, verifier: PublicKey, approve: boolean) => {
+      const position = Buffer.alloc(4);
+      position.writeUInt32LE(index);
+      return Buffer.from(
+        keccak_256(
+          Buffer.concat([tallyBond.toBuffer(), position, verifier.toBuffer(), Buffer.from([approve ? 1 : 0])])
+        )
+      );
+    };
+    const leaves = voters.map((v, i) => leafOf(i, v, approvals[i]));
+    const nodes = [hashPair(leaves[0], leaves[1]), hashPair(leaves[2], leaves[3])];
+    const root = Array.from(hashPair(nodes[0], nodes[1]));
+    const proofFor = (i: number, approve = approvals[i]) => ({
+      index: i,
+      verifier: voters[i],
+      approve,
+      path: [Array.from(leaves[i ^ 1]), Array.from(nodes[1 - (i >> 1)])],
+    });
+
+    const commit = (approveCount: number, total: number) =>
+      program.methods
+        .commitTally(root, approveCount, total)
+        .accounts({ oracle: oracle.publicKey, bond: tallyBond, config: configPDA })
+        .signers([oracle])
+        .rpc();
+    const settle = (proofs: object[]) =>
+      program.methods
+        .finalizeFromTally(proofs)
+        .accounts({
+          oracle: oracle.publicKey,
+          bond: tallyBond,
+          bondVault: tallyVault,
+          agent: agent.publicKey,
+          principal: principal.publicKey,
+          stats: statsPDA,
+          config: configPDA,
+          reputation: reputationPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([{ pubkey: SYSVAR_SLOT_HASHES_PUBKEY, isSigner: false, isWritable: false }])
+        .signers([oracle])
+        .rpc();
+    const allProofs = () => voters.map((_, i) => proofFor(i));
+
+    await updateConfig({ oracle: oracle.publicKey });
+    try {
+      // The root and counts are fixed before the sample is known
+      await expectError(settle(allProofs()), 'TallyNotCommitted');
+      await commit(3, 4);
+      await expectError(commit(4, 4), 'TallyAlreadyCommitted');
+      await sleep(1000);
+
+      // A flipped vote does not hash to the root
+      await expectError(settle([proofFor(0, false)]), 'InvalidTallyProof');
+      // A leaf's position is part of it
+      await expectError(settle([{ ...proofFor(0), index: 1 }]), 'InvalidTallyProof');
+
+      const agentBefore = await provider.connection.getBalance(agent.publicKey);
+      await settle(allProofs());
+
+      const settled = await program.account.bond.fetch(tallyBond);
+      expect(settled.status).to.deep.equal({ completed: {} });
+      expect(settled.verificationVotes.length).to.equal(0);
+      expect(await provider.connection.getBalance(agent.publicKey) - agentBefore).to.equal(
+        0.1 * LAMPORTS_PER_SOL
+      );
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Refuses to close a live bond and reclaims rent once it is slashed', async () => {
+    const [bond, vault] = await setupBond('close-002');
+    const close = () =>
+      program.methods
+        .closeBond()
+        .accounts({
+          principal: principal.publicKey,
+          bond,
+          bondVault: vault,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([principal])
+        .rpc();
+
+    await expectError(close(), 'InvalidBondStatus');
+
+    await vote(bond, verifier1, false);
+    await vote(bond, verifier2, false);
+    await vote(bond, verifier3, false);
+    await finalize(bond, vault);
+    expect((await program.account.bond.fetch(bond)).status).to.deep.equal({ slashed: {} });
+
+    const rent = (await provider.connection.getBalance(bond)) + (await vaultRent());
+    const principalBefore = await provider.connection.getBalance(principal.publicKey);
+    await close();
+    expect(rent).to.be.above(0);
+    expect(await provider.connection.getBalance(principal.publicKey) - principalBefore).to.equal(rent);
+    expect(await provider.connection.getAccountInfo(bond)).to.equal(null);
+  });
+
+  it('Routes the protocol fee on slashed collateral to the treasury', async () => {
+    const treasury = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(treasury.publicKey, LAMPORTS_PER_SOL)
+    );
+    const treasuryAccount = [{ pubkey: treasury.publicKey, isSigner: false, isWritable: true }];
+    const balance = (key: PublicKey) => provider.connection.getBalance(key);
+
+    const emergencySlash = async (bond: PublicKey, vault: PublicKey) => {
+      await concedeSlash(bond);
+      await program.methods
+        .executeEmergencySlash()
+        .accounts({
+          principal: principal.publicKey,
+          bond,
+          bondVault: vault,
+          principalVault: principal.publicKey,
+          stats: statsPDA,
+          config: configPDA,
+          reputation: reputationPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(treasuryAccount)
+        .signers([principal])
+        .rpc();
+    };
+
+    // fee_bps = 0: the principal receives the whole slash
+    const [freeBond, freeVault] = await setupBond('fee-000');
+    let principalBefore = await balance(principal.publicKey);
+    await emergencySlash(freeBond, freeVault);
+    expect(await balance(principal.publicKey) - principalBefore).to.equal(0.1 * LAMPORTS_PER_SOL);
+    expect(await balance(treasury.publicKey)).to.equal(LAMPORTS_PER_SOL);
+
+    await expectError(updateConfig({ feeBps: 500 }), 'InvalidConfig');
+    await updateConfig({ feeBps: 500, treasury: treasury.publicKey });
+    try {
+      // fee_bps = 500: 5% of a majority-vote slash goes to the treasury
+      const [votedBond, votedVault] = await setupBond('fee-001');
+      await vote(votedBond, verifier1, false);
+      await vote(votedBond, verifier2, false);
+      await vote(votedBond, verifier3, false);
+
+      await expectError(finalize(votedBond, votedVault), 'TreasuryMissing');
+
+      principalBefore = await balance(principal.publicKey);
+      await program.methods
+        .finalizeBond()
+        .accounts(finalizeAccounts(votedBond, votedVault))
+        .remainingAccounts(treasuryAccount)
+        .signers([verifier1])
+        .rpc();
+      expect(await balance(treasury.publicKey) - LAMPORTS_PER_SOL).to.equal(0.005 * LAMPORTS_PER_SOL);
+      expect(await balance(principal.publicKey) - principalBefore).to.equal(0.095 * LAMPORTS_PER_SOL);
+
+      // Emergency slashes pay the same fee
+      const [emergencyBond, emergencyVault] = await setupBond('fee-002');
+      principalBefore = await balance(principal.publicKey);
+      await emergencySlash(emergencyBond, emergencyVault);
+      expect(await balance(treasury.publicKey) - LAMPORTS_PER_SOL).to.equal(0.01 * LAMPORTS_PER_SOL);
+      expect(await balance(principal.publicKey) - principalBefore).to.equal(0.095 * LAMPORTS_PER_SOL);
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Warns as the deadline approaches and once it has passed', async () => {
+    const deadline = Math.floor(Date.now() / 1000) + 4;
+    const [bond, vault] = await createBond('poke-001', { deadline });
+    await stakeBond(bond, vault);
+
+    const approaching: any[] = [];
+    const passed: any[] = [];
+    const listeners = [
+      program.addEventListener('DeadlineApproaching', event => {
+        if (event.bondId === 'poke-001') approaching.push(event);
+      }),
+      program.addEventListener('DeadlinePassed', event => {
+        if (event.bondId === 'poke-001') passed.push(event);
+      }),
+    ];
+    const poke = () =>
+      program.methods.pokeBond().accounts({ bond, config: configPDA }).rpc();
+
+    await updateConfig({ deadlineWarningWindow: new anchor.BN(3600) });
+    try {
+      // Pokes are spaced out so repeats are not deduplicated as the same transaction
+      await poke();
+      await sleep(1000);
+      await poke();
+      await sleep(5000);
+      await poke();
+      await sleep(1000);
+      await poke();
+      await sleep(1000);
+    } finally {
+      await updateConfig();
+      await Promise.all(listeners.map(l => program.removeEventListener(l)));
+    }
+
+    // Each notice fires once per deadline
+    expect(approaching.length).to.equal(1);
+    expect(approaching[0].deadline.toNumber()).to.equal(deadline);
+    expect(approaching[0].remaining.toNumber()).to.be.within(0, 4);
+    expect(passed.length).to.equal(1);
+    expect(passed[0].overdueBy.toNumber()).to.be.above(0);
+
+    const poked = await program.account.bond.fetch(bond);
+    expect(poked.deadlineWarned).to.equal(true);
+    expect(poked.deadlinePassedNoted).to.equal(true);
+  });
+
+  it('Keeps finalized bonds for archival or closes them per config', async () => {
+    const finalizeApproved = async (id: string) => {
+      const [bond, vault] = await setupBond(id);
+      await vote(bond, verifier1, true);
+      await vote(bond, verifier2, true);
+      await vote(bond, verifier3, true);
+      const rent = (await provider.connection.getBalance(bond)) + (await vaultRent());
+      const principalBefore = await provider.connection.getBalance(principal.publicKey);
+      await finalize(bond, vault);
+      const refunded = await provider.connection.getBalance(principal.publicKey) - principalBefore;
+      return { bond, rent, refunded };
+    };
+
+    // Default: the bond stays on-chain until close_bond
+    const archived = await finalizeApproved('autoclose-000');
+    expect(archived.refunded).to.equal(0);
+    expect((await program.account.bond.fetch(archived.bond)).status).to.deep.equal({ completed: {} });
+
+    await updateConfig({ autoCloseOnFinalize: true });
+    try {
+      const closed = await finalizeApproved('autoclose-001');
+      expect(closed.refunded).to.equal(closed.rent);
+      expect(await provider.connection.getAccountInfo(closed.bond)).to.equal(null);
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Records reasoned slash votes and counts them toward the slash quorum', async () => {
+    const [bond, vault] = await setupBond('reasoned-001');
+    const voteSlash = (voter: Keypair, reason: string) =>
+      program.methods
+        .voteSlash(reason)
+        .accounts({ verifier: voter.publicKey, bond })
+        .remainingAccounts(voterAccounts(bond, voter.publicKey))
+        .signers([voter])
+        .rpc();
+
+    await expectError(voteSlash(verifier1, 'x'.repeat(101)), 'SlashReasonTooLong');
+    await expectError(voteSlash(agent, 'Self-sabotage'), 'AgentCannotVerify');
+
+    await voteSlash(verifier1, 'Output does not compile');
+    await expectError(voteSlash(verifier1, 'Still broken'), 'AlreadyVoted');
+    await voteSlash(verifier2, 'Tests were deleted');
+    await vote(bond, verifier3, false);
+
+    const voted = await program.account.bond.fetch(bond);
+    expect(voted.slashVotes.map((v: any) => v.reason)).to.deep.equal([
+      'Output does not compile',
+      'Tests were deleted',
+    ]);
+    expect(voted.slashVotes[0].voter.toBase58()).to.equal(verifier1.publicKey.toBase58());
+
+    await finalize(bond, vault);
+    expect((await program.account.bond.fetch(bond)).status).to.deep.equal({ slashed: {} });
+  });
+
+  it('Lets the agent appeal an emergency slash to the verifiers or the arbiter', async () => {
+    const slashAccounts = (bond: PublicKey, vault: PublicKey) => ({
+      principal: principal.publicKey,
+      bond,
+      bondVault: vault,
+      principalVault: principal.publicKey,
+      stats: statsPDA,
+      config: configPDA,
+      reputation: reputationPDA,
+      systemProgram: SystemProgram.programId,
+    });
+    const request = (bond: PublicKey) =>
+      program.methods
+        .requestEmergencySlash('Agent went silent')
+        .accounts({ principal: principal.publicKey, bond, config: configPDA })
+        .signers([principal])
+        .rpc();
+    const execute = (bond: PublicKey, vault: PublicKey) =>
+      program.methods
+        .executeEmergencySlash()
+        .accounts(slashAccounts(bond, vault))
+        .signers([principal])
+        .rpc();
+    const appeal = (bond: PublicKey, signer = agent) =>
+      program.methods
+        .appealEmergencySlash()
+        .accounts({ agent: signer.publicKey, bond, config: configPDA })
+        .signers([signer])
+        .rpc();
+
+    const [bond, vault] = await setupBond(
+      'appeal-003',
+      bondOptions({ appealWindow: new anchor.BN(86400) })
+    );
+    const before = await program.account.bond.fetch(bond);
+    await request(bond);
+    expect((await program.account.bond.fetch(bond)).status).to.deep.equal({ slashRequested: {} });
+
+    // Frozen: no votes, and execution must wait out the full window
+    await expectError(vote(bond, verifier1, true), 'InvalidBondStatus');
+    await expectError(execute(bond, vault), 'AppealWindowOpen');
+
+    await expectError(appeal(bond, principal), 'UnauthorizedAgent');
+
+    // Without an arbiter the appeal goes back to the verifiers' vote
+    const [votedBond, votedVault] = await setupBond(
+      'appeal-005',
+      bondOptions({ appealWindow: new anchor.BN(86400) })
+    );
+    await request(votedBond);
+    await appeal(votedBond);
+    expect((await program.account.bond.fetch(votedBond)).status).to.deep.equal({
+      pendingVerification: {},
+    });
+    await expectError(request(votedBond), 'SlashAlreadyAppealed');
+    await vote(votedBond, verifier1, true);
+    await vote(votedBond, verifier2, true);
+    await vote(votedBond, verifier3, true);
+    await finalize(votedBond, votedVault);
+    expect((await program.account.bond.fetch(votedBond)).status).to.deep.equal({ completed: {} });
+
+    await updateConfig({ arbiter: provider.wallet.publicKey });
+    try {
+      await appeal(bond);
+      const appealed = await program.account.bond.fetch(bond);
+      expect(appealed.status).to.deep.equal({ disputed: {} });
+      expect(appealed.slashRequestedAt.toNumber()).to.equal(0);
+      expect(appealed.deadline.toNumber()).to.be.at.least(before.deadline.toNumber());
+      await expectError(execute(bond, vault), 'SlashNotRequested');
+      await expectError(request(bond), 'InvalidBondStatus');
+      await expectError(vote(bond, verifier1, true), 'InvalidBondStatus');
+
+      // The arbiter, not the principal, now decides the bond
+      await program.methods
+        .resolveDispute(true)
+        .accounts({
+          arbiter: provider.wallet.publicKey,
+          bond,
+          bondVault: vault,
+          agent: agent.publicKey,
+          principal: principal.publicKey,
+          stats: statsPDA,
+          config: configPDA,
+          reputation: reputationPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      expect((await program.account.bond.fetch(bond)).status).to.deep.equal({ completed: {} });
+
+      // Once the window lapses the appeal is gone and the slash goes through
+      const [lapsedBond, lapsedVault] = await setupBond(
+        'appeal-004',
+        bondOptions({ appealWindow: new anchor.BN(2) })
+      );
+      await request(lapsedBond);
+      await sleep(3000);
+      await expectError(appeal(lapsedBond), 'AppealWindowClosed');
+      await execute(lapsedBond, lapsedVault);
+      expect((await program.account.bond.fetch(lapsedBond)).status).to.deep.equal({ slashed: {} });
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Requires the designated escrow authority to co-sign vault releases', async () => {
+    const escrow = Keypair.generate();
+    const [bond, vault] = await setupBond(
+      'escrow-001',
+      bondOptions({ escrowAuthority: escrow.publicKey })
+    );
+    await vote(bond, verifier1, true);
+    await vote(bond, verifier2, true);
+    await vote(bond, verifier3, true);
+
+    await expectError(finalize(bond, vault), 'EscrowSignatureMissing');
+
+    const agentBefore = await provider.connection.getBalance(agent.publicKey);
+    await program.methods
+      .finalizeBond()
+      .accounts(finalizeAccounts(bond, vault))
+      .remainingAccounts([{ pubkey: escrow.publicKey, isSigner: true, isWritable: false }])
+      .signers([verifier1, escrow])
+      .rpc();
+
+    expect((await program.account.bond.fetch(bond)).status).to.deep.equal({ completed: {} });
+    expect(await provider.connection.getBalance(agent.publicKey) - agentBefore).to.equal(
+      0.1 * LAMPORTS_PER_SOL
+    );
+
+    // A collateral reduction pays out of the vault too
+    const [reduced, reducedVault] = await setupBond(
+      'escrow-002',
+      bondOptions({ escrowAuthority: escrow.publicKey })
+    );
+    const reduce = (cosigned: boolean) =>
+      program.methods
+        .reduceCollateral(new anchor.BN(0.04 * LAMPORTS_PER_SOL))
+        .accounts({
+          principal: principal.publicKey,
+          agent: agent.publicKey,
+          bond: reduced,
+          bondVault: reducedVault,
+          stats: statsPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          cosigned ? [{ pubkey: escrow.publicKey, isSigner: true, isWritable: false }] : []
+        )
+        .signers(cosigned ? [principal, agent, escrow] : [principal, agent])
+        .rpc();
+    await expectError(reduce(false), 'EscrowSignatureMissing');
+    await reduce(true);
+    expect((await program.account.bond.fetch(reduced)).collateralAmount.toNumber()).to.equal(
+      0.04 * LAMPORTS_PER_SOL
+    );
+  });
+
+  it('Estimates verifier participation that grows with the reward', async () => {
+    const estimate = (reward: number) =>
+      program.methods
+        .estimateParticipation(new anchor.BN(reward))
+        .accounts({ stats: statsPDA, config: configPDA })
+        .view();
+
+    const rewards = [0, 1_000_000, 2_500_000, 5_000_000, 1_000_000_000, 1_000_000_000_000];
+    const estimates = [];
+    for (const reward of rewards) {
+      estimates.push(await estimate(reward));
+    }
+    const counts = estimates.map(e => e.expectedVerifiers.toNumber());
+
+    expect(counts[0]).to.equal(0);
+    expect(estimates[0].reachesQuorum).to.equal(false);
+    for (let i = 1; i < counts.length; i++) {
+      expect(counts[i]).to.be.at.least(counts[i - 1]);
+    }
+    // Saturates at the per-bond vote cap
+    expect(counts[counts.length - 1]).to.be.at.most(10);
+    expect(estimates[estimates.length - 1].reachesQuorum).to.equal(true);
+  });
+
+  it('Accepts ten votes and cleanly rejects the eleventh', async () => {
+    const [bond] = await setupBond('vote-cap-001');
+    const voters = Array.from({ length: 11 }, () => Keypair.generate());
+    for (const voter of voters.slice(0, 10)) {
+      await vote(bond, voter, true);
+    }
+
+    await expectError(vote(bond, voters[10], true), 'VoteLimitReached');
+    await expectError(
+      program.methods
+        .voteSlash('Late objection')
+        .accounts({ verifier: voters[10].publicKey, bond })
+        .signers([voters[10]])
+        .rpc(),
+      'VoteLimitReached'
+    );
+    expect((await program.account.bond.fetch(bond)).verificationVotes.length).to.equal(10);
+  });
+
+  it('Caps deadline extensions and rejects them once proof is in', async () => {
+    const deadline = Math.floor(Date.now() / 1000) + 86400;
+    const [bond, vault] = await createBond('extend-cap-001', { deadline });
+    await stakeBond(bond, vault);
+    const extend = (target: PublicKey, newDeadline: number) =>
+      program.methods
+        .extendDeadline(new anchor.BN(newDeadline))
+        .accounts({ principal: principal.publicKey, bond: target })
+        .signers([principal])
+        .rpc();
+
+    const extended: any[] = [];
+    const listener = program.addEventListener('DeadlineExtended', event => {
+      if (event.bondId === 'extend-cap-001') extended.push(event);
+    });
+    for (let i = 1; i <= 3; i++) {
+      await extend(bond, deadline + i * 3600);
+    }
+    await expectError(extend(bond, deadline + 4 * 3600), 'ExtensionLimitReached');
+    await sleep(1000);
+    await program.removeEventListener(listener);
+
+    expect(extended.length).to.equal(3);
+    expect(extended[0].oldDeadline.toNumber()).to.equal(deadline);
+    expect(extended[2].newDeadline.toNumber()).to.equal(deadline + 3 * 3600);
+    const capped = await program.account.bond.fetch(bond);
+    expect(capped.extensionCount).to.equal(3);
+    expect(capped.deadline.toNumber()).to.equal(deadline + 3 * 3600);
+
+    const [pendingBond] = await setupBond('extend-pending-001');
+    await expectError(extend(pendingBond, deadline + 3600), 'InvalidBondStatus');
+  });
+
+  it('Compensates a premium-paying agent after a wrongful slash ruling', async () => {
+    const [bond, vault] = await createBond('agent-cover-001');
+    const payPremium = () =>
+      program.methods
+        .payAgentPremium()
+        .accounts({
+          agent: agent.publicKey,
+          bond,
+          config: configPDA,
+          insurancePool: insurancePoolPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+    const claim = () =>
+      program.methods
+        .claimAgentInsurance()
+        .accounts({
+          agent: agent.publicKey,
+          bond,
+          insurancePool: insurancePoolPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+
+    // Cover is off by default
+    await expectError(payPremium(), 'AgentCoverUnavailable');
+
+    await updateConfig({
+      agentPremiumBps: 100,
+      agentCoverMultiple: 50,
+      arbiter: provider.wallet.publicKey,
+    });
+    try {
+      const poolBefore = await provider.connection.getBalance(insurancePoolPDA);
+      await payPremium();
+      expect(await provider.connection.getBalance(insurancePoolPDA) - poolBefore).to.equal(
+        0.001 * LAMPORTS_PER_SOL
+      );
+      await expectError(payPremium(), 'AgentPremiumAlreadyPaid');
+
+      await stakeBond(bond, vault);
+      await submitProof(bond, 'https://example.com/proof/agent-cover-001');
+      await vote(bond, verifier1, false);
+      await vote(bond, verifier2, false);
+      await vote(bond, verifier3, false);
+      await finalize(bond, vault);
+      await expectError(claim(), 'NoInsuranceDue');
+
+      // Top the pool up so it can cover the whole payout
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: provider.wallet.publicKey,
+            toPubkey: insurancePoolPDA,
+            lamports: 0.1 * LAMPORTS_PER_SOL,
+          })
+        )
+      );
+
+      await program.methods
+        .ruleWrongfulSlash()
+        .accounts({ arbiter: provider.wallet.publicKey, bond, config: configPDA })
+        .rpc();
+      // The 0.1 SOL loss is capped at 50 times the 0.001 SOL premium
+      const ruled = await program.account.bond.fetch(bond);
+      expect(ruled.agentCompensationDue.toNumber()).to.equal(0.05 * LAMPORTS_PER_SOL);
+
+      const agentBefore = await provider.connection.getBalance(agent.publicKey);
+      await claim();
+      expect(await provider.connection.getBalance(agent.publicKey) - agentBefore).to.equal(
+        0.05 * LAMPORTS_PER_SOL
+      );
+      expect((await program.account.bond.fetch(bond)).agentCompensationDue.toNumber()).to.equal(0);
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Blocks emergency slashes once an approve quorum has formed', async () => {
+    const [bond, vault] = await setupBond('approved-001');
+
+    await vote(bond, verifier1, true);
+    await vote(bond, verifier2, true);
+    await vote(bond, verifier3, true);
+    await expectError(concedeSlash(bond), 'ApprovalQuorumReached');
+
+    // The approved bond still finalizes in the agent's favour
+    await finalize(bond, vault);
+    expect((await program.account.bond.fetch(bond)).status).to.deep.equal({ completed: {} });
+  });
+
+  it('Releases an approved milestone and slashes only the remainder', async () => {
+    const milestones = [
+      { description: 'Design', amount: new anchor.BN(0.04 * LAMPORTS_PER_SOL) },
+      { description: 'Delivery', amount: new anchor.BN(0.06 * LAMPORTS_PER_SOL) },
+    ];
+    await expectError(
+      createBond('milestone-000', {
+        options: bondOptions({ milestones: milestones.slice(0, 1) }),
+      }),
+      'InvalidMilestones'
+    );
+
+    const [bond, vault] = await createBond('milestone-001', {
+      options: bondOptions({ milestones }),
+    });
+    await stakeBond(bond, vault);
+
+    const approve = (index: number, verifier: Keypair) =>
+      program.methods
+        .approveMilestone(index)
+        .accounts({
+          verifier: verifier.publicKey,
+          bond,
+          profile: deriveProfile(verifier.publicKey),
+          verifierStake: deriveStake(bond, verifier.publicKey),
+          config: configPDA,
+        })
+        .signers([verifier])
+        .rpc();
+    const release = (index: number) =>
+      program.methods
+        .releaseMilestone(index)
+        .accounts({
+          executor: provider.wallet.publicKey,
+          bond,
+          config: configPDA,
+          bondVault: vault,
+          agent: agent.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    // Only registered verifiers with stake on the bond, once work is submitted
+    for (const verifier of [verifier1, verifier2, verifier3]) {
+      await ensureRegistered(verifier);
+    }
+    await expectError(approve(0, verifier1), 'AccountNotInitialized');
+    for (const verifier of [verifier1, verifier2, verifier3]) {
+      await program.methods
+        .stakeVerifier(new anchor.BN(LAMPORTS_PER_SOL / 100))
+        .accounts({
+          verifier: verifier.publicKey,
+          bond,
+          verifierStake: deriveStake(bond, verifier.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([verifier])
+        .rpc();
+    }
+    await expectError(approve(0, verifier1), 'ProofRequired');
+    await submitProof(bond, 'https://example.com/proof/milestone-001');
+
+    await approve(0, verifier1);
+    await approve(0, verifier2);
+    await expectError(release(0), 'MilestoneNotApproved');
+    await expectError(approve(0, agent), 'AccountNotInitialized');
+    await approve(0, verifier3);
+
+    const agentBefore = await provider.connection.getBalance(agent.publicKey);
+    await release(0);
+    expect(await provider.connection.getBalance(agent.publicKey) - agentBefore).to.equal(
+      0.04 * LAMPORTS_PER_SOL
+    );
+    await expectError(release(0), 'MilestoneAlreadyReleased');
+    await expectError(release(1), 'MilestoneNotApproved');
+
+    // The slash takes only the unreleased milestone
+    await vote(bond, verifier1, false);
+    await vote(bond, verifier2, false);
+    await vote(bond, verifier3, false);
+    const principalBefore = await provider.connection.getBalance(principal.publicKey);
+    await finalize(bond, vault);
+
+    expect(await provider.connection.getBalance(principal.publicKey) - principalBefore).to.equal(
+      0.06 * LAMPORTS_PER_SOL
+    );
+    const slashed = await program.account.bond.fetch(bond);
+    expect(slashed.status).to.deep.equal({ slashed: {} });
+    expect(slashed.milestones[0].released).to.equal(true);
+    expect(slashed.milestones[1].released).to.equal(false);
+    expect(slashed.totalReleased.toNumber()).to.equal(0.04 * LAMPORTS_PER_SOL);
+  });
+
+  it('Formats a collateral amount using the mint decimals', async () => {
+    const payer = (provider.wallet as anchor.Wallet).payer;
+    const usdcLike = await createMint(provider.connection, payer, payer.publicKey, null, 6);
+    const format = (amount: number) =>
+      program.methods
+        .formatCollateral(new anchor.BN(amount))
+        .accounts({ mint: usdcLike })
+        .view();
+
+    expect(await format(1_500_000)).to.equal('1.500000');
+    expect(await format(42)).to.equal('0.000042');
+    expect(await format(0)).to.equal('0.000000');
+  });
+
+  it('Counts completions, slashes and cleared collateral per agent', async () => {
+    const repAgent = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(repAgent.publicKey, LAMPORTS_PER_SOL)
+    );
+    const [repPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('reputation'), repAgent.publicKey.toBuffer()],
+      program.programId
+    );
+    const [repPair] = PublicKey.findProgramAddressSync(
+      [Buffer.from('pair'), principal.publicKey.toBuffer(), repAgent.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const runBond = async (id: string, approve: boolean) => {
+      const [bond, vault] = deriveBond(id);
+      await program.methods
+        .createBond(
+          id,
+          'Reputation task',
+          new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+          new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+          bondOptions()
+        )
+        .accounts({
+          principal: principal.publicKey,
+          agent: repAgent.publicKey,
+          bond,
+          bondVault: vault,
+          stats: statsPDA,
+          config: configPDA,
+          reputation: repPDA,
+          pair: repPair,
+          reservation: deriveReservation(id),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([principal])
+        .rpc();
+      await program.methods
+        .stakeCollateral()
+        .accounts({
+          agent: repAgent.publicKey,
+          bond,
+          bondVault: vault,
+          stakingVault: null,
+          stats: statsPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([repAgent])
+        .rpc();
+      await program.methods
+        .submitProof(`https://example.com/proof/${id}`)
+        .accounts({ agent: repAgent.publicKey, bond, config: configPDA })
+        .signers([repAgent])
+        .rpc();
+      for (const verifier of [verifier1, verifier2, verifier3]) {
+        await vote(bond, verifier, approve);
+      }
+      await program.methods
+        .finalizeBond()
+        .accounts({
+          ...finalizeAccounts(bond, vault),
+          agent: repAgent.publicKey,
+          reputation: repPDA,
+        })
+        .signers([verifier1])
+        .rpc();
+    };
+
+    await runBond('rep-count-001', true);
+    await runBond('rep-count-002', true);
+    await runBond('rep-count-003', false);
+
+    const reputation = await program.account.agentReputation.fetch(repPDA);
+    expect(reputation.bondsCompleted.toNumber()).to.equal(2);
+    expect(reputation.bondsSlashed.toNumber()).to.equal(1);
+    expect(reputation.totalCollateralCleared.toNumber()).to.equal(0.2 * LAMPORTS_PER_SOL);
+  });
+
+  it('Splits a slash between syndicated principals by what they funded', async () => {
+    const coPrincipal = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(coPrincipal.publicKey, LAMPORTS_PER_SOL)
+    );
+    await expectError(
+      createBond('syndicate-000', {
+        options: bondOptions({ syndicate: [coPrincipal.publicKey, coPrincipal.publicKey] }),
+      }),
+      'InvalidSyndicate'
+    );
+
+    const [bond, vault] = await setupBond(
+      'syndicate-001',
+      bondOptions({ syndicate: [coPrincipal.publicKey] })
+    );
+    const join = (member: Keypair, amount: number) =>
+      program.methods
+        .joinSyndicate(new anchor.BN(amount))
+        .accounts({
+          member: member.publicKey,
+          bond,
+          bondVault: vault,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member])
+        .rpc();
+
+    // Only invited co-principals may join, and they pay in to do so
+    await expectError(join(verifier1, 0.004 * LAMPORTS_PER_SOL), 'NotSyndicateMember');
+    await program.methods
+      .fundTips(new anchor.BN(0.006 * LAMPORTS_PER_SOL))
+      .accounts({
+        principal: principal.publicKey,
+        bond,
+        bondVault: vault,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([principal])
+      .rpc();
+    await join(coPrincipal, 0.004 * LAMPORTS_PER_SOL);
+    const joined = await program.account.bond.fetch(bond);
+    expect(joined.syndicate[0].contribution.toNumber()).to.equal(0.004 * LAMPORTS_PER_SOL);
+    expect(joined.tipPool.toNumber()).to.equal(0.01 * LAMPORTS_PER_SOL);
+
+    await vote(bond, verifier1, false);
+    await vote(bond, verifier2, false);
+    await vote(bond, verifier3, false);
+
+    await expectError(finalize(bond, vault), 'SyndicateMemberMissing');
+
+    const principalBefore = await provider.connection.getBalance(principal.publicKey);
+    const coBefore = await provider.connection.getBalance(coPrincipal.publicKey);
+    await program.methods
+      .finalizeBond()
+      .accounts(finalizeAccounts(bond, vault))
+      .remainingAccounts([{ pubkey: coPrincipal.publicKey, isSigner: false, isWritable: true }])
+      .signers([verifier1])
+      .rpc();
+
+    // 60/40 by contribution, for the slashed collateral and the unearned tips alike
+    expect(await provider.connection.getBalance(coPrincipal.publicKey) - coBefore).to.equal(
+      0.044 * LAMPORTS_PER_SOL
+    );
+    expect(await provider.connection.getBalance(principal.publicKey) - principalBefore).to.equal(
+      0.066 * LAMPORTS_PER_SOL
+    );
+
+    // A principal who funded nothing still counts for a 0.001 SOL base
+    const [unfunded, unfundedVault] = await setupBond(
+      'syndicate-002',
+      bondOptions({ syndicate: [coPrincipal.publicKey] })
+    );
+    await program.methods
+      .joinSyndicate(new anchor.BN(1))
+      .accounts({
+        member: coPrincipal.publicKey,
+        bond: unfunded,
+        bondVault: unfundedVault,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([coPrincipal])
+      .rpc();
+    await vote(unfunded, verifier1, false);
+    await vote(unfunded, verifier2, false);
+    await vote(unfunded, verifier3, false);
+
+    const unfundedPrincipalBefore = await provider.connection.getBalance(principal.publicKey);
+    const tokenBefore = await provider.connection.getBalance(coPrincipal.publicKey);
+    await program.methods
+      .finalizeBond()
+      .accounts(finalizeAccounts(unfunded, unfundedVault))
+      .remainingAccounts([{ pubkey: coPrincipal.publicKey, isSigner: false, isWritable: true }])
+      .signers([verifier1])
+      .rpc();
+    // 0.1 SOL + 1 lamport split 1_000_000 : 1
+    expect(await provider.connection.getBalance(coPrincipal.publicKey) - tokenBefore).to.equal(100);
+    expect(
+      await provider.connection.getBalance(principal.publicKey) - unfundedPrincipalBefore
+    ).to.equal(0.1 * LAMPORTS_PER_SOL + 1 - 100);
+  });
+
+  it('Refuses to stake a bond whose deadline has already passed', async () => {
+    const [bond, vault] = await createBond('stale-stake-001', {
+      deadline: Math.floor(Date.now() / 1000) + 2,
+    });
+    await sleep(4000);
+
+    await expectError(stakeBond(bond, vault), 'DeadlineExceeded');
+    expect((await program.account.bond.fetch(bond)).status).to.deep.equal({ pending: {} });
+    expect(await provider.connection.getBalance(vault)).to.equal(await vaultRent());
+  });
+
+  it('Lets a reserver create the bond id it reserved', async () => {
+    const id = 'reserve-001';
+    await program.methods
+      .reserveBondId(Array.from(keccak_256(Buffer.from(id))))
+      .accounts({
+        reserver: principal.publicKey,
+        reservation: deriveReservation(id),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([principal])
+      .rpc();
+
+    const reservation = await program.account.bondReservation.fetch(deriveReservation(id));
+    expect(reservation.reserver.toBase58()).to.equal(principal.publicKey.toBase58());
+
+    // Reserving again while the claim is live fails, even for the reserver
+    await expectError(
+      program.methods
+        .reserveBondId(Array.from(keccak_256(Buffer.from(id))))
+        .accounts({
+          reserver: principal.publicKey,
+          reservation: deriveReservation(id),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([principal])
+        .rpc(),
+      'BondIdReserved'
+    );
+
+    // Creating the bond reveals the id and closes the reservation
+    const [bond] = await createBond(id);
+    expect((await program.account.bond.fetch(bond)).principal.toBase58()).to.equal(
+      principal.publicKey.toBase58()
+    );
+    expect(await provider.connection.getAccountInfo(deriveReservation(id))).to.equal(null);
+  });
+
+  it('Blocks other principals from a reserved bond id', async () => {
+    const id = 'reserve-002';
+    const reserver = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(reserver.publicKey, LAMPORTS_PER_SOL)
+    );
+    await program.methods
+      .reserveBondId(Array.from(keccak_256(Buffer.from(id))))
+      .accounts({
+        reserver: reserver.publicKey,
+        reservation: deriveReservation(id),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([reserver])
+      .rpc();
+
+    await expectError(createBond(id), 'BondIdReserved');
+    expect(await provider.connection.getAccountInfo(deriveBond(id)[0])).to.equal(null);
+  });
+
+  it('Rejects a bond where the principal is also the agent', async () => {
+    const id = 'self-bond-001';
+    const [bond, vault] = deriveBond(id);
+    const [selfReputation] = PublicKey.findProgramAddressSync(
+      [Buffer.from('reputation'), principal.publicKey.toBuffer()],
+      program.programId
+    );
+    const [selfPair] = PublicKey.findProgramAddressSync(
+      [Buffer.from('pair'), principal.publicKey.toBuffer(), principal.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await expectError(
+      program.methods
+        .createBond(
+          id,
+          'Self-bond task',
+          new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+          new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+          bondOptions()
+        )
+        .accounts({
+          principal: principal.publicKey,
+          agent: principal.publicKey,
+          bond,
+          bondVault: vault,
+          stats: statsPDA,
+          config: configPDA,
+          reputation: selfReputation,
+          pair: selfPair,
+          reservation: deriveReservation(id),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([principal])
+        .rpc(),
+      'PrincipalCannotBeAgent'
+    );
+    expect(await provider.connection.getAccountInfo(bond)).to.equal(null);
+  });
+
+  it('Lets a crank call try_finalize before a bond is ready without failing', async () => {
+    const [bond, vault] = await setupBond('crank-001');
+    await vote(bond, verifier1, true);
+
+    await program.methods
+      .tryFinalize()
+      .accounts(finalizeAccounts(bond, vault))
+      .signers([verifier1])
+      .rpc();
+    expect((await program.account.bond.fetch(bond)).status).to.deep.equal({
+      pendingVerification: {},
+    });
+    expect(await provider.connection.getBalance(vault)).to.be.greaterThan(0);
+  });
+
+  it('Leaves a bond alone through try_finalize while finalize_bond would refuse it', async () => {
+    const crank = (bond: PublicKey, vault: PublicKey) =>
+      program.methods
+        .tryFinalize()
+        .accounts(finalizeAccounts(bond, vault))
+        .signers([verifier1])
+        .rpc();
+    const setPaused = (paused: boolean) =>
+      program.methods
+        .setPaused(paused)
+        .accounts({ admin: provider.wallet.publicKey, config: configPDA })
+        .rpc();
+
+    // A slash while paused
+    const [slashBond, slashVault] = await setupBond('crank-003');
+    await vote(slashBond, verifier1, false);
+    await vote(slashBond, verifier2, false);
+    await vote(slashBond, verifier3, false);
+    await setPaused(true);
+    try {
+      expect(
+        await program.methods
+          .canFinalize(verifier1.publicKey)
+          .accounts({ bond: slashBond, config: configPDA })
+          .view()
+      ).to.equal(false);
+      await crank(slashBond, slashVault);
+      expect((await program.account.bond.fetch(slashBond)).status).to.deep.equal({
+        pendingVerification: {},
+      });
+    } finally {
+      await setPaused(false);
+    }
+    await crank(slashBond, slashVault);
+    expect((await program.account.bond.fetch(slashBond)).status).to.deep.equal({ slashed: {} });
+
+    // An escrow that has not co-signed
+    const escrow = Keypair.generate();
+    const [escrowBond, escrowVault] = await setupBond(
+      'crank-004',
+      bondOptions({ escrowAuthority: escrow.publicKey })
+    );
+    await vote(escrowBond, verifier1, true);
+    await vote(escrowBond, verifier2, true);
+    await vote(escrowBond, verifier3, true);
+    await crank(escrowBond, escrowVault);
+    expect((await program.account.bond.fetch(escrowBond)).status).to.deep.equal({
+      pendingVerification: {},
+    });
+  });
+
+  it('Finalizes through try_finalize once quorum is reached', async () => {
+    const [bond, vault] = await setupBond('crank-002');
+    await vote(bond, verifier1, true);
+    await vote(bond, verifier2, true);
+    await vote(bond, verifier3, true);
+
+    const agentBefore = await provider.connection.getBalance(agent.publicKey);
+    await program.methods
+      .tryFinalize()
+      .accounts(finalizeAccounts(bond, vault))
+      .signers([verifier1])
+      .rpc();
+
+    expect((await program.account.bond.fetch(bond)).status).to.deep.equal({ completed: {} });
+    expect(await provider.connection.getBalance(agent.publicKey) - agentBefore).to.equal(
+      0.1 * LAMPORTS_PER_SOL
+    );
+  });
+
+  it('Pays approving verifiers a share of a completed bond', async () => {
+    const treasury = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(treasury.publicKey, LAMPORTS_PER_SOL)
+    );
+    const balance = (key: PublicKey) => provider.connection.getBalance(key);
+
+    await expectError(updateConfig({ verifierRewardBps: 1000 }), 'InvalidConfig');
+    await updateConfig({ verifierRewardBps: 1000, treasury: treasury.publicKey });
+    try {
+      const [bond, vault] = await setupBond('verifier-reward-001');
+      const verifiers = [verifier1, verifier2, verifier3];
+      for (const verifier of verifiers) {
+        await vote(bond, verifier, true);
+      }
+
+      const before = await Promise.all(verifiers.map(v => balance(v.publicKey)));
+      const agentBefore = await balance(agent.publicKey);
+      // verifier3's wallet is left out; its share waits in the bond account
+      await program.methods
+        .finalizeBond()
+        .accounts(finalizeAccounts(bond, vault))
+        .remainingAccounts(
+          [verifier1.publicKey, verifier2.publicKey, treasury.publicKey].map(pubkey => ({
+            pubkey,
+            isSigner: false,
+            isWritable: true,
+          }))
+        )
+        .signers([verifier1])
+        .rpc();
+      expect(await balance(verifier3.publicKey)).to.equal(before[2]);
+      expect((await program.account.bond.fetch(bond)).deferredRewards).to.equal(1 << 2);
+
+      const claimDeferred = (verifier: Keypair) =>
+        program.methods
+          .claimDeferredReward()
+          .accounts({ verifier: verifier.publicKey, bond })
+          .rpc();
+      await expectError(claimDeferred(verifier1), 'NoRewardDue');
+      await claimDeferred(verifier3);
+      await expectError(claimDeferred(verifier3), 'NoRewardDue');
+
+      // 10% of 0.1 SOL split three ways, the odd lamport to the treasury
+      const after = await Promise.all(verifiers.map(v => balance(v.publicKey)));
+      after.forEach((lamports, i) => expect(lamports - before[i]).to.equal(3_333_333));
+      expect(await balance(treasury.publicKey) - LAMPORTS_PER_SOL).to.equal(1);
+      expect(await balance(agent.publicKey) - agentBefore).to.equal(0.09 * LAMPORTS_PER_SOL);
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Reports quorum status without enough votes and on a tie', async () => {
+    const [bond, vault] = await setupBond('quorum-status-001');
+    const status = () =>
+      program.methods.getBondStatus().accounts({ bond, config: configPDA }).view();
+
+    await vote(bond, verifier1, true);
+    let snapshot = await status();
+    expect(snapshot.totalVotes).to.equal(1);
+    expect(snapshot.approveVotes).to.equal(1);
+    expect(snapshot.quorumReached).to.equal(false);
+    expect(snapshot.canFinalize).to.equal(false);
+    expect(snapshot.winningOutcome).to.equal(null);
+
+    // 2-2 clears the head-count quorum with neither side ahead
+    const tieVoter = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(tieVoter.publicKey, LAMPORTS_PER_SOL)
+    );
+    await vote(bond, verifier2, false);
+    await vote(bond, verifier3, true);
+    await vote(bond, tieVoter, false);
+    snapshot = await status();
+    expect(snapshot.totalVotes).to.equal(4);
+    expect(snapshot.approveVotes).to.equal(2);
+    expect(snapshot.slashVotes).to.equal(2);
+    expect(snapshot.quorumReached).to.equal(true);
+    expect(snapshot.tied).to.equal(true);
+    expect(snapshot.canFinalize).to.equal(true);
+    expect(snapshot.winningOutcome).to.equal(null);
+  });
+
+  it('Suggests longer deadlines for longer and more complex tasks', async () => {
+    const suggest = async (length: number, tier: number) =>
+      (
+        await program.methods
+          .suggestDeadline(length, tier)
+          .accounts({ config: configPDA })
+          .view()
+      ).toNumber();
+
+    const short = await suggest(50, 0);
+    expect(short).to.equal(86400);
+    expect(await suggest(450, 0)).to.be.greaterThan(short);
+    expect(await suggest(50, 3)).to.be.greaterThan(short);
+    expect(await suggest(450, 3)).to.be.greaterThan(await suggest(450, 0));
+
+    await expectError(
+      program.methods.suggestDeadline(50, 5).accounts({ config: configPDA }).rpc(),
+      'InvalidComplexityTier'
+    );
+  });
+
+  it('Records a resolved DAO decision as the governance vote', async () => {
+    const [bond] = await setupBond('dao-001');
+    const governanceProgram = Keypair.generate().publicKey;
+    const resolve = (governance: Keypair) =>
+      program.methods
+        .resolveDaoDecision(true)
+        .accounts({
+          payer: provider.wallet.publicKey,
+          governance: governance.publicKey,
+          bond,
+          config: configPDA,
+          decision: PublicKey.findProgramAddressSync(
+            [Buffer.from('dao_decision'), bond.toBuffer(), governance.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([governance])
+        .rpc();
+
+    // Stand-in for a governance PDA: a registered verifier handed to the
+    // configured governance program
+    const governance = Keypair.generate();
+    await ensureRegistered(governance);
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.assign({ accountPubkey: governance.publicKey, programId: governanceProgram })
+      ),
+      [governance]
+    );
+    const [decision] = PublicKey.findProgramAddressSync(
+      [Buffer.from('dao_decision'), bond.toBuffer(), governance.publicKey.toBuffer()],
+      program.programId
+    );
+
+    // A plain wallet cannot pose as governance, nor can anyone while DAO votes are off
+    const impostor = Keypair.generate();
+    await ensureRegistered(impostor);
+    await expectError(resolve(governance), 'UnauthorizedGovernance');
+    await updateConfig({ governanceProgram });
+    try {
+      await expectError(resolve(impostor), 'UnauthorizedGovernance');
+      await resolve(governance);
+    } finally {
+      await updateConfig();
+    }
+
+    // Anyone may crank the resolved decision into a vote, but only once
+    const daoVerify = () =>
+      program.methods
+        .daoVerify()
+        .accounts({ bond, decision })
+        .remainingAccounts(voterAccounts(bond, governance.publicKey))
+        .rpc();
+    await daoVerify();
+    const votes = (await program.account.bond.fetch(bond)).verificationVotes;
+    expect(votes).to.have.length(1);
+    expect(votes[0].verifier.toBase58()).to.equal(governance.publicKey.toBase58());
+    expect(votes[0].approve).to.equal(true);
+
+    await sleep(1000);
+    await expectError(daoVerify(), 'AlreadyVoted');
+
+    // Amending the task voids the decision; governance may decide afresh
+    await program.methods
+      .amendTask('Revised scope for the DAO', new anchor.BN(Math.floor(Date.now() / 1000) + 7200))
+      .accounts({ principal: principal.publicKey, agent: agent.publicKey, bond })
+      .signers([principal, agent])
+      .rpc();
+    await submitProof(bond, 'https://example.com/proof/dao-001-amended');
+    await expectError(daoVerify(), 'StaleDaoDecision');
+    await updateConfig({ governanceProgram });
+    try {
+      await resolve(governance);
+      await sleep(1000);
+      await expectError(resolve(governance), 'DaoDecisionAlreadyResolved');
+    } finally {
+      await updateConfig();
+    }
+    await daoVerify();
+    expect((await program.account.bond.fetch(bond)).verificationVotes).to.have.length(1);
+  });
+
+  it('Refunds the agent when the vote ties 3-3 or 4-4', async () => {
+    for (const [id, perSide] of [['tie-001', 3], ['tie-002', 4]] as const) {
+      const [bond, vault] = await setupBond(id);
+      for (let i = 0; i < perSide; i++) {
+        await vote(bond, Keypair.generate(), true);
+        await vote(bond, Keypair.generate(), false);
+      }
+
+      const agentBefore = await provider.connection.getBalance(agent.publicKey);
+      const principalBefore = await provider.connection.getBalance(principal.publicKey);
+      await finalize(bond, vault);
+
+      const settled = await program.account.bond.fetch(bond);
+      expect(settled.status).to.deep.equal({ expired: {} });
+      expect(settled.totalReleased.toNumber()).to.equal(0.1 * LAMPORTS_PER_SOL);
+      expect(await provider.connection.getBalance(agent.publicKey) - agentBefore).to.equal(
+        0.1 * LAMPORTS_PER_SOL
+      );
+      expect(await provider.connection.getBalance(principal.publicKey)).to.equal(principalBefore);
+    }
+  });
+
+  it('Finalizes a commit-reveal bond on its revealed votes', async () => {
+    const [bond, vault] = await setupBond(
+      'commit-reveal-002',
+      bondOptions({
+        voteMode: { blind: {} },
+        commitWindow: new anchor.BN(3),
+        revealWindow: new anchor.BN(60),
+      })
+    );
+    const ballots: [Keypair, boolean][] = [
+      [verifier1, true],
+      [verifier2, true],
+      [verifier3, false],
+    ];
+    const salt = (verifier: Keypair) => verifier.publicKey.toBuffer();
+    for (const [verifier, approve] of ballots) {
+      await program.methods
+        .commitVote(
+          Array.from(
+            keccak_256(
+              Buffer.concat([Buffer.from([approve ? 1 : 0]), salt(verifier), verifier.publicKey.toBuffer()])
+            )
+          )
+        )
+        .accounts({ verifier: verifier.publicKey, bond })
+        .signers([verifier])
+        .rpc();
+    }
+
+    // Commitments alone carry no weight
+    await expectError(finalize(bond, vault), 'QuorumNotReached');
+
+    await sleep(5000);
+    for (const [verifier, approve] of ballots) {
+      await program.methods
+        .revealVote(approve, Array.from(salt(verifier)))
+        .accounts({ verifier: verifier.publicKey, bond })
+        .remainingAccounts(voterAccounts(bond, verifier.publicKey))
+        .signers([verifier])
+        .rpc();
+    }
+
+    await finalize(bond, vault);
+    const settled = await program.account.bond.fetch(bond);
+    expect(settled.status).to.deep.equal({ completed: {} });
+    expect(settled.verificationVotes.map(v => v.approve)).to.deep.equal([true, true, false]);
+  });
+
+  it('Sends slashed collateral to the destination the principal chose', async () => {
+    const treasury = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(treasury.publicKey, LAMPORTS_PER_SOL)
+    );
+    const incinerator = new PublicKey('1nc1nerator11111111111111111111111111111111');
+    const balance = (key: PublicKey) => provider.connection.getBalance(key);
+    // Create, stake and vote down a bond; the returned call finalizes it
+    const votedDown = async (id: string, slashDestination: Record<string, object>) => {
+      const [bond, vault] = await setupBond(id, bondOptions({ slashDestination }));
+      await vote(bond, verifier1, false);
+      await vote(bond, verifier2, false);
+      await vote(bond, verifier3, false);
+      return (to: PublicKey[]) =>
+        program.methods
+          .finalizeBond()
+          .accounts(finalizeAccounts(bond, vault))
+          .remainingAccounts(to.map(pubkey => ({ pubkey, isSigner: false, isWritable: true })))
+          .signers([verifier1])
+          .rpc();
+    };
+
+    await expectError(
+      createBond('slash-dest-000', { options: bondOptions({ slashDestination: { treasury: {} } }) }),
+      'InvalidSlashDestination'
+    );
+
+    const toPrincipal = await votedDown('slash-dest-001', { principal: {} });
+    let principalBefore = await balance(principal.publicKey);
+    await toPrincipal([]);
+    expect(await balance(principal.publicKey) - principalBefore).to.equal(0.1 * LAMPORTS_PER_SOL);
+
+    await updateConfig({ treasury: treasury.publicKey });
+    try {
+      const toTreasury = await votedDown('slash-dest-002', { treasury: {} });
+      await expectError(toTreasury([]), 'SlashDestinationMissing');
+      principalBefore = await balance(principal.publicKey);
+      await toTreasury([treasury.publicKey]);
+      expect(await balance(treasury.publicKey) - LAMPORTS_PER_SOL).to.equal(0.1 * LAMPORTS_PER_SOL);
+      expect(await balance(principal.publicKey)).to.equal(principalBefore);
+    } finally {
+      await updateConfig();
+    }
+
+    const burned = await votedDown('slash-dest-003', { burn: {} });
+    principalBefore = await balance(principal.publicKey);
+    await burned([incinerator]);
+    expect(await balance(principal.publicKey)).to.equal(principalBefore);
+
+    // A missed installment is slashed to the same destination
+    const [scheduled, scheduledVault] = await createBond('slash-dest-004', {
+      options: bondOptions({
+        slashDestination: { burn: {} },
+        installments: 2,
+        installmentInterval: new anchor.BN(2),
+      }),
+    });
+    await program.methods
+      .fundInstallment()
+      .accounts({
+        agent: agent.publicKey,
+        bond: scheduled,
+        bondVault: scheduledVault,
+        stats: statsPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent])
+      .rpc();
+    await sleep(5000);
+    const slashMissed = (to: PublicKey[]) =>
+      program.methods
+        .slashMissedInstallment()
+        .accounts({
+          principal: principal.publicKey,
+          bond: scheduled,
+          bondVault: scheduledVault,
+          stats: statsPDA,
+          config: configPDA,
+          reputation: reputationPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(to.map(pubkey => ({ pubkey, isSigner: false, isWritable: true })))
+        .signers([principal])
+        .rpc();
+    await expectError(slashMissed([]), 'SlashDestinationMissing');
+    principalBefore = await balance(principal.publicKey);
+    const burnedBefore = await balance(incinerator);
+    const statsBefore = await program.account.stats.fetch(statsPDA);
+    const reputationBefore = await program.account.agentReputation.fetch(reputationPDA);
+    await slashMissed([incinerator]);
+    const statsAfter = await program.account.stats.fetch(statsPDA);
+    expect(statsAfter.bondsSlashed.toNumber()).to.equal(statsBefore.bondsSlashed.toNumber() + 1);
+    expect(statsAfter.activeBonds.toNumber()).to.equal(statsBefore.activeBonds.toNumber());
+    expect(
+      (await program.account.agentReputation.fetch(reputationPDA)).bondsSlashed.toNumber()
+    ).to.equal(reputationBefore.bondsSlashed.toNumber() + 1);
+    expect(await balance(principal.publicKey)).to.equal(principalBefore);
+    expect(await balance(incinerator) - burnedBefore).to.equal(0.05 * LAMPORTS_PER_SOL);
+    expect((await program.account.bond.fetch(scheduled)).slashReason).to.deep.equal({
+      missedInstallment: {},
+    });
+  });
+
+  it('Halts new bonds while paused but still lets existing ones finalize', async () => {
+    const setPaused = (paused: boolean) =>
+      program.methods
+        .setPaused(paused)
+        .accounts({ admin: provider.wallet.publicKey, config: configPDA })
+        .rpc();
+
+    const [liveBond, liveVault] = await setupBond('pause-001');
+    const [pendingBond, pendingVault] = await createBond('pause-002');
+    await vote(liveBond, verifier1, true);
+    await vote(liveBond, verifier2, true);
+    await vote(liveBond, verifier3, true);
+
+    await expectError(
+      program.methods
+        .setPaused(true)
+        .accounts({ admin: principal.publicKey, config: configPDA })
+        .signers([principal])
+        .rpc(),
+      'UnauthorizedAdmin'
+    );
+
+    await setPaused(true);
+    try {
+      await expectError(createBond('pause-003'), 'ProgramPaused');
+      await expectError(stakeBond(pendingBond, pendingVault), 'ProgramPaused');
+
+      await finalize(liveBond, liveVault);
+      expect((await program.account.bond.fetch(liveBond)).status).to.deep.equal({ completed: {} });
+    } finally {
+      await setPaused(false);
+    }
+
+    await stakeBond(pendingBond, pendingVault);
+  });
+
+  it('Finalizes on the decisive vote and pays that verifier the keeper bounty', async () => {
+    const [bond, vault] = await setupBond(
+      'verify-finalize-001',
+      bondOptions({ keeperBounty: new anchor.BN(0.01 * LAMPORTS_PER_SOL) })
+    );
+    const verifyAndFinalize = (verifier: Keypair) =>
+      program.methods
+        .verifyAndFinalize(true)
+        .accounts(finalizeAccounts(bond, vault, verifier.publicKey))
+        .remainingAccounts(voterAccounts(bond, verifier.publicKey))
+        .signers([verifier])
+        .rpc();
+
+    // Short of quorum the vote is only recorded
+    await verifyAndFinalize(verifier1);
+    await verifyAndFinalize(verifier2);
+    let current = await program.account.bond.fetch(bond);
+    expect(current.status).to.deep.equal({ pendingVerification: {} });
+    expect(current.verificationVotes).to.have.length(2);
+
+    const deciderBefore = await provider.connection.getBalance(verifier3.publicKey);
+    const agentBefore = await provider.connection.getBalance(agent.publicKey);
+    await verifyAndFinalize(verifier3);
+
+    current = await program.account.bond.fetch(bond);
+    expect(current.status).to.deep.equal({ completed: {} });
+    expect(await provider.connection.getBalance(verifier3.publicKey) - deciderBefore).to.equal(
+      0.01 * LAMPORTS_PER_SOL
+    );
+    expect(await provider.connection.getBalance(agent.publicKey) - agentBefore).to.equal(
+      0.1 * LAMPORTS_PER_SOL
+    );
+  });
+
+  it('Stores and publishes the verification rubric', async () => {
+    const rubric = 'Program compiles, tests pass, and escrow release requires both signatures';
+    let published = '';
+    const listener = program.addEventListener('RubricPublished', event => {
+      if (event.bondId === 'rubric-001') {
+        published = event.rubric;
+      }
+    });
+    try {
+      const [bond] = await createBond('rubric-001', { options: bondOptions({ rubric }) });
+      expect((await program.account.bond.fetch(bond)).rubric).to.equal(rubric);
+      await sleep(1000);
+      expect(published).to.equal(rubric);
+    } finally {
+      await program.removeEventListener(listener);
+    }
+
+    await expectError(
+      createBond('rubric-002', { options: bondOptions({ rubric: 'x'.repeat(257) }) }),
+      'RubricTooLong'
+    );
+  });
+
+  it('Returns the agent exactly its collateral and keeps the vault rent-exempt', async () => {
+    const collateral = 0.05 * LAMPORTS_PER_SOL;
+    const [bond, vault] = await createBond('vault-rent-001', {
+      collateral: new anchor.BN(collateral),
+    });
+    const rent = await vaultRent();
+    expect((await program.account.bond.fetch(bond)).vaultRent.toNumber()).to.equal(rent);
+    expect(await provider.connection.getBalance(vault)).to.equal(rent);
+
+    await stakeBond(bond, vault);
+    await submitProof(bond, 'https://example.com/proof/vault-rent-001');
+    await vote(bond, verifier1, true);
+    await vote(bond, verifier2, true);
+    await vote(bond, verifier3, true);
+
+    const agentBefore = await provider.connection.getBalance(agent.publicKey);
+    await finalize(bond, vault);
+    expect(await provider.connection.getBalance(agent.publicKey) - agentBefore).to.equal(collateral);
+    expect(await provider.connection.getBalance(vault)).to.equal(rent);
+  });
+
+  it('Lets the arbiter break a tied vote in either direction', async () => {
+    const escalate = (bond: PublicKey) =>
+      program.methods
+        .escalateToArbiter()
+        .accounts({ party: principal.publicKey, bond, config: configPDA })
+        .signers([principal])
+        .rpc();
+    const resolve = (bond: PublicKey, vault: PublicKey, approve: boolean) =>
+      program.methods
+        .resolveDispute(approve)
+        .accounts({
+          arbiter: provider.wallet.publicKey,
+          bond,
+          bondVault: vault,
+          agent: agent.publicKey,
+          principal: principal.publicKey,
+          stats: statsPDA,
+          config: configPDA,
+          reputation: reputationPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    const tiedBond = async (id: string) => {
+      const [bond, vault] = await setupBond(id);
+      await vote(bond, verifier1, true);
+      await vote(bond, verifier2, false);
+      return [bond, vault];
+    };
+
+    await updateConfig({ arbiter: provider.wallet.publicKey });
+    try {
+      // Unanimous so far: nothing to escalate
+      const [agreedBond] = await setupBond('arbiter-tie-000');
+      await vote(agreedBond, verifier1, true);
+      await expectError(escalate(agreedBond), 'DisputeNotContested');
+
+      const [approvedBond, approvedVault] = await tiedBond('arbiter-tie-001');
+      await escalate(approvedBond);
+      await resolve(approvedBond, approvedVault, true);
+      let settled = await program.account.bond.fetch(approvedBond);
+      expect(settled.status).to.deep.equal({ completed: {} });
+      expect(settled.arbiterRuling).to.equal(true);
+
+      const [slashedBond, slashedVault] = await tiedBond('arbiter-tie-002');
+      await escalate(slashedBond);
+      await resolve(slashedBond, slashedVault, false);
+      settled = await program.account.bond.fetch(slashedBond);
+      expect(settled.status).to.deep.equal({ slashed: {} });
+      expect(settled.arbiterRuling).to.equal(false);
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Rejects timeout math that would overflow a near-max deadline', async () => {
+    const id = 'max-deadline-001';
+    const [bond, vault] = deriveBond(id);
+    await program.methods
+      .createBond(
+        id,
+        'Open-ended task',
+        new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+        new anchor.BN('9223372036854775000'),
+        bondOptions()
+      )
+      .accounts({
+        principal: principal.publicKey,
+        agent: agent.publicKey,
+        bond,
+        bondVault: vault,
+        stats: statsPDA,
+        config: configPDA,
+        reputation: reputationPDA,
+        pair: pairPDA,
+        reservation: deriveReservation(id),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([principal])
+      .rpc();
+    await stakeBond(bond, vault);
+    await submitProof(bond, `https://example.com/proof/${id}`);
+
+    // deadline + timeout_grace does not fit in an i64
+    await expectError(finalize(bond, vault), 'DeadlineOverflow');
+
+    // A decided vote never needs the timeout
+    await vote(bond, verifier1, true);
+    await vote(bond, verifier2, true);
+    await vote(bond, verifier3, true);
+    await finalize(bond, vault);
+    expect((await program.account.bond.fetch(bond)).status).to.deep.equal({ completed: {} });
+  });
+
+  it('Accepts a 32-byte bond id and rejects longer or empty ones', async () => {
+    const boundary = 'b'.repeat(32);
+    const [bond] = await createBond(boundary);
+    expect((await program.account.bond.fetch(bond)).bondId).to.equal(boundary);
+
+    // A 33-byte id has no PDA, so create_bond fails validating the bond
+    // account; derive_pdas reports the reason
+    const createRaw = (id: string, [bondAddress, vault, reservation]: PublicKey[]) =>
+      program.methods
+        .createBond(
+          id,
+          'Id check',
+          new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+          new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+          bondOptions()
+        )
+        .accounts({
+          principal: principal.publicKey,
+          agent: agent.publicKey,
+          bond: bondAddress,
+          bondVault: vault,
+          stats: statsPDA,
+          config: configPDA,
+          reputation: reputationPDA,
+          pair: pairPDA,
+          reservation,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([principal])
+        .rpc();
+    const placeholders = [0, 1, 2].map(() => Keypair.generate().publicKey);
+
+    let rejected = false;
+    try {
+      await createRaw('b'.repeat(33), placeholders);
+    } catch {
+      rejected = true;
+    }
+    expect(rejected).to.equal(true);
+    await expectError(
+      program.methods.derivePdas('b'.repeat(33)).accounts({}).rpc(),
+      'BondIdTooLong'
+    );
+
+    await expectError(createRaw('', [...deriveBond(''), deriveReservation('')]), 'BondIdEmpty');
+  });
+
+  it('Reports whether a given caller may finalize', async () => {
+    const escrow = Keypair.generate();
+    const [bond] = await setupBond(
+      'can-finalize-001',
+      bondOptions({ escrowAuthority: escrow.publicKey })
+    );
+    const canFinalize = (caller: PublicKey) =>
+      program.methods.canFinalize(caller).accounts({ bond, config: configPDA }).view();
+
+    // Nobody may finalize before quorum
+    expect(await canFinalize(escrow.publicKey)).to.equal(false);
+
+    await vote(bond, verifier1, true);
+    await vote(bond, verifier2, true);
+    await vote(bond, verifier3, true);
+
+    // Only the escrow can finalize alone; others need its co-signature
+    expect(await canFinalize(escrow.publicKey)).to.equal(true);
+    expect(await canFinalize(verifier1.publicKey)).to.equal(false);
+
+    // Without an escrow any caller may finalize
+    const [openBond] = await setupBond('can-finalize-002');
+    await vote(openBond, verifier1, true);
+    await vote(openBond, verifier2, true);
+    await vote(openBond, verifier3, true);
+    expect(
+      await program.methods
+        .canFinalize(Keypair.generate().publicKey)
+        .accounts({ bond: openBond, config: configPDA })
+        .view()
+    ).to.equal(true);
+  });
+
+  it('Lets an acknowledged emergency slash execute without waiting', async () => {
+    const [bond, vault] = await setupBond(
+      'acknowledge-001',
+      bondOptions({ appealWindow: new anchor.BN(86400) })
+    );
+    const acknowledge = (signer: Keypair) =>
+      program.methods
+        .acknowledgeSlash()
+        .accounts({ agent: signer.publicKey, bond })
+        .signers([signer])
+        .rpc();
+    const execute = () =>
+      program.methods
+        .executeEmergencySlash()
+        .accounts({
+          principal: principal.publicKey,
+          bond,
+          bondVault: vault,
+          principalVault: principal.publicKey,
+          stats: statsPDA,
+          config: configPDA,
+          reputation: reputationPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([principal])
+        .rpc();
+
+    await expectError(acknowledge(agent), 'SlashNotRequested');
+    await program.methods
+      .requestEmergencySlash('Agent went silent')
+      .accounts({ principal: principal.publicKey, bond, config: configPDA })
+      .signers([principal])
+      .rpc();
+    await expectError(execute(), 'AppealWindowOpen');
+
+    await expectError(acknowledge(verifier1), 'UnauthorizedAgent');
+    await acknowledge(agent);
+    expect((await program.account.bond.fetch(bond)).slashAcknowledged).to.equal(true);
+    await expectError(
+      program.methods
+        .appealEmergencySlash()
+        .accounts({ agent: agent.publicKey, bond, config: configPDA })
+        .signers([agent])
+        .rpc(),
+      'SlashAlreadyAcknowledged'
+    );
+
+    // The full window is still open, but the principal is paid out now
+    const principalBefore = await provider.connection.getBalance(principal.publicKey);
+    await execute();
+    expect((await program.account.bond.fetch(bond)).status).to.deep.equal({ slashed: {} });
+    expect(await provider.connection.getBalance(principal.publicKey) - principalBefore).to.equal(
+      0.1 * LAMPORTS_PER_SOL
+    );
+  });
+
+  it('Reports the vote tally and path in BondFinalized on an auto-slash', async () => {
+    await updateConfig({ verificationDuration: new anchor.BN(2) });
+    try {
+      const [bond, vault] = await setupBond('finalized-event-001');
+      await vote(bond, verifier1, true);
+      await vote(bond, verifier2, false);
+      await sleep(4000);
+
+      let finalized: any;
+      const listener = program.addEventListener('BondFinalized', event => {
+        if (event.bondId === 'finalized-event-001') finalized = event;
+      });
+      await finalize(bond, vault);
+      await sleep(1000);
+      await program.removeEventListener(listener);
+
+      expect(finalized.status).to.deep.equal({ slashed: {} });
+      expect(finalized.totalVotes).to.equal(2);
+      expect(finalized.approveVotes).to.equal(1);
+      expect(finalized.slashVotes).to.equal(1);
+      expect(finalized.path).to.deep.equal({ autoSlash: {} });
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Applies the configured vote minimum and majority', async () => {
+    await expectError(updateConfig({ majorityNum: new anchor.BN(4) }), 'InvalidConfig');
+    await expectError(updateConfig({ majorityDen: new anchor.BN(0) }), 'InvalidConfig');
+    await expectError(
+      updateConfig({ majorityNum: new anchor.BN(1), majorityDen: new anchor.BN(2) }),
+      'InvalidConfig'
+    );
+    await expectError(updateConfig({ minVotes: new anchor.BN(0) }), 'InvalidConfig');
+
+    await updateConfig({ minVotes: new anchor.BN(5) });
+    try {
+      const [bond, vault] = await setupBond('quorum-config-001');
+      const voters = Array.from({ length: 5 }, () => Keypair.generate());
+      for (const voter of voters.slice(0, 4)) {
+        await vote(bond, voter, true);
+      }
+      await expectError(finalize(bond, vault), 'QuorumNotReached');
+      await vote(bond, voters[4], true);
+      await finalize(bond, vault);
+      expect((await program.account.bond.fetch(bond)).status).to.deep.equal({ completed: {} });
+    } finally {
+      await updateConfig();
+    }
+
+    await updateConfig({ majorityNum: new anchor.BN(3), majorityDen: new anchor.BN(4) });
+    try {
+      // 2-1 clears two thirds but not three quarters
+      const [bond, vault] = await setupBond('quorum-config-002');
+      await vote(bond, verifier1, true);
+      await vote(bond, verifier2, true);
+      await vote(bond, verifier3, false);
+      await expectError(finalize(bond, vault), 'QuorumNotReached');
+      await vote(bond, Keypair.generate(), true);
+      await finalize(bond, vault);
+      expect((await program.account.bond.fetch(bond)).status).to.deep.equal({ completed: {} });
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Raises the required quorum when collateral is topped up', async () => {
+    // One extra vote per 0.15 SOL at risk: 0.1 SOL needs 3, 0.15 SOL needs 4
+    await updateConfig({ quorumStep: new anchor.BN(0.15 * LAMPORTS_PER_SOL) });
+    try {
+      const [bond, vault] = await setupBond('quorum-topup-001');
+      expect((await program.account.bond.fetch(bond)).requiredVotes.toNumber()).to.equal(3);
+
+      await vote(bond, verifier1, true);
+      await vote(bond, verifier2, true);
+      await vote(bond, verifier3, true);
+      const status = () =>
+        program.methods.getBondStatus().accounts({ bond, config: configPDA }).view();
+      expect((await status()).canFinalize).to.equal(true);
+
+      await program.methods
+        .topUpBuffer(new anchor.BN(0.05 * LAMPORTS_PER_SOL))
+        .accounts({
+          agent: agent.publicKey,
+          bond,
+          config: configPDA,
+          bondVault: vault,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+      expect((await program.account.bond.fetch(bond)).requiredVotes.toNumber()).to.equal(4);
+
+      // Three approvals were enough before the top-up and no longer are
+      expect((await status()).quorumReached).to.equal(false);
+      await expectError(finalize(bond, vault), 'QuorumNotReached');
+
+      await vote(bond, Keypair.generate(), true);
+      await finalize(bond, vault);
+      expect((await program.account.bond.fetch(bond)).status).to.deep.equal({ completed: {} });
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Replays a bond lifecycle from persisted state', async () => {
+    const [bond, vault] = await setupBond('replay-001');
+    await vote(bond, verifier1, true);
+    await vote(bond, verifier2, true);
+    await vote(bond, verifier3, false);
+    await finalize(bond, vault);
+
+    const replay = await program.methods.replayEvents().accounts({ bond }).view();
+    expect(replay.bondId).to.equal('replay-001');
+    expect(replay.status).to.deep.equal({ completed: {} });
+    expect(replay.slashReason).to.equal(null);
+
+    // Created, staked, proved, voted, settled, in that order
+    const created = replay.createdAt.toNumber();
+    const staked = replay.stakedAt.toNumber();
+    const proved = replay.proofs[0].submittedAt.toNumber();
+    const voteTimes = replay.votes.map(v => v.timestamp.toNumber());
+    const completed = replay.completedAt.toNumber();
+    expect(staked).to.be.at.least(created);
+    expect(proved).to.be.at.least(staked);
+    expect(Math.min(...voteTimes)).to.be.at.least(proved);
+    expect(completed).to.be.at.least(Math.max(...voteTimes));
+
+    expect(replay.votes.map(v => v.verifier.toBase58())).to.deep.equal(
+      [verifier1, verifier2, verifier3].map(v => v.publicKey.toBase58())
+    );
+    expect(replay.votes.map(v => v.approve)).to.deep.equal([true, true, false]);
+    expect(replay.collateralAmount.toNumber()).to.equal(0.1 * LAMPORTS_PER_SOL);
+    expect(replay.totalReleased.toNumber()).to.equal(0.1 * LAMPORTS_PER_SOL);
+    expect(replay.totalSlashed.toNumber()).to.equal(0);
+  });
+
+  it('Weighs votes by the stake snapshotted when they were cast', async () => {
+    const [bond, vault] = await setupBond(
+      'stake-snapshot-001',
+      bondOptions({ voteWeighting: { linear: {} } })
+    );
+    const stake = (verifier: Keypair, amount: number) =>
+      program.methods
+        .stakeVerifier(new anchor.BN(amount))
+        .accounts({
+          verifier: verifier.publicKey,
+          bond,
+          verifierStake: deriveStake(bond, verifier.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([verifier])
+        .rpc();
+
+    // A vote must name the voter's stake address, staked or not
+    await expectError(
+      program.methods
+        .verifyWork(true)
+        .accounts({ verifier: verifier2.publicKey, bond })
+        .signers([verifier2])
+        .rpc(),
+      'VerifierStakeMissing'
+    );
+
+    await stake(verifier1, (9 * LAMPORTS_PER_SOL) / 100);
+    await stake(verifier2, LAMPORTS_PER_SOL / 100);
+    await stake(verifier3, LAMPORTS_PER_SOL / 100);
+    await vote(bond, verifier1, false);
+    await vote(bond, verifier2, true);
+    await vote(bond, verifier3, true);
+    await expectError(stake(verifier2, LAMPORTS_PER_SOL), 'StakeAfterVote');
+
+    const votes = (await program.account.bond.fetch(bond)).verificationVotes;
+    expect(votes.map(v => v.stake.toNumber())).to.deep.equal([
+      (9 * LAMPORTS_PER_SOL) / 100,
+      LAMPORTS_PER_SOL / 100,
+      LAMPORTS_PER_SOL / 100,
+    ]);
+
+    // No stake accounts passed: the whale's weight still decides
+    await finalize(bond, vault);
+    expect((await program.account.bond.fetch(bond)).status).to.deep.equal({ slashed: {} });
+  });
+
+  it('Forfeits losing verifier stakes to the winning side', async () => {
+    await updateConfig({ stakeForfeitBps: 5000 });
+    try {
+      const [bond, vault] = await setupBond('stake-forfeit-001');
+      const ballots: [Keypair, number, boolean][] = [
+        [verifier1, 0.02 * LAMPORTS_PER_SOL, true],
+        [verifier2, 0.01 * LAMPORTS_PER_SOL, true],
+        [verifier3, 0.03 * LAMPORTS_PER_SOL, false],
+      ];
+      for (const [verifier, amount, approve] of ballots) {
+        await program.methods
+          .stakeVerifier(new anchor.BN(amount))
+          .accounts({
+            verifier: verifier.publicKey,
+            bond,
+            verifierStake: deriveStake(bond, verifier.publicKey),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([verifier])
+          .rpc();
+        await vote(bond, verifier, approve);
+      }
+      await finalize(bond, vault);
+      expect((await program.account.bond.fetch(bond)).stakeForfeitBps).to.equal(5000);
+
+      // Anyone may reclaim; the stake and its rent go to the verifier.
+      // Returns the change beyond the stake account's own balance
+      const reclaim = async (verifier: Keypair) => {
+        const stake = deriveStake(bond, verifier.publicKey);
+        const held = await provider.connection.getBalance(stake);
+        const before = await provider.connection.getBalance(verifier.publicKey);
+        await program.methods
+          .reclaimVerifierStake()
+          .accounts({ verifier: verifier.publicKey, bond, verifierStake: stake })
+          .rpc();
+        return (await provider.connection.getBalance(verifier.publicKey)) - before - held;
+      };
+
+      // Winners wait until the loser's forfeit is in
+      await expectError(reclaim(verifier1), 'ForfeitsOutstanding');
+      expect(await reclaim(verifier3)).to.equal(-0.015 * LAMPORTS_PER_SOL);
+      // 0.015 SOL split 2:1 by winning stake
+      expect(await reclaim(verifier1)).to.equal(0.01 * LAMPORTS_PER_SOL);
+      expect(await reclaim(verifier2)).to.equal(0.005 * LAMPORTS_PER_SOL);
+    } finally {
+      await updateConfig();
+    }
+  });
+
+  it('Keeps a bond open until every verifier stake is reclaimed', async () => {
+    await updateConfig({ autoCloseOnFinalize: true });
+    try {
+      const [bond, vault] = await setupBond('close-stakes-001');
+      await program.methods
+        .stakeVerifier(new anchor.BN(LAMPORTS_PER_SOL / 100))
+        .accounts({
+          verifier: verifier1.publicKey,
+          bond,
+          verifierStake: deriveStake(bond, verifier1.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([verifier1])
+        .rpc();
+      await vote(bond, verifier1, true);
+      await vote(bond, verifier2, true);
+      await vote(bond, verifier3, true);
+
+      // Auto-close skips a bond with a stake still in it
+      await finalize(bond, vault);
+      expect((await program.account.bond.fetch(bond)).openStakes).to.equal(1);
+
+      const close = () =>
+        program.methods
+          .closeBond()
+          .accounts({
+            principal: principal.publicKey,
+            bond,
+            bondVault: vault,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([principal])
+          .rpc();
+      await expectError(close(), 'ClaimsOutstanding');
+
+      await program.methods
+        .reclaimVerifierStake()
+        .accounts({
+          verifier: verifier1.publicKey,
+          bond,
+          verifierStake: deriveStake(bond, verifier1.publicKey),
+        })
+        .rpc();
+      await close();
+      expect(await provider.connection.getAccountInfo(bond)).to.equal(null);
+    } finally {
+      await updateConfig();
+    }
+  });
 });